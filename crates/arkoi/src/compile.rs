@@ -0,0 +1,53 @@
+use std::{cell::RefCell, path::PathBuf, rc::Rc};
+
+use clap::Args;
+use lasso::Rodeo;
+use termcolor::{ColorChoice, StandardStream};
+
+use bytecode::Compiler;
+use diagnostics::{file::Files, renderer::Renderer};
+
+use crate::frontend::{analyze, TargetArgs};
+
+#[derive(Args)]
+pub struct CompileArgs {
+    // The source file that should be compiled
+    input_file: PathBuf,
+    // The file the bytecode gets written to
+    output_file: PathBuf,
+    #[command(flatten)]
+    target: TargetArgs,
+}
+
+pub fn compile(args: CompileArgs) {
+    let input_path = args.input_file.as_path();
+    if !input_path.exists() {
+        panic!("The input file doesn't exist.");
+    }
+
+    let source = std::fs::read_to_string(input_path).expect("Couldn't read the file.");
+    let input_path = input_path.to_string_lossy();
+
+    let mut files = Files::new();
+    let file_id = files.add(input_path, &source);
+
+    let stdout = StandardStream::stdout(ColorChoice::Auto);
+    let interner = Rc::new(RefCell::new(Rodeo::new()));
+    let mut renderer = Renderer::new(&files, interner.clone(), stdout);
+
+    let target = args.target.into();
+    let Some(mut program) = analyze(&files, file_id, interner.clone(), target, &mut renderer)
+    else {
+        std::process::exit(1);
+    };
+
+    let module = match Compiler::new(interner.clone(), target).compile(&mut program) {
+        Ok(module) => module,
+        Err(error) => {
+            renderer.render(error);
+            std::process::exit(1);
+        }
+    };
+
+    std::fs::write(args.output_file, module.encode()).expect("Couldn't write the output file.");
+}
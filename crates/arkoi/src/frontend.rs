@@ -0,0 +1,73 @@
+use std::{cell::RefCell, rc::Rc};
+
+use clap::{
+    builder::{PossibleValuesParser, TypedValueParser},
+    Args,
+};
+use lasso::Rodeo;
+use termcolor::WriteColor;
+
+use ast::{Program, Target};
+use diagnostics::{
+    file::{FileID, Files},
+    renderer::Renderer,
+};
+use lexer::Lexer;
+use parser::Parser;
+use semantics::Semantics;
+
+#[derive(Args)]
+pub struct TargetArgs {
+    /// The width of the pointer-sized integers `usize` and `isize` in bits
+    #[arg(
+        long,
+        default_value = "64",
+        value_parser = PossibleValuesParser::new(["16", "32", "64"])
+            .map(|width| width.parse::<usize>().unwrap()),
+    )]
+    size_width: usize,
+}
+
+impl From<TargetArgs> for Target {
+    fn from(value: TargetArgs) -> Self {
+        Self {
+            size_width: value.size_width,
+        }
+    }
+}
+
+/// Runs every stage of the frontend on the given file. All errors found along
+/// the way are rendered and result in `None`.
+pub fn analyze<W: WriteColor>(
+    files: &Files,
+    file_id: FileID,
+    interner: Rc<RefCell<Rodeo>>,
+    target: Target,
+    renderer: &mut Renderer<'_, W>,
+) -> Option<Program> {
+    let lexer = Lexer::new(files, file_id, interner.clone());
+    let iterator = lexer.into_iter();
+    let mut parser = Parser::new(iterator);
+    let mut program = parser.parse_program();
+
+    if !parser.errors.is_empty() {
+        for error in parser.errors {
+            renderer.render(error);
+        }
+
+        return None;
+    }
+
+    let mut semantics = Semantics::new(interner.clone(), target);
+    semantics.run_all(&mut program);
+
+    if !semantics.errors.is_empty() {
+        for error in semantics.errors {
+            renderer.render(error);
+        }
+
+        return None;
+    }
+
+    Some(program)
+}
@@ -1,9 +1,12 @@
+mod compile;
+mod frontend;
+mod repl;
 mod run;
 
-use std::path::PathBuf;
-
-use clap::{Args, Parser, Subcommand};
+use clap::{Parser, Subcommand};
 
+use compile::{compile, CompileArgs};
+use repl::{repl, ReplArgs};
 use run::{run, RunArgs};
 
 #[derive(clap::Parser)]
@@ -16,7 +19,7 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Mode {
-    /// Runs a given source using the integrated interpreter
+    /// Runs a given source using the integrated interpreter or a compiled file using the VM
     Run(RunArgs),
     /// Compiles the source to either bytecode for the VM or machine targets
     Compile(CompileArgs),
@@ -24,21 +27,24 @@ enum Mode {
     Repl(ReplArgs),
 }
 
-#[derive(Args)]
-struct CompileArgs {
-    input_file: PathBuf,
-    output_file: PathBuf,
-}
-
-#[derive(Args)]
-struct ReplArgs {}
+/// The interpreter walks the syntax tree recursively, thus a deeply recursive
+/// program needs a lot more stack than the main thread has.
+const STACK_SIZE: usize = 512 * 1024 * 1024;
 
 fn main() {
     let cli = Cli::parse();
 
-    match cli.mode {
-        Mode::Run(args) => run(args),
-        Mode::Compile(_) => {}
-        Mode::Repl(_) => {}
+    let thread = std::thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || match cli.mode {
+            Mode::Run(args) => run(args),
+            Mode::Compile(args) => compile(args),
+            Mode::Repl(args) => repl(args),
+        })
+        .expect("Couldn't spawn the main thread.");
+
+    // The panic itself already got printed by the thread.
+    if thread.join().is_err() {
+        std::process::exit(101);
     }
 }
@@ -0,0 +1,225 @@
+use std::{
+    cell::RefCell,
+    io::{BufRead, Write},
+    rc::Rc,
+};
+
+use clap::Args;
+use lasso::Rodeo;
+use termcolor::{ColorChoice, StandardStream};
+
+use ast::{StmtKind, Target};
+use diagnostics::{file::Files, renderer::Renderer};
+use interpreter::Interpreter;
+use lexer::{error::LexerError, token::TokenKind, Lexer};
+use parser::Parser;
+use semantics::Semantics;
+
+use crate::frontend::TargetArgs;
+
+#[derive(Args)]
+pub struct ReplArgs {
+    #[command(flatten)]
+    target: TargetArgs,
+}
+
+pub fn repl(args: ReplArgs) {
+    let mut session = Session::new(args.target.into());
+
+    let mut stdin = std::io::stdin().lock();
+    let mut input = String::new();
+
+    loop {
+        let prompt = if input.is_empty() { "> " } else { ". " };
+        print!("{}", prompt);
+        std::io::stdout()
+            .flush()
+            .expect("Couldn't flush the stdout.");
+
+        let mut line = String::new();
+        match stdin.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => input.push_str(&line),
+            Err(error) => panic!("Couldn't read the input: {}", error),
+        }
+
+        if !is_complete(&input) {
+            continue;
+        }
+
+        if !input.trim().is_empty() {
+            session.evaluate(&input, &mut std::io::stdout());
+        }
+
+        input.clear();
+    }
+}
+
+/// The input is only evaluated once every opened brace, bracket and
+/// parenthesis got closed again, thus functions can be written over multiple
+/// lines. The input gets lexed, so the ones inside of strings and comments
+/// aren't counted.
+fn is_complete(input: &str) -> bool {
+    let mut files = Files::new();
+    let file_id = files.add("repl", input);
+    let interner = Rc::new(RefCell::new(Rodeo::new()));
+
+    let mut tokens = Lexer::new(&files, file_id, interner).into_iter();
+    let depth = tokens
+        .by_ref()
+        .fold(0isize, |depth, token| match token.kind {
+            TokenKind::Parent(true) | TokenKind::Bracket(true) | TokenKind::Brace(true) => {
+                depth + 1
+            }
+            TokenKind::Parent(false) | TokenKind::Bracket(false) | TokenKind::Brace(false) => {
+                depth - 1
+            }
+            _ => depth,
+        });
+
+    // A block comment, which isn't closed yet, continues on the next line.
+    let open_comment = tokens
+        .take_errors()
+        .iter()
+        .any(|error| matches!(error, LexerError::UnterminatedComment(_)));
+
+    depth <= 0 && !open_comment
+}
+
+/// Everything that needs to survive between two inputs of the REPL.
+struct Session {
+    files: Files,
+    interner: Rc<RefCell<Rodeo>>,
+    semantics: Semantics,
+    interpreter: Interpreter,
+}
+
+impl Session {
+    fn new(target: Target) -> Self {
+        let interner = Rc::new(RefCell::new(Rodeo::new()));
+
+        Self {
+            files: Files::new(),
+            interpreter: Interpreter::new(interner.clone(), target),
+            semantics: Semantics::new(interner.clone(), target),
+            interner,
+        }
+    }
+
+    /// Writes the value and the type of every expression statement to the
+    /// output, while errors are rendered to the stdout.
+    fn evaluate(&mut self, source: &str, output: &mut impl Write) {
+        let file_id = self.files.add("repl", source);
+
+        let stdout = StandardStream::stdout(ColorChoice::Auto);
+        let mut renderer = Renderer::new(&self.files, self.interner.clone(), stdout);
+
+        let lexer = Lexer::new(&self.files, file_id, self.interner.clone());
+        let mut parser = Parser::new(lexer.into_iter());
+        let mut program = parser.parse_repl();
+
+        if !parser.errors.is_empty() {
+            for error in parser.errors {
+                renderer.render(error);
+            }
+
+            return;
+        }
+
+        // Declarations and values of an input that fails aren't kept, so it
+        // can be corrected and entered again.
+        let checkpoint = self.semantics.clone();
+        let values = self.interpreter.clone();
+
+        self.semantics.run_all(&mut program);
+        if !self.semantics.errors.is_empty() {
+            for error in self.semantics.errors.drain(..) {
+                renderer.render(error);
+            }
+
+            self.semantics = checkpoint;
+            return;
+        }
+
+        for statement in program.statements.iter_mut() {
+            match self.interpreter.execute(statement) {
+                Ok(Some(value)) => {
+                    // The type is the one the type checker found, as the value
+                    // doesn't know it for structs, enums and pointer-sized
+                    // integers.
+                    let kind = match statement {
+                        StmtKind::ExprStmt(node) => node.type_.get(),
+                        _ => None,
+                    };
+
+                    match kind {
+                        Some(kind) => {
+                            let name = kind.name(&self.interner.borrow());
+                            writeln!(output, "{} @{}", value, name)
+                        }
+                        None => writeln!(output, "{}", value),
+                    }
+                    .expect("Couldn't write the output.");
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    renderer.render(error);
+
+                    self.semantics = checkpoint;
+                    self.interpreter = values;
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn complete() {
+        assert!(is_complete("let value = 42;"));
+        assert!(is_complete("fun main() @i32 {\n    42\n}"));
+        assert!(!is_complete("fun main() @i32 {\n"));
+        assert!(!is_complete("let values = [1,\n"));
+    }
+
+    /// Evaluates the inputs one after another and collects their output.
+    fn evaluate(inputs: &[&str]) -> String {
+        let mut session = Session::new(Target::default());
+
+        let mut output = Vec::new();
+        for input in inputs {
+            session.evaluate(input, &mut output);
+        }
+
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn static_types() {
+        let output = evaluate(&[
+            "struct Point { x @i32, y @i32 }",
+            "let values = [1, 2, 3];",
+            "values",
+            "len(values)",
+            "Point { x: 1, y: 2 }",
+            "(true, 2u8)",
+        ]);
+
+        assert_eq!(
+            output,
+            "[1, 2, 3] @[i32; 3]\n3 @usize\n{ 1, 2 } @Point\n{ true, 2 } @(bool, u8)\n"
+        );
+    }
+
+    #[test]
+    fn complete_strings_and_comments() {
+        assert!(is_complete("let s = \"a(\";"));
+        assert!(is_complete("let s = \"}\"; // {"));
+        assert!(!is_complete("let s = \"{\"; /* )"));
+        assert!(!is_complete("fun main() @i32 { // }"));
+    }
+}
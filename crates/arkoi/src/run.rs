@@ -4,15 +4,22 @@ use clap::Args;
 use lasso::Rodeo;
 use termcolor::{ColorChoice, StandardStream};
 
+use bytecode::{format::MAGIC, Module};
 use diagnostics::{file::Files, renderer::Renderer};
-use lexer::Lexer;
-use parser::Parser;
-use semantics::Semantics;
+use interpreter::Interpreter;
+use value::Value;
+use vm::VM;
+
+use ast::Target;
+
+use crate::frontend::{analyze, TargetArgs};
 
 #[derive(Args)]
 pub struct RunArgs {
-    // The file that should be run
+    // The file that should be run, either a source or a compiled file
     input_file: PathBuf,
+    #[command(flatten)]
+    target: TargetArgs,
 }
 
 pub fn run(args: RunArgs) {
@@ -21,43 +28,69 @@ pub fn run(args: RunArgs) {
         panic!("The input file doesn't exist.");
     }
 
-    let source = std::fs::read_to_string(input_path).expect("Couldn't read the file.");
+    let bytes = std::fs::read(input_path).expect("Couldn't read the file.");
     let input_path = input_path.to_string_lossy();
 
-    let mut files = Files::new();
-    let file_id = files.add(input_path, &source);
+    // Compiled files are recognized by their header, everything else is
+    // treated as source code.
+    if bytes.starts_with(MAGIC) {
+        run_compiled(&bytes);
+    } else {
+        let source = String::from_utf8(bytes).expect("The file isn't valid UTF-8.");
+        run_source(&input_path, &source, args.target.into());
+    }
+}
 
+fn run_compiled(bytes: &[u8]) {
+    let files = Files::new();
     let stdout = StandardStream::stdout(ColorChoice::Auto);
     let interner = Rc::new(RefCell::new(Rodeo::new()));
-    let mut renderer = Renderer::new(&files, interner.clone(), stdout);
+    let mut renderer = Renderer::new(&files, interner, stdout);
 
-    let lexer = Lexer::new(&files, file_id, interner.clone());
-    if !lexer.errors.is_empty() {
-        for error in lexer.errors {
+    let module = match Module::decode(bytes) {
+        Ok(module) => module,
+        Err(error) => {
             renderer.render(error);
+            std::process::exit(1);
         }
+    };
 
-        return;
-    }
-
-    let iterator = lexer.into_iter();
-    let mut parser = Parser::new(iterator);
-    let mut program = parser.parse_program();
-
-    if !parser.errors.is_empty() {
-        for error in parser.errors {
+    let mut vm = VM::new(&module);
+    match vm.run() {
+        Ok(value) => exit(value),
+        Err(error) => {
             renderer.render(error);
+            std::process::exit(1);
         }
-
-        return;
     }
+}
+
+fn run_source(path: &str, source: &str, target: Target) {
+    let mut files = Files::new();
+    let file_id = files.add(path, source);
+
+    let stdout = StandardStream::stdout(ColorChoice::Auto);
+    let interner = Rc::new(RefCell::new(Rodeo::new()));
+    let mut renderer = Renderer::new(&files, interner.clone(), stdout);
 
-    let mut semantics = Semantics::new(&mut program);
-    semantics.run_all();
+    let Some(mut program) = analyze(&files, file_id, interner.clone(), target, &mut renderer)
+    else {
+        std::process::exit(1);
+    };
 
-    if !semantics.errors.is_empty() {
-        for error in semantics.errors {
+    let mut interpreter = Interpreter::new(interner.clone(), target);
+    match interpreter.run(&mut program) {
+        Ok(value) => exit(value),
+        Err(error) => {
             renderer.render(error);
+            std::process::exit(1);
         }
     }
 }
+
+/// An integer returned by `main` is used as the exit code of the process.
+fn exit(value: Option<Value>) {
+    if let Some(Value::Int(exit_code, _, _)) = value {
+        std::process::exit(exit_code as i32);
+    }
+}
@@ -0,0 +1,856 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use lasso::Rodeo;
+
+use crate::{
+    error::{CompileError, NoMainFunction, NoSymbolFound, NoTypeFound, UnknownSymbol},
+    instruction::Instruction,
+    module::{Function, Module},
+};
+use ast::{
+    symbol::{Symbol, SymbolKind},
+    traversal::{Visitable, Visitor, Walkable},
+    ArrayLiteral, Assign, Binary, BinaryOperator, Break, Call, Cast, Continue, DestructureDecl,
+    ExprKind, ExprStmt, FieldAccess, FunDecl, Id, If, Index, Len, LetDecl, Literal, Match, Pattern,
+    Program, Return, StmtKind, StructLiteral, Target, TupleLiteral, TypeKind, Unary, UnaryOperator,
+    VariantLiteral, While,
+};
+use name_resolution::error::InvalidSymbolKind;
+use value::Value;
+
+type Result = std::result::Result<(), CompileError>;
+
+/// The symbols are already unique per declaration after the name resolution,
+/// thus their address is enough to find the slot of a variable.
+type SymbolRef = *const RefCell<Symbol>;
+
+/// Everything needed while the body of a function gets compiled.
+#[derive(Debug)]
+struct FunctionState {
+    function: Function,
+    return_type: TypeKind,
+    locals: HashMap<SymbolRef, u32>,
+    /// The enclosing loops, starting with the outermost one.
+    loops: Vec<Loop>,
+}
+
+#[derive(Debug)]
+struct Loop {
+    /// Where the condition of the loop starts, the target of `continue`.
+    start: u32,
+    /// Jumps of `break` statements, which need to be pointed after the loop.
+    breaks: Vec<usize>,
+}
+
+#[derive(Debug)]
+pub struct Compiler {
+    interner: Rc<RefCell<Rodeo>>,
+    /// Decides the width pointer-sized integers are compiled to.
+    target: Target,
+    constants: Vec<Value>,
+    globals: HashMap<SymbolRef, u32>,
+    indices: HashMap<*const RefCell<FunDecl>, u32>,
+    functions: Vec<Function>,
+    init: Function,
+    state: Option<FunctionState>,
+}
+
+impl Compiler {
+    pub fn new(interner: Rc<RefCell<Rodeo>>, target: Target) -> Self {
+        Self {
+            interner,
+            target,
+            constants: Vec::new(),
+            globals: HashMap::new(),
+            indices: HashMap::new(),
+            functions: Vec::new(),
+            init: Function::new("init", 0),
+            state: None,
+        }
+    }
+
+    /// Compiles a program, which already passed the semantic analysis, to a module.
+    pub fn compile(mut self, program: &mut Program) -> std::result::Result<Module, CompileError> {
+        let main = self.interner.borrow().get("main");
+        let mut main_index = None;
+
+        // The indices of the functions are assigned up front, so calls can
+        // refer to functions that get compiled later on.
+        for statement in program.statements.iter() {
+            let StmtKind::FunDecl(fun_decl) = statement else {
+                continue;
+            };
+
+            let index = self.functions.len() as u32;
+            let borrow = fun_decl.borrow();
+            let name = match borrow.id.get_spur() {
+                Some(spur) => {
+                    if main == Some(spur) {
+                        main_index = Some(index);
+                    }
+
+                    self.interner.borrow().resolve(&spur).to_string()
+                }
+                None => String::new(),
+            };
+
+            self.indices.insert(Rc::as_ptr(fun_decl), index);
+            self.functions
+                .push(Function::new(name, borrow.parameters.len() as u32));
+        }
+
+        // The same goes for the global variables, which can be read by
+        // functions compiled before their declaration.
+        for statement in program.statements.iter() {
+            let symbols = match statement {
+                StmtKind::LetDecl(node) => node.symbol.get().into_iter().collect(),
+                StmtKind::DestructureDecl(node) => node
+                    .pattern
+                    .bindings()
+                    .into_iter()
+                    .filter_map(|binding| binding.symbol.get())
+                    .collect(),
+                _ => Vec::new(),
+            };
+
+            for symbol in symbols {
+                let index = self.globals.len() as u32;
+                self.globals.insert(Rc::as_ptr(symbol), index);
+            }
+        }
+
+        let main = main_index.ok_or(NoMainFunction)?;
+        program.accept(&mut self)?;
+        self.init.code.push(Instruction::ReturnVoid);
+
+        Ok(Module {
+            constants: self.constants,
+            globals: self.globals.len() as u32,
+            init: self.init,
+            functions: self.functions,
+            main,
+        })
+    }
+
+    fn function(&mut self) -> &mut Function {
+        match self.state {
+            Some(ref mut state) => &mut state.function,
+            None => &mut self.init,
+        }
+    }
+
+    fn emit(&mut self, instruction: Instruction) -> usize {
+        let function = self.function();
+        function.code.push(instruction);
+        function.code.len() - 1
+    }
+
+    /// Points the jump at the given index to the next instruction.
+    fn patch(&mut self, index: usize) {
+        let function = self.function();
+        let target = function.code.len() as u32;
+
+        function.code[index] = match function.code[index] {
+            Instruction::Jump(_) => Instruction::Jump(target),
+            Instruction::JumpIfFalse(_) => Instruction::JumpIfFalse(target),
+            ref instruction => panic!("Can't patch the instruction {:?}.", instruction),
+        };
+    }
+
+    /// The instructions to load and store the variable the identifier refers to.
+    fn variable(&self, node: &Id) -> std::result::Result<(Instruction, Instruction), CompileError> {
+        let symbol = node.symbol.get().ok_or(NoSymbolFound::new(node.id.span))?;
+        let symbol = Rc::as_ptr(symbol);
+
+        let local = self
+            .state
+            .as_ref()
+            .and_then(|state| state.locals.get(&symbol));
+        match (local, self.globals.get(&symbol)) {
+            (Some(slot), _) => Ok((Instruction::GetLocal(*slot), Instruction::SetLocal(*slot))),
+            (None, Some(index)) => Ok((
+                Instruction::GetGlobal(*index),
+                Instruction::SetGlobal(*index),
+            )),
+            (None, None) => Err(UnknownSymbol::new(node.id.span).into()),
+        }
+    }
+
+    /// Gives the declared symbol a local slot inside of a function or a
+    /// global one otherwise and returns the instruction to store into it.
+    fn declare(&mut self, symbol: SymbolRef) -> Instruction {
+        match self.state {
+            Some(ref mut state) => {
+                let slot = state.function.locals;
+                state.function.locals += 1;
+                state.locals.insert(symbol, slot);
+                Instruction::SetLocal(slot)
+            }
+            None => {
+                let index = self.globals.len() as u32;
+                Instruction::SetGlobal(*self.globals.entry(symbol).or_insert(index))
+            }
+        }
+    }
+
+    /// Structs, enums, arrays and tuples don't need to be converted, as the
+    /// type checker only allows the exact same type of elements to be assigned.
+    fn cast(&mut self, kind: TypeKind) {
+        if !matches!(
+            kind,
+            TypeKind::Struct(_)
+                | TypeKind::Enum(_)
+                | TypeKind::Array(_, _)
+                | TypeKind::Slice(_)
+                | TypeKind::Tuple(_)
+        ) {
+            self.emit(Instruction::Cast(kind.resolve(self.target)));
+        }
+    }
+
+    /// The right operand is jumped over, if the left one already decides the
+    /// result. In that case, the result gets pushed as a constant instead.
+    fn logical(&mut self, node: &mut Binary) -> Result {
+        node.lhs.accept(self)?;
+        let short = self.emit(Instruction::JumpIfFalse(0));
+
+        let end = match node.operator {
+            BinaryOperator::LogAnd => {
+                node.rhs.accept(self)?;
+                let end = self.emit(Instruction::Jump(0));
+                self.patch(short);
+                self.emit_bool(false);
+                end
+            }
+            _ => {
+                self.emit_bool(true);
+                let end = self.emit(Instruction::Jump(0));
+                self.patch(short);
+                node.rhs.accept(self)?;
+                end
+            }
+        };
+        self.patch(end);
+
+        Self::default_result()
+    }
+
+    /// Evaluates the indices of an assignment target from left to right and
+    /// stores them in new local slots, so they can be used more than once.
+    fn indices(&mut self, target: &mut ExprKind) -> std::result::Result<Vec<u32>, CompileError> {
+        match target {
+            ExprKind::FieldAccess(node) => self.indices(&mut node.target),
+            ExprKind::Index(node) => {
+                let mut slots = self.indices(&mut node.target)?;
+                node.index.accept(self)?;
+
+                let function = self.function();
+                let slot = function.locals;
+                function.locals += 1;
+                self.emit(Instruction::SetLocal(slot));
+
+                slots.push(slot);
+                Ok(slots)
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Replaces the struct or array on top of the stack with the value the
+    /// step leads to.
+    fn get_step(&mut self, step: &Step) {
+        match *step {
+            Step::Field(field) => {
+                self.emit(Instruction::GetField(field));
+            }
+            Step::Index(slot) => {
+                self.emit(Instruction::GetLocal(slot));
+                self.emit(Instruction::GetIndex);
+            }
+        }
+    }
+
+    /// Tests the value the path of payload and element accesses leads to
+    /// inside of the scrutinee in the given slot against the pattern. Every
+    /// failing test jumps to the next arm, while the bindings get stored
+    /// right away.
+    fn pattern(
+        &mut self,
+        pattern: &Pattern,
+        scrutinee: u32,
+        path: &mut Vec<Instruction>,
+        fails: &mut Vec<usize>,
+    ) -> Result {
+        let load = |compiler: &mut Self, path: &[Instruction]| {
+            compiler.emit(Instruction::GetLocal(scrutinee));
+            for instruction in path.iter() {
+                compiler.emit(instruction.clone());
+            }
+        };
+
+        match pattern {
+            Pattern::Wildcard(_) => {}
+            Pattern::Binding(node) => {
+                let symbol = node.symbol.get().ok_or(NoSymbolFound::new(node.id.span))?;
+                load(self, path);
+                let instruction = self.declare(Rc::as_ptr(symbol));
+                self.emit(instruction);
+            }
+            Pattern::Literal(node) => {
+                let mut value = Value::literal(&node.literal, &self.interner.borrow(), self.target);
+                if node.negated {
+                    value = value
+                        .unary(UnaryOperator::Neg)
+                        .expect("The type checker only allows negated numbers.");
+                }
+
+                load(self, path);
+                let index = self.constant(value);
+                self.emit(Instruction::Constant(index));
+                self.emit(Instruction::Binary(BinaryOperator::Eq));
+                fails.push(self.emit(Instruction::JumpIfFalse(0)));
+            }
+            Pattern::Variant(node) => {
+                let index = *node.index.get().ok_or(NoSymbolFound::new(node.span))?;
+                load(self, path);
+                self.emit(Instruction::IsVariant(index as u32));
+                fails.push(self.emit(Instruction::JumpIfFalse(0)));
+
+                for (index, pattern) in node.patterns.iter().enumerate() {
+                    path.push(Instruction::GetPayload(index as u32));
+                    self.pattern(pattern, scrutinee, path, fails)?;
+                    path.pop();
+                }
+            }
+            Pattern::Tuple(node) => {
+                for (index, pattern) in node.patterns.iter().enumerate() {
+                    path.push(Instruction::GetField(index as u32));
+                    self.pattern(pattern, scrutinee, path, fails)?;
+                    path.pop();
+                }
+            }
+        }
+
+        Self::default_result()
+    }
+
+    fn emit_bool(&mut self, value: bool) {
+        let index = self.constant(Value::Bool(value));
+        self.emit(Instruction::Constant(index));
+    }
+
+    fn constant(&mut self, value: Value) -> u32 {
+        match self.constants.iter().position(|other| *other == value) {
+            Some(index) => index as u32,
+            None => {
+                self.constants.push(value);
+                self.constants.len() as u32 - 1
+            }
+        }
+    }
+}
+
+impl Visitor for Compiler {
+    type Return = ();
+    type Error = CompileError;
+
+    fn default_result() -> Result {
+        Ok(())
+    }
+
+    fn visit_stmt(&mut self, node: &mut StmtKind) -> Result {
+        let StmtKind::Block(block) = node else {
+            return node.walk(self);
+        };
+
+        block.accept(self)?;
+        if block.tail.as_ref().is_some_and(has_value) {
+            self.emit(Instruction::Pop);
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_expr_stmt(&mut self, node: &mut ExprStmt) -> Result {
+        node.expression.accept(self)?;
+        if has_value(&node.expression) {
+            self.emit(Instruction::Pop);
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_let_decl(&mut self, node: &mut LetDecl) -> Result {
+        // The symbol also knows the type of a declaration without one.
+        let symbol = node.symbol.get().ok_or(NoSymbolFound::new(node.id.span))?;
+        let kind = symbol
+            .borrow()
+            .type_
+            .get()
+            .map(|type_| type_.kind.clone())
+            .ok_or(NoTypeFound::new(node.id.span))?;
+
+        match node.expression {
+            Some(ref mut expression) => expression.accept(self)?,
+            None => {
+                let index = self.constant(Value::zero(kind.resolve(self.target)));
+                self.emit(Instruction::Constant(index));
+            }
+        }
+        self.cast(kind);
+
+        let instruction = self.declare(Rc::as_ptr(symbol));
+        self.emit(instruction);
+
+        Self::default_result()
+    }
+
+    fn visit_destructure_decl(&mut self, node: &mut DestructureDecl) -> Result {
+        // Just like the scrutinee of a match, the value is stored in a new
+        // local slot. The type checker made sure that the pattern can't fail.
+        node.expression.accept(self)?;
+        let function = self.function();
+        let value = function.locals;
+        function.locals += 1;
+        self.emit(Instruction::SetLocal(value));
+
+        self.pattern(&node.pattern, value, &mut Vec::new(), &mut Vec::new())
+    }
+
+    fn visit_fun_decl(&mut self, node: &mut Rc<RefCell<FunDecl>>) -> Result {
+        let index = *self
+            .indices
+            .get(&Rc::as_ptr(node))
+            .ok_or(UnknownSymbol::new(node.borrow().span))?;
+
+        // The block gets cloned, as the declaration can't be borrowed while
+        // the recursive calls inside of it get compiled.
+        let mut block = node.borrow().block.clone();
+
+        let mut locals = HashMap::new();
+        for (slot, parameter) in node.borrow().parameters.iter().enumerate() {
+            let symbol = parameter
+                .symbol
+                .get()
+                .ok_or(NoSymbolFound::new(parameter.span))?;
+            locals.insert(Rc::as_ptr(symbol), slot as u32);
+        }
+
+        self.state = Some(FunctionState {
+            function: std::mem::take(&mut self.functions[index as usize]),
+            return_type: node.borrow().type_.kind.clone(),
+            locals,
+            loops: Vec::new(),
+        });
+
+        // The value of the block is returned, just like a `return` would do.
+        let return_type = node.borrow().type_.kind.clone();
+        let result = block.accept(self).map(|_| {
+            if block.tail.as_ref().is_some_and(has_value) {
+                self.cast(return_type.clone());
+                self.emit(Instruction::Return);
+            } else if return_type == TypeKind::Void {
+                self.emit(Instruction::ReturnVoid);
+            }
+        });
+
+        let state = self.state.take().expect("The state got set before.");
+        self.functions[index as usize] = state.function;
+
+        result
+    }
+
+    fn visit_return(&mut self, node: &mut Return) -> Result {
+        let Some(ref mut expression) = node.expression else {
+            self.emit(Instruction::ReturnVoid);
+            return Self::default_result();
+        };
+
+        expression.accept(self)?;
+
+        if let Some(return_type) = self.state.as_ref().map(|state| state.return_type.clone()) {
+            self.cast(return_type);
+        }
+        self.emit(Instruction::Return);
+
+        Self::default_result()
+    }
+
+    fn visit_call(&mut self, node: &mut Call) -> Result {
+        let symbol = node
+            .symbol
+            .get()
+            .ok_or(NoSymbolFound::new(node.span))?
+            .clone();
+
+        let kind = symbol.borrow().kind.clone();
+        let fun_decl = match kind {
+            SymbolKind::Function(fun_decl) => fun_decl,
+            kind => return Err(InvalidSymbolKind::new(kind, "function", node.span).into()),
+        };
+
+        let index = *self
+            .indices
+            .get(&Rc::as_ptr(&fun_decl))
+            .ok_or(UnknownSymbol::new(node.span))?;
+        let parameters = fun_decl
+            .borrow()
+            .parameters
+            .iter()
+            .map(|parameter| parameter.type_.kind.clone())
+            .collect::<Vec<_>>();
+
+        for (argument, kind) in node.arguments.iter_mut().zip(parameters) {
+            argument.accept(self)?;
+            self.cast(kind);
+        }
+        self.emit(Instruction::Call(index));
+
+        Self::default_result()
+    }
+
+    fn visit_if(&mut self, node: &mut If) -> Result {
+        node.condition.accept(self)?;
+        let then = self.emit(Instruction::JumpIfFalse(0));
+
+        node.then.accept(self)?;
+
+        let Some(ref mut else_) = node.else_ else {
+            self.patch(then);
+            return Self::default_result();
+        };
+
+        let end = self.emit(Instruction::Jump(0));
+        self.patch(then);
+
+        else_.accept(self)?;
+        self.patch(end);
+
+        Self::default_result()
+    }
+
+    fn visit_match(&mut self, node: &mut Match) -> Result {
+        // The scrutinee is stored in a new local slot, as every arm needs it.
+        node.expression.accept(self)?;
+        let function = self.function();
+        let scrutinee = function.locals;
+        function.locals += 1;
+        self.emit(Instruction::SetLocal(scrutinee));
+
+        let mut ends = Vec::new();
+        for arm in node.arms.iter_mut() {
+            let mut fails = Vec::new();
+            self.pattern(&arm.pattern, scrutinee, &mut Vec::new(), &mut fails)?;
+
+            arm.expression.accept(self)?;
+            ends.push(self.emit(Instruction::Jump(0)));
+
+            for fail in fails {
+                self.patch(fail);
+            }
+        }
+
+        // The type checker made sure that the last arm is never left through
+        // a failing test, as the match is exhaustive.
+        for end in ends {
+            self.patch(end);
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_while(&mut self, node: &mut While) -> Result {
+        let start = self.function().code.len() as u32;
+
+        node.condition.accept(self)?;
+        let end = self.emit(Instruction::JumpIfFalse(0));
+
+        if let Some(ref mut state) = self.state {
+            state.loops.push(Loop {
+                start,
+                breaks: Vec::new(),
+            });
+        }
+
+        let result = node.block.accept(self);
+        let breaks = match self.state {
+            Some(ref mut state) => state.loops.pop().map(|loop_| loop_.breaks),
+            None => None,
+        };
+        result?;
+
+        if node.block.tail.as_ref().is_some_and(has_value) {
+            self.emit(Instruction::Pop);
+        }
+        self.emit(Instruction::Jump(start));
+
+        self.patch(end);
+        for jump in breaks.unwrap_or_default() {
+            self.patch(jump);
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_break(&mut self, _node: &mut Break) -> Result {
+        let jump = self.emit(Instruction::Jump(0));
+        if let Some(loop_) = self.state.as_mut().and_then(|state| state.loops.last_mut()) {
+            loop_.breaks.push(jump);
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_continue(&mut self, _node: &mut Continue) -> Result {
+        let start = self
+            .state
+            .as_ref()
+            .and_then(|state| state.loops.last())
+            .map(|loop_| loop_.start);
+
+        if let Some(start) = start {
+            self.emit(Instruction::Jump(start));
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_assign(&mut self, node: &mut Assign) -> Result {
+        let target_span = node.target.span();
+        let slots = self.indices(&mut node.target)?;
+        let (target, steps) =
+            place(&node.target, &mut slots.into_iter()).ok_or(NoSymbolFound::new(target_span))?;
+
+        let (get, set) = self.variable(target)?;
+        let kind = target
+            .symbol
+            .get()
+            .and_then(|symbol| symbol.borrow().type_.get().map(|type_| type_.kind.clone()))
+            .ok_or(NoSymbolFound::new(target_span))?;
+
+        // The value is evaluated before the target gets read, thus changes it
+        // makes to the target aren't overwritten by the stale copy. If the
+        // target needs to be read first, the value waits in a new local slot.
+        node.value.accept(self)?;
+        let value = match !steps.is_empty() || node.operator.binary().is_some() {
+            true => {
+                let function = self.function();
+                let slot = function.locals;
+                function.locals += 1;
+                self.emit(Instruction::SetLocal(slot));
+                Some(slot)
+            }
+            false => None,
+        };
+
+        // Every struct and array on the way to the assigned place gets pushed,
+        // so the updated values can be stored back into them from the inside
+        // out. The index of an array is pushed right after it.
+        for depth in 0..steps.len() {
+            self.emit(get.clone());
+            for step in &steps[..depth] {
+                self.get_step(step);
+            }
+            if let Step::Index(slot) = steps[depth] {
+                self.emit(Instruction::GetLocal(slot));
+            }
+        }
+
+        match node.operator.binary() {
+            Some(operator) => {
+                self.emit(get);
+                for step in steps.iter() {
+                    self.get_step(step);
+                }
+                if let Some(slot) = value {
+                    self.emit(Instruction::GetLocal(slot));
+                }
+                self.emit(Instruction::Binary(operator));
+            }
+            None => {
+                if let Some(slot) = value {
+                    self.emit(Instruction::GetLocal(slot));
+                }
+            }
+        }
+
+        // The type checker made sure that the value has the exact type of the
+        // field or element, thus only variables need to be converted.
+        match steps.is_empty() {
+            true => self.cast(kind),
+            false => steps.iter().rev().for_each(|step| {
+                self.emit(match *step {
+                    Step::Field(field) => Instruction::SetField(field),
+                    Step::Index(_) => Instruction::SetIndex,
+                });
+            }),
+        }
+        self.emit(set);
+
+        Self::default_result()
+    }
+
+    fn visit_struct_literal(&mut self, node: &mut StructLiteral) -> Result {
+        // The fields are evaluated in the order of their declaration, which
+        // is the order the struct stores them in.
+        let mut fields = node.fields.iter_mut().collect::<Vec<_>>();
+        fields.sort_by_key(|field| field.index.get().copied());
+
+        for field in fields.iter_mut() {
+            field.expression.accept(self)?;
+        }
+        self.emit(Instruction::Struct(fields.len() as u32));
+
+        Self::default_result()
+    }
+
+    fn visit_field_access(&mut self, node: &mut FieldAccess) -> Result {
+        node.target.accept(self)?;
+
+        let index = *node.index.get().ok_or(NoSymbolFound::new(node.span))?;
+        self.emit(Instruction::GetField(index as u32));
+
+        Self::default_result()
+    }
+
+    fn visit_variant_literal(&mut self, node: &mut VariantLiteral) -> Result {
+        let index = *node.index.get().ok_or(NoSymbolFound::new(node.span))?;
+        for argument in node.arguments.iter_mut() {
+            argument.accept(self)?;
+        }
+        self.emit(Instruction::Variant(
+            index as u32,
+            node.arguments.len() as u32,
+        ));
+
+        Self::default_result()
+    }
+
+    fn visit_index(&mut self, node: &mut Index) -> Result {
+        node.target.accept(self)?;
+        node.index.accept(self)?;
+        self.emit(Instruction::GetIndex);
+
+        Self::default_result()
+    }
+
+    fn visit_array_literal(&mut self, node: &mut ArrayLiteral) -> Result {
+        for element in node.elements.iter_mut() {
+            element.accept(self)?;
+        }
+        self.emit(Instruction::Array(node.elements.len() as u32));
+
+        Self::default_result()
+    }
+
+    fn visit_tuple_literal(&mut self, node: &mut TupleLiteral) -> Result {
+        // A tuple is nothing more than a struct, whose fields have no names.
+        for element in node.elements.iter_mut() {
+            element.accept(self)?;
+        }
+        self.emit(Instruction::Struct(node.elements.len() as u32));
+
+        Self::default_result()
+    }
+
+    fn visit_len(&mut self, node: &mut Len) -> Result {
+        node.expression.accept(self)?;
+        self.emit(Instruction::Len);
+        self.cast(TypeKind::INDEX);
+
+        Self::default_result()
+    }
+
+    fn visit_binary(&mut self, node: &mut Binary) -> Result {
+        if node.operator.is_logical() {
+            return self.logical(node);
+        }
+
+        node.lhs.accept(self)?;
+        node.rhs.accept(self)?;
+        self.emit(Instruction::Binary(node.operator));
+
+        Self::default_result()
+    }
+
+    fn visit_unary(&mut self, node: &mut Unary) -> Result {
+        node.expression.accept(self)?;
+        self.emit(Instruction::Unary(node.operator));
+
+        Self::default_result()
+    }
+
+    fn visit_cast(&mut self, node: &mut Cast) -> Result {
+        node.expression.accept(self)?;
+        self.cast(node.type_.kind.clone());
+
+        Self::default_result()
+    }
+
+    fn visit_literal(&mut self, node: &mut Literal) -> Result {
+        let value = Value::literal(node, &self.interner.borrow(), self.target);
+        let index = self.constant(value);
+        self.emit(Instruction::Constant(index));
+
+        Self::default_result()
+    }
+
+    fn visit_id(&mut self, node: &mut Id) -> Result {
+        let (get, _) = self.variable(node)?;
+        self.emit(get);
+
+        Self::default_result()
+    }
+}
+
+/// A single step from a variable towards the place an assignment changes.
+#[derive(Debug)]
+enum Step {
+    /// The index of the field.
+    Field(u32),
+    /// The local slot the evaluated index got stored in.
+    Index(u32),
+}
+
+/// Splits an assignment target into its variable and the steps, which lead
+/// to the assigned place. The slots of the indices are used in their order.
+fn place<'a>(
+    target: &'a ExprKind,
+    slots: &mut impl Iterator<Item = u32>,
+) -> Option<(&'a Id, Vec<Step>)> {
+    match target {
+        ExprKind::Id(id) => Some((id, Vec::new())),
+        ExprKind::FieldAccess(node) => {
+            let (id, mut steps) = place(&node.target, slots)?;
+            steps.push(Step::Field(*node.index.get()? as u32));
+            Some((id, steps))
+        }
+        ExprKind::Index(node) => {
+            let (id, mut steps) = place(&node.target, slots)?;
+            steps.push(Step::Index(slots.next()?));
+            Some((id, steps))
+        }
+        _ => None,
+    }
+}
+
+/// Whether the expression leaves a value on the stack. The type checker
+/// already made sure that the branches of an if agree on it.
+fn has_value(expression: &ExprKind) -> bool {
+    match expression {
+        ExprKind::Call(node) => {
+            let symbol = node.symbol.get();
+            let type_ = symbol
+                .and_then(|symbol| symbol.borrow().type_.get().map(|type_| type_.kind.clone()));
+            type_ != Some(TypeKind::Void)
+        }
+        ExprKind::If(node) => node.then.tail.as_ref().is_some_and(has_value),
+        ExprKind::Match(node) => node
+            .arms
+            .first()
+            .is_some_and(|arm| has_value(&arm.expression)),
+        ExprKind::Block(node) => node.tail.as_ref().is_some_and(has_value),
+        ExprKind::Assign(_) => false,
+        _ => true,
+    }
+}
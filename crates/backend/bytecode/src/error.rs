@@ -0,0 +1,269 @@
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+
+use lasso::Rodeo;
+
+use diagnostics::{
+    positional::LabelSpan,
+    report::{LabelBuilder, Report, ReportBuilder, Reportable, Serverity},
+};
+
+use name_resolution::error::InvalidSymbolKind;
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct NoMainFunction;
+
+impl From<NoMainFunction> for CompileError {
+    fn from(value: NoMainFunction) -> Self {
+        Self::NoMainFunction(value)
+    }
+}
+
+impl Reportable for NoMainFunction {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("Couldn't find a 'main' function to start the program with.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct NoSymbolFound {
+    span: LabelSpan,
+}
+
+impl NoSymbolFound {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<NoSymbolFound> for CompileError {
+    fn from(value: NoSymbolFound) -> Self {
+        Self::InternalError(InternalError::NoSymbolFound(value))
+    }
+}
+
+impl Reportable for NoSymbolFound {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("Couldn't find a symbol for this node:")
+            .code(2)
+            .serverity(Serverity::Bug)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct NoTypeFound {
+    span: LabelSpan,
+}
+
+impl NoTypeFound {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<NoTypeFound> for CompileError {
+    fn from(value: NoTypeFound) -> Self {
+        Self::InternalError(InternalError::NoTypeFound(value))
+    }
+}
+
+impl Reportable for NoTypeFound {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("Couldn't find a type for this node:")
+            .code(2)
+            .serverity(Serverity::Bug)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct UnknownSymbol {
+    span: LabelSpan,
+}
+
+impl UnknownSymbol {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<UnknownSymbol> for CompileError {
+    fn from(value: UnknownSymbol) -> Self {
+        Self::InternalError(InternalError::UnknownSymbol(value))
+    }
+}
+
+impl Reportable for UnknownSymbol {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("No slot got assigned to the symbol of this node:")
+            .code(2)
+            .serverity(Serverity::Bug)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+impl From<InvalidSymbolKind> for CompileError {
+    fn from(value: InvalidSymbolKind) -> Self {
+        Self::InternalError(InternalError::InvalidSymbolKind(value))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub enum CompileError {
+    NoMainFunction(NoMainFunction),
+    InternalError(InternalError),
+}
+
+impl Reportable for CompileError {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        match self {
+            Self::NoMainFunction(error) => error.into_report(interner),
+            Self::InternalError(error) => error.into_report(interner),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub enum InternalError {
+    NoSymbolFound(NoSymbolFound),
+    NoTypeFound(NoTypeFound),
+    UnknownSymbol(UnknownSymbol),
+    InvalidSymbolKind(InvalidSymbolKind),
+}
+
+impl Reportable for InternalError {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        match self {
+            Self::NoSymbolFound(error) => error.into_report(interner),
+            Self::NoTypeFound(error) => error.into_report(interner),
+            Self::UnknownSymbol(error) => error.into_report(interner),
+            Self::InvalidSymbolKind(error) => error.into_report(interner),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, PartialEq)]
+pub struct InvalidHeader;
+
+impl From<InvalidHeader> for DecodeError {
+    fn from(value: InvalidHeader) -> Self {
+        Self::InvalidHeader(value)
+    }
+}
+
+impl Reportable for InvalidHeader {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("The file doesn't start with the header of an Arkoi bytecode file.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, PartialEq)]
+pub struct UnsupportedVersion {
+    version: u16,
+    supported: u16,
+}
+
+impl UnsupportedVersion {
+    pub fn new(version: u16, supported: u16) -> Self {
+        Self { version, supported }
+    }
+}
+
+impl From<UnsupportedVersion> for DecodeError {
+    fn from(value: UnsupportedVersion) -> Self {
+        Self::UnsupportedVersion(value)
+    }
+}
+
+impl Reportable for UnsupportedVersion {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        let message = format!(
+            "The bytecode has the version '{}' but only the version '{}' is supported.",
+            self.version, self.supported
+        );
+
+        ReportBuilder::default()
+            .message(message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, PartialEq)]
+pub struct MalformedBytecode {
+    offset: usize,
+}
+
+impl MalformedBytecode {
+    pub fn new(offset: usize) -> Self {
+        Self { offset }
+    }
+}
+
+impl From<MalformedBytecode> for DecodeError {
+    fn from(value: MalformedBytecode) -> Self {
+        Self::MalformedBytecode(value)
+    }
+}
+
+impl Reportable for MalformedBytecode {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        let message = format!("The bytecode is malformed at the offset '{}'.", self.offset);
+
+        ReportBuilder::default()
+            .message(message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, PartialEq)]
+pub enum DecodeError {
+    InvalidHeader(InvalidHeader),
+    UnsupportedVersion(UnsupportedVersion),
+    MalformedBytecode(MalformedBytecode),
+}
+
+impl Reportable for DecodeError {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        match self {
+            Self::InvalidHeader(error) => error.into_report(interner),
+            Self::UnsupportedVersion(error) => error.into_report(interner),
+            Self::MalformedBytecode(error) => error.into_report(interner),
+        }
+    }
+}
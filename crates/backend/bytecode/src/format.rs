@@ -0,0 +1,558 @@
+//! The binary file format of a compiled module. All numbers are stored in
+//! little endian and every file starts with the [`MAGIC`] bytes followed by
+//! the [`VERSION`] of the format, which has to be increased on every change.
+
+use ast::{BinaryOperator, TypeKind, UnaryOperator};
+use value::Value;
+
+use crate::{
+    error::{DecodeError, InvalidHeader, MalformedBytecode, UnsupportedVersion},
+    instruction::Instruction,
+    module::{Function, Module},
+};
+
+pub const MAGIC: &[u8; 4] = b"ARKB";
+pub const VERSION: u16 = 7;
+
+/// Marks an array value, which follows the codes of the types.
+const ARRAY: u8 = 4;
+
+type Result<T> = std::result::Result<T, DecodeError>;
+
+impl Module {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut writer = Writer::default();
+
+        writer.bytes(MAGIC);
+        writer.u16(VERSION);
+
+        writer.u32(self.globals);
+        writer.u32(self.main);
+
+        writer.u32(self.constants.len() as u32);
+        self.constants.iter().for_each(|value| writer.value(value));
+
+        writer.function(&self.init);
+        writer.u32(self.functions.len() as u32);
+        self.functions
+            .iter()
+            .for_each(|function| writer.function(function));
+
+        writer.buffer
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Module> {
+        if !bytes.starts_with(MAGIC) {
+            return Err(InvalidHeader.into());
+        }
+
+        let mut reader = Reader::new(bytes, MAGIC.len());
+
+        let version = reader.u16()?;
+        if version != VERSION {
+            return Err(UnsupportedVersion::new(version, VERSION).into());
+        }
+
+        let globals = reader.u32()?;
+        let main_offset = reader.offset;
+        let main = reader.u32()?;
+
+        let length = reader.u32()?;
+        let constants = (0..length)
+            .map(|_| reader.value())
+            .collect::<Result<Vec<_>>>()?;
+
+        let init = reader.function()?;
+        let length = reader.u32()?;
+        let functions = (0..length)
+            .map(|_| reader.function())
+            .collect::<Result<Vec<_>>>()?;
+
+        if main as usize >= functions.len() {
+            return Err(MalformedBytecode::new(main_offset).into());
+        }
+
+        if reader.offset != bytes.len() {
+            return Err(MalformedBytecode::new(reader.offset).into());
+        }
+
+        Ok(Module {
+            constants,
+            globals,
+            init,
+            functions,
+            main,
+        })
+    }
+}
+
+#[derive(Default)]
+struct Writer {
+    buffer: Vec<u8>,
+}
+
+impl Writer {
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    fn u8(&mut self, value: u8) {
+        self.buffer.push(value);
+    }
+
+    fn u16(&mut self, value: u16) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn string(&mut self, value: &str) {
+        self.u32(value.len() as u32);
+        self.bytes(value.as_bytes());
+    }
+
+    fn type_kind(&mut self, kind: TypeKind) {
+        match kind {
+            TypeKind::Int(signed, size) => {
+                self.u8(0);
+                self.u8(signed as u8);
+                self.u8(size as u8);
+            }
+            TypeKind::Decimal(size) => {
+                self.u8(1);
+                self.u8(size as u8);
+            }
+            TypeKind::Bool => self.u8(2),
+            TypeKind::Str => self.u8(3),
+            TypeKind::Size(_) => unreachable!("The compiler resolves pointer-sized integers."),
+            TypeKind::Struct(_)
+            | TypeKind::Enum(_)
+            | TypeKind::Array(_, _)
+            | TypeKind::Slice(_)
+            | TypeKind::Tuple(_) => {
+                unreachable!("Structs, enums, arrays and tuples are never cast.")
+            }
+            TypeKind::Void => unreachable!("There are no void values."),
+        }
+    }
+
+    fn value(&mut self, value: &Value) {
+        match value {
+            Value::Int(value, signed, size) => {
+                self.type_kind(TypeKind::Int(*signed, *size));
+                self.bytes(&value.to_le_bytes());
+            }
+            Value::Decimal(value, size) => {
+                self.type_kind(TypeKind::Decimal(*size));
+                self.bytes(&value.to_le_bytes());
+            }
+            Value::Bool(value) => {
+                self.type_kind(TypeKind::Bool);
+                self.u8(*value as u8);
+            }
+            Value::Str(value) => {
+                self.type_kind(TypeKind::Str);
+                self.string(value);
+            }
+            Value::Array(values) => {
+                self.u8(ARRAY);
+                self.u32(values.len() as u32);
+                values.iter().for_each(|value| self.value(value));
+            }
+            Value::Struct(_) | Value::Variant(_, _) => {
+                unreachable!("Structs and variants are never constants.")
+            }
+        }
+    }
+
+    fn function(&mut self, function: &Function) {
+        self.string(&function.name);
+        self.u32(function.arity);
+        self.u32(function.locals);
+
+        self.u32(function.code.len() as u32);
+        function
+            .code
+            .iter()
+            .for_each(|instruction| self.instruction(instruction.clone()));
+    }
+
+    fn instruction(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Constant(index) => {
+                self.u8(0);
+                self.u32(index);
+            }
+            Instruction::GetLocal(index) => {
+                self.u8(1);
+                self.u32(index);
+            }
+            Instruction::SetLocal(index) => {
+                self.u8(2);
+                self.u32(index);
+            }
+            Instruction::GetGlobal(index) => {
+                self.u8(3);
+                self.u32(index);
+            }
+            Instruction::SetGlobal(index) => {
+                self.u8(4);
+                self.u32(index);
+            }
+            Instruction::Binary(operator) => {
+                self.u8(5);
+                self.u8(binary_operator_code(operator));
+            }
+            Instruction::Unary(operator) => {
+                self.u8(6);
+                self.u8(unary_operator_code(operator));
+            }
+            Instruction::Cast(kind) => {
+                self.u8(7);
+                self.type_kind(kind);
+            }
+            Instruction::Call(index) => {
+                self.u8(8);
+                self.u32(index);
+            }
+            Instruction::Return => self.u8(9),
+            Instruction::Pop => self.u8(10),
+            Instruction::Jump(target) => {
+                self.u8(11);
+                self.u32(target);
+            }
+            Instruction::JumpIfFalse(target) => {
+                self.u8(12);
+                self.u32(target);
+            }
+            Instruction::Struct(length) => {
+                self.u8(13);
+                self.u32(length);
+            }
+            Instruction::GetField(index) => {
+                self.u8(14);
+                self.u32(index);
+            }
+            Instruction::SetField(index) => {
+                self.u8(15);
+                self.u32(index);
+            }
+            Instruction::ReturnVoid => self.u8(16),
+            Instruction::Array(length) => {
+                self.u8(17);
+                self.u32(length);
+            }
+            Instruction::GetIndex => self.u8(18),
+            Instruction::SetIndex => self.u8(19),
+            Instruction::Len => self.u8(20),
+            Instruction::Variant(index, length) => {
+                self.u8(21);
+                self.u32(index);
+                self.u32(length);
+            }
+            Instruction::IsVariant(index) => {
+                self.u8(22);
+                self.u32(index);
+            }
+            Instruction::GetPayload(index) => {
+                self.u8(23);
+                self.u32(index);
+            }
+        }
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8], offset: usize) -> Self {
+        Self { bytes, offset }
+    }
+
+    fn malformed<T>(&self) -> Result<T> {
+        Err(MalformedBytecode::new(self.offset).into())
+    }
+
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let Some(bytes) = self.bytes.get(self.offset..self.offset + N) else {
+            return self.malformed();
+        };
+
+        self.offset += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes::<1>()?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_le_bytes(self.bytes()?))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.bytes()?))
+    }
+
+    fn bool(&mut self) -> Result<bool> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => self.malformed(),
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let length = self.u32()? as usize;
+        let Some(bytes) = self.bytes.get(self.offset..self.offset + length) else {
+            return self.malformed();
+        };
+
+        let Ok(string) = String::from_utf8(bytes.to_vec()) else {
+            return self.malformed();
+        };
+
+        self.offset += length;
+        Ok(string)
+    }
+
+    fn type_kind(&mut self) -> Result<TypeKind> {
+        match self.u8()? {
+            0 => Ok(TypeKind::Int(self.bool()?, self.size(&[8, 16, 32, 64])?)),
+            1 => Ok(TypeKind::Decimal(self.size(&[32, 64])?)),
+            2 => Ok(TypeKind::Bool),
+            3 => Ok(TypeKind::Str),
+            _ => self.malformed(),
+        }
+    }
+
+    fn size(&mut self, allowed: &[usize]) -> Result<usize> {
+        let size = self.u8()? as usize;
+        match allowed.contains(&size) {
+            true => Ok(size),
+            false => self.malformed(),
+        }
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        if self.bytes.get(self.offset) == Some(&ARRAY) {
+            self.offset += 1;
+            let length = self.u32()?;
+            let values = (0..length)
+                .map(|_| self.value())
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(Value::Array(values));
+        }
+
+        match self.type_kind()? {
+            TypeKind::Int(signed, size) => {
+                let value = i128::from_le_bytes(self.bytes()?);
+                Ok(Value::int(value, signed, size))
+            }
+            TypeKind::Decimal(size) => {
+                let value = f64::from_le_bytes(self.bytes()?);
+                Ok(Value::decimal(value, size))
+            }
+            TypeKind::Bool => Ok(Value::Bool(self.bool()?)),
+            TypeKind::Str => Ok(Value::Str(self.string()?.into())),
+            TypeKind::Size(_) => unreachable!("Pointer-sized integers are never decoded."),
+            TypeKind::Struct(_)
+            | TypeKind::Enum(_)
+            | TypeKind::Array(_, _)
+            | TypeKind::Slice(_)
+            | TypeKind::Tuple(_) => {
+                unreachable!("Structs, enums, arrays and tuples are never decoded as a type.")
+            }
+            TypeKind::Void => unreachable!("There are no void values."),
+        }
+    }
+
+    fn function(&mut self) -> Result<Function> {
+        let name = self.string()?;
+        let arity = self.u32()?;
+        let locals = self.u32()?;
+
+        let length = self.u32()?;
+        let code = (0..length)
+            .map(|_| self.instruction())
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Function {
+            name,
+            arity,
+            locals,
+            code,
+        })
+    }
+
+    fn instruction(&mut self) -> Result<Instruction> {
+        let offset = self.offset;
+        Ok(match self.u8()? {
+            0 => Instruction::Constant(self.u32()?),
+            1 => Instruction::GetLocal(self.u32()?),
+            2 => Instruction::SetLocal(self.u32()?),
+            3 => Instruction::GetGlobal(self.u32()?),
+            4 => Instruction::SetGlobal(self.u32()?),
+            5 => match binary_operator(self.u8()?) {
+                Some(operator) => Instruction::Binary(operator),
+                None => return Err(MalformedBytecode::new(offset).into()),
+            },
+            6 => match unary_operator(self.u8()?) {
+                Some(operator) => Instruction::Unary(operator),
+                None => return Err(MalformedBytecode::new(offset).into()),
+            },
+            7 => Instruction::Cast(self.type_kind()?),
+            8 => Instruction::Call(self.u32()?),
+            9 => Instruction::Return,
+            10 => Instruction::Pop,
+            11 => Instruction::Jump(self.u32()?),
+            12 => Instruction::JumpIfFalse(self.u32()?),
+            13 => Instruction::Struct(self.u32()?),
+            14 => Instruction::GetField(self.u32()?),
+            15 => Instruction::SetField(self.u32()?),
+            16 => Instruction::ReturnVoid,
+            17 => Instruction::Array(self.u32()?),
+            18 => Instruction::GetIndex,
+            19 => Instruction::SetIndex,
+            20 => Instruction::Len,
+            21 => Instruction::Variant(self.u32()?, self.u32()?),
+            22 => Instruction::IsVariant(self.u32()?),
+            23 => Instruction::GetPayload(self.u32()?),
+            _ => return Err(MalformedBytecode::new(offset).into()),
+        })
+    }
+}
+
+const BINARY_OPERATORS: [BinaryOperator; 16] = [
+    BinaryOperator::Eq,
+    BinaryOperator::NotEq,
+    BinaryOperator::Greater,
+    BinaryOperator::GreaterEq,
+    BinaryOperator::Less,
+    BinaryOperator::LessEq,
+    BinaryOperator::Add,
+    BinaryOperator::Sub,
+    BinaryOperator::Mul,
+    BinaryOperator::Div,
+    BinaryOperator::Rem,
+    BinaryOperator::BitOr,
+    BinaryOperator::BitXor,
+    BinaryOperator::BitAnd,
+    BinaryOperator::Shl,
+    BinaryOperator::Shr,
+];
+
+const UNARY_OPERATORS: [UnaryOperator; 3] = [
+    UnaryOperator::Neg,
+    UnaryOperator::LogNeg,
+    UnaryOperator::BitNot,
+];
+
+fn binary_operator_code(operator: BinaryOperator) -> u8 {
+    BINARY_OPERATORS
+        .iter()
+        .position(|other| *other == operator)
+        .expect("Every binary operator needs a code.") as u8
+}
+
+fn binary_operator(code: u8) -> Option<BinaryOperator> {
+    BINARY_OPERATORS.get(code as usize).copied()
+}
+
+fn unary_operator_code(operator: UnaryOperator) -> u8 {
+    UNARY_OPERATORS
+        .iter()
+        .position(|other| *other == operator)
+        .expect("Every unary operator needs a code.") as u8
+}
+
+fn unary_operator(code: u8) -> Option<UnaryOperator> {
+    UNARY_OPERATORS.get(code as usize).copied()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn module() -> Module {
+        let mut main = Function::new("main", 0);
+        main.locals = 1;
+        main.code = vec![
+            Instruction::Constant(0),
+            Instruction::Cast(TypeKind::Int(true, 32)),
+            Instruction::SetLocal(0),
+            Instruction::GetLocal(0),
+            Instruction::GetGlobal(0),
+            Instruction::Binary(BinaryOperator::Add),
+            Instruction::Constant(2),
+            Instruction::JumpIfFalse(10),
+            Instruction::Struct(2),
+            Instruction::GetField(1),
+            Instruction::SetField(0),
+            Instruction::Array(2),
+            Instruction::GetIndex,
+            Instruction::SetIndex,
+            Instruction::Len,
+            Instruction::Variant(1, 2),
+            Instruction::IsVariant(1),
+            Instruction::GetPayload(0),
+            Instruction::ReturnVoid,
+            Instruction::Return,
+        ];
+
+        let mut init = Function::new("init", 0);
+        init.code = vec![Instruction::Constant(1), Instruction::SetGlobal(0)];
+
+        Module {
+            constants: vec![
+                Value::Int(-2, true, 32),
+                Value::Decimal(4.25, 64),
+                Value::Bool(true),
+                Value::Str("\"Hello\"\n".into()),
+                Value::Array(vec![Value::Bool(false), Value::Bool(true)]),
+            ],
+            globals: 1,
+            init,
+            functions: vec![main],
+            main: 0,
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let module = module();
+        let bytes = module.encode();
+        assert_eq!(Module::decode(&bytes), Ok(module));
+    }
+
+    #[test]
+    fn invalid_header() {
+        let bytes = b"ELF\0".to_vec();
+        assert_eq!(Module::decode(&bytes), Err(InvalidHeader.into()));
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let mut bytes = module().encode();
+        bytes[MAGIC.len()] = 0xFF;
+        assert!(matches!(
+            Module::decode(&bytes),
+            Err(DecodeError::UnsupportedVersion(_))
+        ));
+    }
+
+    #[test]
+    fn truncated() {
+        let bytes = module().encode();
+        let bytes = &bytes[..bytes.len() - 1];
+        assert!(matches!(
+            Module::decode(bytes),
+            Err(DecodeError::MalformedBytecode(_))
+        ));
+    }
+}
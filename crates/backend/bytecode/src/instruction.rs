@@ -0,0 +1,57 @@
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+
+use ast::{BinaryOperator, TypeKind, UnaryOperator};
+
+/// The instructions of the stack machine. Every operand is taken from the
+/// top of the stack and every result is pushed back onto it.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instruction {
+    /// Pushes the constant with the given index of the module.
+    Constant(u32),
+    /// Pushes the value of the local slot of the current frame.
+    GetLocal(u32),
+    /// Pops the top value into the local slot of the current frame.
+    SetLocal(u32),
+    GetGlobal(u32),
+    SetGlobal(u32),
+    Binary(BinaryOperator),
+    Unary(UnaryOperator),
+    /// Converts the top value to the given type.
+    Cast(TypeKind),
+    /// Calls the function with the given index, its arguments are on the stack.
+    Call(u32),
+    /// Pops the return value and continues with the calling frame.
+    Return,
+    /// Continues with the calling frame, as the function doesn't return a value.
+    ReturnVoid,
+    Pop,
+    /// Continues with the instruction at the given index of the current function.
+    Jump(u32),
+    /// Pops the condition and jumps to the given index if it's false.
+    JumpIfFalse(u32),
+    /// Pops the given amount of field values and pushes them as a struct.
+    Struct(u32),
+    /// Pops a struct and pushes the value of the field with the given index.
+    GetField(u32),
+    /// Pops a value and a struct and pushes the struct with the value stored
+    /// in the field with the given index.
+    SetField(u32),
+    /// Pops the given amount of element values and pushes them as an array.
+    Array(u32),
+    /// Pops an index and an array and pushes the element at the index.
+    GetIndex,
+    /// Pops a value, an index and an array and pushes the array with the
+    /// value stored at the index.
+    SetIndex,
+    /// Pops an array and pushes its amount of elements as an `u64`.
+    Len,
+    /// Pops the given amount of values and pushes them as the variant with
+    /// the given position in the declaration of its enum.
+    Variant(u32, u32),
+    /// Pops a variant and pushes whether it's the one with the given position.
+    IsVariant(u32),
+    /// Pops a variant and pushes the value it carries at the given index.
+    GetPayload(u32),
+}
@@ -0,0 +1,9 @@
+pub mod compiler;
+pub mod error;
+pub mod format;
+pub mod instruction;
+pub mod module;
+
+pub use crate::compiler::*;
+pub use crate::instruction::*;
+pub use crate::module::*;
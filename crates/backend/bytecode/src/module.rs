@@ -0,0 +1,39 @@
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+
+use value::Value;
+
+use crate::instruction::Instruction;
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Function {
+    pub name: String,
+    pub arity: u32,
+    /// The amount of local slots, which includes the parameters.
+    pub locals: u32,
+    pub code: Vec<Instruction>,
+}
+
+impl Function {
+    pub fn new(name: impl Into<String>, arity: u32) -> Self {
+        Self {
+            name: name.into(),
+            arity,
+            locals: arity,
+            code: Vec::new(),
+        }
+    }
+}
+
+/// A whole compiled program. The `init` function evaluates the global
+/// declarations and is run once before the `main` function gets called.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Module {
+    pub constants: Vec<Value>,
+    pub globals: u32,
+    pub init: Function,
+    pub functions: Vec<Function>,
+    pub main: u32,
+}
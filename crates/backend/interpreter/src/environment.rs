@@ -0,0 +1,100 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use ast::symbol::Symbol;
+
+use value::Value;
+
+/// The symbols are already unique per declaration after the name resolution,
+/// thus their address is enough to identify the value of a variable.
+type SymbolRef = *const RefCell<Symbol>;
+
+#[derive(Debug, Default, Clone)]
+pub struct Scope {
+    values: HashMap<SymbolRef, Value>,
+}
+
+impl Scope {
+    pub fn define(&mut self, symbol: &Rc<RefCell<Symbol>>, value: Value) {
+        self.values.insert(Rc::as_ptr(symbol), value);
+    }
+
+    pub fn get(&self, symbol: &Rc<RefCell<Symbol>>) -> Option<Value> {
+        self.values.get(&Rc::as_ptr(symbol)).cloned()
+    }
+}
+
+/// Every function call gets its own frame, which consists of the scopes of
+/// its blocks. Only the globals are shared between the frames.
+#[derive(Debug, Default, Clone)]
+pub struct Environment {
+    globals: Scope,
+    frames: Vec<Vec<Scope>>,
+}
+
+impl Environment {
+    pub fn push_frame(&mut self) {
+        self.frames.push(vec![Scope::default()]);
+    }
+
+    pub fn pop_frame(&mut self) {
+        self.frames.pop();
+    }
+
+    pub fn enter(&mut self) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.push(Scope::default());
+        }
+    }
+
+    pub fn exit(&mut self) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.pop();
+        }
+    }
+
+    pub fn define(&mut self, symbol: &Rc<RefCell<Symbol>>, value: Value) {
+        let scope = match self.frames.last_mut() {
+            Some(frame) => frame
+                .last_mut()
+                .expect("There should at least be one scope per frame."),
+            None => &mut self.globals,
+        };
+
+        scope.define(symbol, value);
+    }
+
+    /// Overwrites the value of an already defined variable. Returns `false`
+    /// if the variable couldn't be found.
+    pub fn assign(&mut self, symbol: &Rc<RefCell<Symbol>>, value: Value) -> bool {
+        let key = Rc::as_ptr(symbol);
+
+        if let Some(frame) = self.frames.last_mut() {
+            for scope in frame.iter_mut().rev() {
+                if let Some(slot) = scope.values.get_mut(&key) {
+                    *slot = value;
+                    return true;
+                }
+            }
+        }
+
+        match self.globals.values.get_mut(&key) {
+            Some(slot) => {
+                *slot = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn get(&self, symbol: &Rc<RefCell<Symbol>>) -> Option<Value> {
+        if let Some(frame) = self.frames.last() {
+            for scope in frame.iter().rev() {
+                if let Some(value) = scope.get(symbol) {
+                    return Some(value);
+                }
+            }
+        }
+
+        self.globals.get(symbol)
+    }
+}
@@ -0,0 +1,339 @@
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+
+use lasso::{Rodeo, Spur};
+
+use diagnostics::{
+    positional::LabelSpan,
+    report::{LabelBuilder, Report, ReportBuilder, Reportable, Serverity},
+};
+
+use name_resolution::error::InvalidSymbolKind;
+use value::Value;
+
+pub type Result = std::result::Result<Option<Value>, Interrupt>;
+
+/// Stops the evaluation of the current function or loop, either because of
+/// control flow or because of an error that needs to be reported.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub enum Interrupt {
+    Return(Option<Value>),
+    Break,
+    Continue,
+    Error(InterpreterError),
+}
+
+impl From<InterpreterError> for Interrupt {
+    fn from(value: InterpreterError) -> Self {
+        Self::Error(value)
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct NoMainFunction;
+
+impl From<NoMainFunction> for InterpreterError {
+    fn from(value: NoMainFunction) -> Self {
+        Self::NoMainFunction(value)
+    }
+}
+
+impl Reportable for NoMainFunction {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("Couldn't find a 'main' function to start the program with.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct DivisionByZero {
+    span: LabelSpan,
+}
+
+impl DivisionByZero {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<DivisionByZero> for InterpreterError {
+    fn from(value: DivisionByZero) -> Self {
+        Self::DivisionByZero(value)
+    }
+}
+
+impl Reportable for DivisionByZero {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("Attempted to divide by zero.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message("The divisor of this expression is zero")
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct MissingReturn {
+    name: Spur,
+    span: LabelSpan,
+}
+
+impl MissingReturn {
+    pub fn new(name: Spur, span: LabelSpan) -> Self {
+        Self { name, span }
+    }
+}
+
+impl From<MissingReturn> for InterpreterError {
+    fn from(value: MissingReturn) -> Self {
+        Self::MissingReturn(value)
+    }
+}
+
+impl Reportable for MissingReturn {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let message = format!(
+            "The function '{}' reached its end without returning a value.",
+            interner.resolve(&self.name)
+        );
+
+        ReportBuilder::default()
+            .message(message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct IndexOutOfBounds {
+    index: i128,
+    length: usize,
+    span: LabelSpan,
+}
+
+impl IndexOutOfBounds {
+    pub fn new(index: i128, length: usize, span: LabelSpan) -> Self {
+        Self {
+            index,
+            length,
+            span,
+        }
+    }
+}
+
+impl From<IndexOutOfBounds> for InterpreterError {
+    fn from(value: IndexOutOfBounds) -> Self {
+        Self::IndexOutOfBounds(value)
+    }
+}
+
+impl Reportable for IndexOutOfBounds {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message(format!(
+                "The index {} is out of bounds for a length of {}.",
+                self.index, self.length
+            ))
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message("This index is out of bounds")
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub enum InterpreterError {
+    NoMainFunction(NoMainFunction),
+    DivisionByZero(DivisionByZero),
+    MissingReturn(MissingReturn),
+    IndexOutOfBounds(IndexOutOfBounds),
+    InternalError(InternalError),
+}
+
+impl Reportable for InterpreterError {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        match self {
+            Self::NoMainFunction(error) => error.into_report(interner),
+            Self::DivisionByZero(error) => error.into_report(interner),
+            Self::MissingReturn(error) => error.into_report(interner),
+            Self::IndexOutOfBounds(error) => error.into_report(interner),
+            Self::InternalError(error) => error.into_report(interner),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct NoSymbolFound {
+    span: LabelSpan,
+}
+
+impl NoSymbolFound {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<NoSymbolFound> for InterpreterError {
+    fn from(value: NoSymbolFound) -> Self {
+        Self::InternalError(InternalError::NoSymbolFound(value))
+    }
+}
+
+impl Reportable for NoSymbolFound {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("Couldn't find a symbol for this node:")
+            .code(2)
+            .serverity(Serverity::Bug)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct NoTypeFound {
+    span: LabelSpan,
+}
+
+impl NoTypeFound {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<NoTypeFound> for InterpreterError {
+    fn from(value: NoTypeFound) -> Self {
+        Self::InternalError(InternalError::NoTypeFound(value))
+    }
+}
+
+impl Reportable for NoTypeFound {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("Couldn't find a type for this node:")
+            .code(2)
+            .serverity(Serverity::Bug)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct NoValueFound {
+    span: LabelSpan,
+}
+
+impl NoValueFound {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<NoValueFound> for InterpreterError {
+    fn from(value: NoValueFound) -> Self {
+        Self::InternalError(InternalError::NoValueFound(value))
+    }
+}
+
+impl Reportable for NoValueFound {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("Couldn't evaluate a value for this node:")
+            .code(2)
+            .serverity(Serverity::Bug)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct InvalidOperands {
+    span: LabelSpan,
+}
+
+impl InvalidOperands {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<InvalidOperands> for InterpreterError {
+    fn from(value: InvalidOperands) -> Self {
+        Self::InternalError(InternalError::InvalidOperands(value))
+    }
+}
+
+impl Reportable for InvalidOperands {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("The operands of this operation didn't pass the type checker:")
+            .code(2)
+            .serverity(Serverity::Bug)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+impl From<InvalidSymbolKind> for InterpreterError {
+    fn from(value: InvalidSymbolKind) -> Self {
+        Self::InternalError(InternalError::InvalidSymbolKind(value))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub enum InternalError {
+    NoSymbolFound(NoSymbolFound),
+    NoTypeFound(NoTypeFound),
+    NoValueFound(NoValueFound),
+    InvalidOperands(InvalidOperands),
+    InvalidSymbolKind(InvalidSymbolKind),
+}
+
+impl Reportable for InternalError {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        match self {
+            Self::NoSymbolFound(error) => error.into_report(interner),
+            Self::NoTypeFound(error) => error.into_report(interner),
+            Self::NoValueFound(error) => error.into_report(interner),
+            Self::InvalidOperands(error) => error.into_report(interner),
+            Self::InvalidSymbolKind(error) => error.into_report(interner),
+        }
+    }
+}
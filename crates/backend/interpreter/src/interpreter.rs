@@ -0,0 +1,625 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use lasso::Rodeo;
+
+use crate::{
+    environment::Environment,
+    error::{
+        DivisionByZero, IndexOutOfBounds, InterpreterError, Interrupt, InvalidOperands,
+        MissingReturn, NoMainFunction, NoSymbolFound, NoTypeFound, NoValueFound, Result,
+    },
+};
+use ast::{
+    symbol::{Symbol, SymbolKind},
+    traversal::{Visitable, Visitor},
+    ArrayLiteral, Assign, Binary, BinaryOperator, Block, Break, Call, Cast, Continue,
+    DestructureDecl, ExprKind, FieldAccess, FunDecl, Id, If, Index, Len, LetDecl, Literal, Match,
+    Pattern, Program, Return, StmtKind, StructLiteral, Target, TupleLiteral, TypeKind, Unary,
+    UnaryOperator, VariantLiteral, While,
+};
+use diagnostics::positional::LabelSpan;
+use name_resolution::error::InvalidSymbolKind;
+use value::Value;
+
+#[derive(Debug, Clone)]
+pub struct Interpreter {
+    interner: Rc<RefCell<Rodeo>>,
+    /// Decides the width values of pointer-sized integers wrap around at.
+    target: Target,
+    environment: Environment,
+    /// The bodies of the functions, which aren't executed right now. Only a
+    /// recursive call needs another copy, as the body is visited mutably.
+    bodies: HashMap<*const RefCell<FunDecl>, Bodies>,
+}
+
+#[derive(Debug, Clone)]
+struct Bodies {
+    /// Keeps the declaration alive, so its address can't be reused.
+    _fun_decl: Rc<RefCell<FunDecl>>,
+    blocks: Vec<Block>,
+}
+
+impl Interpreter {
+    pub fn new(interner: Rc<RefCell<Rodeo>>, target: Target) -> Self {
+        Self {
+            interner,
+            target,
+            environment: Environment::default(),
+            bodies: HashMap::new(),
+        }
+    }
+
+    /// Evaluates the global declarations of the program and calls its `main`
+    /// function. A `void` function doesn't result in a value.
+    pub fn run(
+        &mut self,
+        program: &mut Program,
+    ) -> std::result::Result<Option<Value>, InterpreterError> {
+        if let Err(Interrupt::Error(error)) = program.accept(self) {
+            return Err(error);
+        }
+
+        let main = self.find_main(program).ok_or(NoMainFunction)?;
+        self.call(&main, Vec::new())
+    }
+
+    /// Executes a single statement and returns its value, if it has one.
+    pub fn execute(
+        &mut self,
+        statement: &mut StmtKind,
+    ) -> std::result::Result<Option<Value>, InterpreterError> {
+        match statement.accept(self) {
+            Ok(value) | Err(Interrupt::Return(value)) => Ok(value),
+            Err(Interrupt::Break | Interrupt::Continue) => Ok(None),
+            Err(Interrupt::Error(error)) => Err(error),
+        }
+    }
+
+    fn find_main(&self, program: &Program) -> Option<Rc<RefCell<FunDecl>>> {
+        let main = self.interner.borrow().get("main")?;
+
+        program
+            .statements
+            .iter()
+            .find_map(|statement| match statement {
+                StmtKind::FunDecl(fun_decl) if fun_decl.borrow().id.get_spur() == Some(main) => {
+                    Some(fun_decl.clone())
+                }
+                _ => None,
+            })
+    }
+
+    fn call(
+        &mut self,
+        fun_decl: &Rc<RefCell<FunDecl>>,
+        arguments: Vec<Value>,
+    ) -> std::result::Result<Option<Value>, InterpreterError> {
+        let (type_, name, span) = {
+            let fun_decl = fun_decl.borrow();
+            let type_ = fun_decl.type_.kind.resolve(self.target);
+            let name = fun_decl
+                .id
+                .get_spur()
+                .expect("Identifiers always have a name.");
+            (type_, name, fun_decl.id.span)
+        };
+
+        let mut parameters = Vec::new();
+        for (parameter, argument) in fun_decl.borrow().parameters.iter().zip(arguments) {
+            let symbol = parameter
+                .symbol
+                .get()
+                .ok_or(NoSymbolFound::new(parameter.span))?;
+            let kind = parameter.type_.kind.resolve(self.target);
+            parameters.push((symbol.clone(), argument.cast(kind)));
+        }
+
+        self.environment.push_frame();
+        for (symbol, argument) in parameters.into_iter() {
+            self.environment.define(&symbol, argument);
+        }
+
+        let bodies = self
+            .bodies
+            .entry(Rc::as_ptr(fun_decl))
+            .or_insert_with(|| Bodies {
+                _fun_decl: fun_decl.clone(),
+                blocks: Vec::new(),
+            });
+        let mut block = match bodies.blocks.pop() {
+            Some(block) => block,
+            None => fun_decl.borrow().block.as_ref().clone(),
+        };
+
+        let result = block.accept(self);
+        self.environment.pop_frame();
+
+        if let Some(bodies) = self.bodies.get_mut(&Rc::as_ptr(fun_decl)) {
+            bodies.blocks.push(block);
+        }
+
+        match result {
+            Ok(Some(value)) | Err(Interrupt::Return(Some(value))) if type_ != TypeKind::Void => {
+                Ok(Some(value.cast(type_)))
+            }
+            Err(Interrupt::Error(error)) => Err(error),
+            _ if type_ == TypeKind::Void => Ok(None),
+            // The name resolution made sure that `break` and `continue` can't
+            // leave the function.
+            _ => Err(MissingReturn::new(name, span).into()),
+        }
+    }
+
+    fn evaluate(&mut self, expression: &mut ExprKind) -> std::result::Result<Value, Interrupt> {
+        let span = expression.span();
+        match expression.accept(self)? {
+            Some(value) => Ok(value),
+            None => Err(InterpreterError::from(NoValueFound::new(span)).into()),
+        }
+    }
+
+    /// Splits an assignment target into its variable and the steps, which
+    /// lead to the assigned place. The indices are evaluated from left to
+    /// right, before the assigned value.
+    fn place(
+        &mut self,
+        target: &mut ExprKind,
+    ) -> std::result::Result<(Rc<RefCell<Symbol>>, Vec<Step>), Interrupt> {
+        let span = target.span();
+        match target {
+            ExprKind::Id(id) => match id.symbol.get() {
+                Some(symbol) => Ok((symbol.clone(), Vec::new())),
+                None => Err(InterpreterError::from(NoSymbolFound::new(span)).into()),
+            },
+            ExprKind::FieldAccess(node) => {
+                let (symbol, mut steps) = self.place(&mut node.target)?;
+                let index = node
+                    .index
+                    .get()
+                    .ok_or(InterpreterError::from(NoSymbolFound::new(span)))?;
+                steps.push(Step::Field(*index));
+                Ok((symbol, steps))
+            }
+            ExprKind::Index(node) => {
+                let (symbol, mut steps) = self.place(&mut node.target)?;
+                let index = self.evaluate(&mut node.index)?;
+                steps.push(Step::Index(index, node.index.span()));
+                Ok((symbol, steps))
+            }
+            _ => Err(InterpreterError::from(NoSymbolFound::new(span)).into()),
+        }
+    }
+
+    /// Whether the value matches the pattern. The values of the bindings are
+    /// collected, as they are only defined once the whole pattern matched.
+    fn matches(
+        &self,
+        pattern: &Pattern,
+        value: &Value,
+        bindings: &mut Vec<(Rc<RefCell<Symbol>>, Value)>,
+    ) -> std::result::Result<bool, InterpreterError> {
+        match (pattern, value) {
+            (Pattern::Wildcard(_), _) => Ok(true),
+            (Pattern::Binding(node), value) => {
+                let symbol = node.symbol.get().ok_or(NoSymbolFound::new(node.id.span))?;
+                bindings.push((symbol.clone(), value.clone()));
+                Ok(true)
+            }
+            (Pattern::Literal(node), value) => {
+                let mut literal =
+                    Value::literal(&node.literal, &self.interner.borrow(), self.target);
+                if node.negated {
+                    literal = literal
+                        .unary(UnaryOperator::Neg)
+                        .ok_or(InvalidOperands::new(node.span))?;
+                }
+
+                match value.clone().binary(BinaryOperator::Eq, literal) {
+                    Some(Value::Bool(equal)) => Ok(equal),
+                    _ => Err(InvalidOperands::new(node.span).into()),
+                }
+            }
+            (Pattern::Variant(node), Value::Variant(index, values)) => {
+                if node.index.get() != Some(index) {
+                    return Ok(false);
+                }
+
+                for (pattern, value) in node.patterns.iter().zip(values.iter()) {
+                    if !self.matches(pattern, value, bindings)? {
+                        return Ok(false);
+                    }
+                }
+
+                Ok(true)
+            }
+            (Pattern::Variant(node), _) => Err(InvalidOperands::new(node.span).into()),
+            (Pattern::Tuple(node), Value::Struct(values)) => {
+                for (pattern, value) in node.patterns.iter().zip(values.iter()) {
+                    if !self.matches(pattern, value, bindings)? {
+                        return Ok(false);
+                    }
+                }
+
+                Ok(true)
+            }
+            (Pattern::Tuple(node), _) => Err(InvalidOperands::new(node.span).into()),
+        }
+    }
+}
+
+/// A single step from a variable towards the place an assignment changes.
+enum Step {
+    Field(usize),
+    Index(Value, LabelSpan),
+}
+
+impl Visitor for Interpreter {
+    type Return = Option<Value>;
+    type Error = Interrupt;
+
+    fn default_result() -> Result {
+        Ok(None)
+    }
+
+    fn visit_let_decl(&mut self, node: &mut LetDecl) -> Result {
+        // The symbol also knows the type of a declaration without one.
+        let symbol = node
+            .symbol
+            .get()
+            .ok_or(InterpreterError::from(NoSymbolFound::new(node.id.span)))?;
+        let kind = symbol
+            .borrow()
+            .type_
+            .get()
+            .map(|type_| type_.kind.resolve(self.target))
+            .ok_or(InterpreterError::from(NoTypeFound::new(node.id.span)))?;
+
+        let value = match node.expression {
+            Some(ref mut expression) => self.evaluate(expression)?.cast(kind),
+            None => Value::zero(kind),
+        };
+        self.environment.define(symbol, value);
+
+        Self::default_result()
+    }
+
+    fn visit_destructure_decl(&mut self, node: &mut DestructureDecl) -> Result {
+        let value = self.evaluate(&mut node.expression)?;
+
+        // The type checker made sure that the pattern matches every value.
+        let mut bindings = Vec::new();
+        if !self.matches(&node.pattern, &value, &mut bindings)? {
+            let error = InvalidOperands::new(node.pattern.span());
+            return Err(InterpreterError::from(error).into());
+        }
+
+        for (symbol, value) in bindings.iter() {
+            self.environment.define(symbol, value.clone());
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_fun_decl(&mut self, _node: &mut Rc<RefCell<FunDecl>>) -> Result {
+        Self::default_result()
+    }
+
+    fn visit_block(&mut self, node: &mut Block) -> Result {
+        self.environment.enter();
+
+        let result = node
+            .statements
+            .iter_mut()
+            .try_for_each(|statement| statement.accept(self).map(|_| ()))
+            .and_then(|_| match node.tail {
+                Some(ref mut tail) => tail.accept(self),
+                None => Ok(None),
+            });
+
+        self.environment.exit();
+        result
+    }
+
+    fn visit_if(&mut self, node: &mut If) -> Result {
+        let condition = match self.evaluate(&mut node.condition)? {
+            Value::Bool(condition) => condition,
+            _ => {
+                let error = InvalidOperands::new(node.condition.span());
+                return Err(InterpreterError::from(error).into());
+            }
+        };
+
+        match (condition, &mut node.else_) {
+            (true, _) => node.then.accept(self),
+            (false, Some(else_)) => else_.accept(self),
+            (false, None) => Self::default_result(),
+        }
+    }
+
+    fn visit_match(&mut self, node: &mut Match) -> Result {
+        let value = self.evaluate(&mut node.expression)?;
+
+        for arm in node.arms.iter_mut() {
+            let mut bindings = Vec::new();
+            if !self.matches(&arm.pattern, &value, &mut bindings)? {
+                continue;
+            }
+
+            self.environment.enter();
+            for (symbol, value) in bindings.iter() {
+                self.environment.define(symbol, value.clone());
+            }
+
+            let result = arm.expression.accept(self);
+            self.environment.exit();
+            return result;
+        }
+
+        // The type checker made sure that the match is exhaustive.
+        Err(InterpreterError::from(InvalidOperands::new(node.span)).into())
+    }
+
+    fn visit_while(&mut self, node: &mut While) -> Result {
+        loop {
+            match self.evaluate(&mut node.condition)? {
+                Value::Bool(true) => {}
+                Value::Bool(false) => break,
+                _ => {
+                    let error = InvalidOperands::new(node.condition.span());
+                    return Err(InterpreterError::from(error).into());
+                }
+            }
+
+            match node.block.accept(self) {
+                Ok(_) | Err(Interrupt::Continue) => {}
+                Err(Interrupt::Break) => break,
+                Err(interrupt) => return Err(interrupt),
+            }
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_break(&mut self, _node: &mut Break) -> Result {
+        Err(Interrupt::Break)
+    }
+
+    fn visit_continue(&mut self, _node: &mut Continue) -> Result {
+        Err(Interrupt::Continue)
+    }
+
+    fn visit_return(&mut self, node: &mut Return) -> Result {
+        let value = match node.expression {
+            Some(ref mut expression) => Some(self.evaluate(expression)?),
+            None => None,
+        };
+
+        Err(Interrupt::Return(value))
+    }
+
+    fn visit_call(&mut self, node: &mut Call) -> Result {
+        let symbol = node
+            .symbol
+            .get()
+            .ok_or(InterpreterError::from(NoSymbolFound::new(node.span)))?
+            .clone();
+
+        let kind = symbol.borrow().kind.clone();
+        let fun_decl = match kind {
+            SymbolKind::Function(fun_decl) => fun_decl,
+            kind => {
+                let error = InvalidSymbolKind::new(kind, "function", node.span);
+                return Err(InterpreterError::from(error).into());
+            }
+        };
+
+        let mut arguments = Vec::new();
+        for argument in node.arguments.iter_mut() {
+            arguments.push(self.evaluate(argument)?);
+        }
+
+        Ok(self.call(&fun_decl, arguments)?)
+    }
+
+    fn visit_assign(&mut self, node: &mut Assign) -> Result {
+        let target_span = node.target.span();
+        let (symbol, steps) = self.place(&mut node.target)?;
+
+        // The value is evaluated before the target gets read, thus changes it
+        // makes to the target aren't overwritten by the stale copy.
+        let mut value = self.evaluate(&mut node.value)?;
+        let mut base = self
+            .environment
+            .get(&symbol)
+            .ok_or(InterpreterError::from(NoValueFound::new(target_span)))?;
+
+        let mut current = &mut base;
+        for step in steps {
+            current = match (current, step) {
+                (Value::Struct(values), Step::Field(field)) => values.get_mut(field),
+                (Value::Array(values), Step::Index(index, span)) => {
+                    let index = bounds(&index, values.len(), span)?;
+                    values.get_mut(index)
+                }
+                _ => None,
+            }
+            .ok_or(InterpreterError::from(InvalidOperands::new(target_span)))?;
+        }
+
+        if let Some(operator) = node.operator.binary() {
+            if operator.is_division() && value.is_zero() {
+                return Err(InterpreterError::from(DivisionByZero::new(node.span)).into());
+            }
+
+            value = current
+                .clone()
+                .binary(operator, value)
+                .ok_or(InterpreterError::from(InvalidOperands::new(node.span)))?;
+        }
+
+        *current = match current.kind() {
+            Some(kind) => value.cast(kind),
+            None => value,
+        };
+        self.environment.assign(&symbol, base);
+
+        Self::default_result()
+    }
+
+    fn visit_struct_literal(&mut self, node: &mut StructLiteral) -> Result {
+        // The fields are evaluated in the order of their declaration, just
+        // like the compiled bytecode does.
+        let mut fields = node.fields.iter_mut().collect::<Vec<_>>();
+        fields.sort_by_key(|field| field.index.get().copied());
+
+        let mut values = Vec::new();
+        for field in fields {
+            values.push(self.evaluate(&mut field.expression)?);
+        }
+
+        Ok(Some(Value::Struct(values)))
+    }
+
+    fn visit_field_access(&mut self, node: &mut FieldAccess) -> Result {
+        let target = self.evaluate(&mut node.target)?;
+
+        let value = match (target, node.index.get()) {
+            (Value::Struct(mut values), Some(index)) if *index < values.len() => {
+                values.swap_remove(*index)
+            }
+            _ => return Err(InterpreterError::from(InvalidOperands::new(node.span)).into()),
+        };
+
+        Ok(Some(value))
+    }
+
+    fn visit_variant_literal(&mut self, node: &mut VariantLiteral) -> Result {
+        let index = node
+            .index
+            .get()
+            .ok_or(InterpreterError::from(NoSymbolFound::new(node.span)))?;
+
+        let mut values = Vec::new();
+        for argument in node.arguments.iter_mut() {
+            values.push(self.evaluate(argument)?);
+        }
+
+        Ok(Some(Value::Variant(*index, values)))
+    }
+
+    fn visit_index(&mut self, node: &mut Index) -> Result {
+        let target = self.evaluate(&mut node.target)?;
+        let index = self.evaluate(&mut node.index)?;
+
+        match target {
+            Value::Array(mut values) => {
+                let index = bounds(&index, values.len(), node.index.span())?;
+                Ok(Some(values.swap_remove(index)))
+            }
+            _ => Err(InterpreterError::from(InvalidOperands::new(node.span)).into()),
+        }
+    }
+
+    fn visit_array_literal(&mut self, node: &mut ArrayLiteral) -> Result {
+        let mut values = Vec::new();
+        for element in node.elements.iter_mut() {
+            values.push(self.evaluate(element)?);
+        }
+
+        Ok(Some(Value::Array(values)))
+    }
+
+    fn visit_tuple_literal(&mut self, node: &mut TupleLiteral) -> Result {
+        let mut values = Vec::new();
+        for element in node.elements.iter_mut() {
+            values.push(self.evaluate(element)?);
+        }
+
+        Ok(Some(Value::Struct(values)))
+    }
+
+    fn visit_len(&mut self, node: &mut Len) -> Result {
+        match self.evaluate(&mut node.expression)? {
+            Value::Array(values) => Ok(Some(Value::int(
+                values.len() as i128,
+                false,
+                self.target.size_width,
+            ))),
+            _ => Err(InterpreterError::from(InvalidOperands::new(node.span)).into()),
+        }
+    }
+
+    fn visit_binary(&mut self, node: &mut Binary) -> Result {
+        let lhs = self.evaluate(&mut node.lhs)?;
+
+        if node.operator.is_logical() {
+            return match (node.operator, lhs) {
+                (BinaryOperator::LogAnd, Value::Bool(false)) => Ok(Some(Value::Bool(false))),
+                (BinaryOperator::LogOr, Value::Bool(true)) => Ok(Some(Value::Bool(true))),
+                (_, Value::Bool(_)) => node.rhs.accept(self),
+                _ => Err(InterpreterError::from(InvalidOperands::new(node.span)).into()),
+            };
+        }
+
+        let rhs = self.evaluate(&mut node.rhs)?;
+
+        if node.operator.is_division() && rhs.is_zero() {
+            return Err(InterpreterError::from(DivisionByZero::new(node.span)).into());
+        }
+
+        match lhs.binary(node.operator, rhs) {
+            Some(value) => Ok(Some(value)),
+            None => Err(InterpreterError::from(InvalidOperands::new(node.span)).into()),
+        }
+    }
+
+    fn visit_unary(&mut self, node: &mut Unary) -> Result {
+        let expression = self.evaluate(&mut node.expression)?;
+
+        match expression.unary(node.operator) {
+            Some(value) => Ok(Some(value)),
+            None => Err(InterpreterError::from(InvalidOperands::new(node.span)).into()),
+        }
+    }
+
+    fn visit_cast(&mut self, node: &mut Cast) -> Result {
+        let value = self.evaluate(&mut node.expression)?;
+        Ok(Some(value.cast(node.type_.kind.resolve(self.target))))
+    }
+
+    fn visit_literal(&mut self, node: &mut Literal) -> Result {
+        Ok(Some(Value::literal(
+            node,
+            &self.interner.borrow(),
+            self.target,
+        )))
+    }
+
+    fn visit_id(&mut self, node: &mut Id) -> Result {
+        let id_span = node.id.span;
+        let symbol = node
+            .symbol
+            .get()
+            .ok_or(InterpreterError::from(NoSymbolFound::new(id_span)))?;
+
+        match self.environment.get(symbol) {
+            Some(value) => Ok(Some(value)),
+            None => Err(InterpreterError::from(NoValueFound::new(id_span)).into()),
+        }
+    }
+}
+
+/// Checks the index against the length of the array, which it's used on.
+fn bounds(
+    index: &Value,
+    length: usize,
+    span: LabelSpan,
+) -> std::result::Result<usize, InterpreterError> {
+    match *index {
+        Value::Int(index, _, _) => usize::try_from(index)
+            .ok()
+            .filter(|index| *index < length)
+            .ok_or(IndexOutOfBounds::new(index, length, span).into()),
+        _ => Err(InvalidOperands::new(span).into()),
+    }
+}
@@ -0,0 +1,5 @@
+pub mod environment;
+pub mod error;
+pub mod interpreter;
+
+pub use crate::interpreter::*;
@@ -0,0 +1,3 @@
+pub mod value;
+
+pub use crate::value::*;
@@ -0,0 +1,390 @@
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+use lasso::Rodeo;
+
+use ast::{BinaryOperator, Literal, LiteralKind, Target, TypeKind, UnaryOperator};
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// An integer that always lies inside the range of its signedness and size.
+    Int(i128, bool, usize),
+    Decimal(f64, usize),
+    Bool(bool),
+    /// The decoded content of a string, without any escape sequences.
+    Str(Rc<str>),
+    /// The values of the fields in the order of their declaration, or the
+    /// elements of a tuple.
+    Struct(Vec<Value>),
+    /// The values of the elements, which all share the same type.
+    Array(Vec<Value>),
+    /// The position of the variant in the declaration and the values it carries.
+    Variant(usize, Vec<Value>),
+}
+
+impl Value {
+    pub fn int(value: i128, signed: bool, size: usize) -> Self {
+        Self::Int(Self::wrap(value, signed, size), signed, size)
+    }
+
+    pub fn decimal(value: f64, size: usize) -> Self {
+        match size {
+            32 => Self::Decimal(value as f32 as f64, size),
+            _ => Self::Decimal(value, size),
+        }
+    }
+
+    /// The number is cast to the type the type checker chose for the literal.
+    /// The content of strings is resolved by the interner.
+    pub fn literal(node: &Literal, interner: &Rodeo, target: Target) -> Self {
+        let value = match node.kind {
+            LiteralKind::Int => {
+                let value = node.token.get_int().unwrap();
+                let size = match value {
+                    value if value <= u8::MAX as usize => 8,
+                    value if value <= u16::MAX as usize => 16,
+                    value if value <= u32::MAX as usize => 32,
+                    _ => 64,
+                };
+
+                Self::Int(value as i128, false, size)
+            }
+            LiteralKind::Decimal => {
+                let value = node.token.get_dec().unwrap();
+                match value {
+                    value if value >= f32::MIN as f64 && value <= f32::MAX as f64 => {
+                        Self::decimal(value, 32)
+                    }
+                    _ => Self::decimal(value, 64),
+                }
+            }
+            LiteralKind::Bool => Self::Bool(node.token.get_bool().unwrap()),
+            LiteralKind::String => {
+                let content = node.token.get_spur().unwrap();
+                Self::Str(interner.resolve(&content).into())
+            }
+        };
+
+        match node.type_.get() {
+            Some(kind) => value.cast(kind.resolve(target)),
+            None => value,
+        }
+    }
+
+    /// The value a declaration without an initializer starts with.
+    pub fn zero(kind: TypeKind) -> Self {
+        match kind {
+            TypeKind::Int(signed, size) => Self::Int(0, signed, size),
+            TypeKind::Size(_) => unreachable!("The width of pointer-sized integers is resolved."),
+            TypeKind::Decimal(size) => Self::Decimal(0.0, size),
+            TypeKind::Bool => Self::Bool(false),
+            TypeKind::Str => Self::Str("".into()),
+            TypeKind::Array(element, length) => Self::Array(vec![Self::zero(*element); length]),
+            TypeKind::Tuple(elements) => {
+                Self::Struct(elements.into_iter().map(Self::zero).collect())
+            }
+            TypeKind::Struct(_) | TypeKind::Enum(_) | TypeKind::Slice(_) => {
+                unreachable!("The type checker requires an initializer.")
+            }
+            TypeKind::Void => unreachable!("The type checker doesn't allow void variables."),
+        }
+    }
+
+    /// Wraps the value around, just like a fixed size two's complement integer would do.
+    fn wrap(value: i128, signed: bool, size: usize) -> i128 {
+        let value = value & ((1 << size) - 1);
+        if signed && (value >> (size - 1)) & 1 == 1 {
+            value - (1 << size)
+        } else {
+            value
+        }
+    }
+
+    /// Converts the value to the given type, used whenever a value gets bound to a declaration
+    /// or explicitly converted. Integers are truncated or sign-extended, while decimals are
+    /// truncated towards zero and saturate at the bounds of an integer.
+    pub fn cast(self, kind: TypeKind) -> Self {
+        match (self, kind) {
+            (Self::Int(value, _, _), TypeKind::Int(signed, size)) => Self::int(value, signed, size),
+            (Self::Int(value, _, _), TypeKind::Decimal(size)) => Self::decimal(value as f64, size),
+            (Self::Decimal(value, _), TypeKind::Int(signed, size)) => {
+                Self::saturate(value, signed, size)
+            }
+            (Self::Decimal(value, _), TypeKind::Decimal(size)) => Self::decimal(value, size),
+            (Self::Bool(value), TypeKind::Int(signed, size)) => {
+                Self::Int(value as i128, signed, size)
+            }
+            (value, _) => value,
+        }
+    }
+
+    /// Clamps the decimal into the range of the integer, while `NaN` becomes zero.
+    fn saturate(value: f64, signed: bool, size: usize) -> Self {
+        let (min, max) = match signed {
+            true => (-(1 << (size - 1)), (1 << (size - 1)) - 1),
+            false => (0, (1 << size) - 1),
+        };
+
+        // The conversion already truncates and saturates at the bounds of an i128.
+        Self::Int((value as i128).clamp(min, max), signed, size)
+    }
+
+    /// Returns `None` if the operator isn't defined for the operands, which
+    /// includes an integer division or remainder by zero.
+    pub fn binary(self, operator: BinaryOperator, other: Value) -> Option<Value> {
+        Some(match (self, other) {
+            (Self::Int(lhs, signed, size), Self::Int(rhs, _, _)) => match operator {
+                BinaryOperator::Add => Self::int(lhs.wrapping_add(rhs), signed, size),
+                BinaryOperator::Sub => Self::int(lhs.wrapping_sub(rhs), signed, size),
+                BinaryOperator::Mul => Self::int(lhs.wrapping_mul(rhs), signed, size),
+                BinaryOperator::Div if rhs == 0 => return None,
+                BinaryOperator::Div => Self::int(lhs.wrapping_div(rhs), signed, size),
+                BinaryOperator::Rem if rhs == 0 => return None,
+                BinaryOperator::Rem => Self::int(lhs.wrapping_rem(rhs), signed, size),
+                BinaryOperator::BitOr => Self::int(lhs | rhs, signed, size),
+                BinaryOperator::BitXor => Self::int(lhs ^ rhs, signed, size),
+                BinaryOperator::BitAnd => Self::int(lhs & rhs, signed, size),
+                // The amount wraps around at the width, just like the value does.
+                BinaryOperator::Shl => Self::int(lhs << rhs.rem_euclid(size as i128), signed, size),
+                BinaryOperator::Shr => Self::int(lhs >> rhs.rem_euclid(size as i128), signed, size),
+                operator => Self::Bool(Self::compare(operator, lhs, rhs)?),
+            },
+
+            (Self::Decimal(lhs, size), Self::Decimal(rhs, _)) => match operator {
+                BinaryOperator::Add => Self::decimal(lhs + rhs, size),
+                BinaryOperator::Sub => Self::decimal(lhs - rhs, size),
+                BinaryOperator::Mul => Self::decimal(lhs * rhs, size),
+                BinaryOperator::Div => Self::decimal(lhs / rhs, size),
+                operator => Self::Bool(Self::compare(operator, lhs, rhs)?),
+            },
+
+            (Self::Bool(lhs), Self::Bool(rhs)) => match operator {
+                BinaryOperator::Eq => Self::Bool(lhs == rhs),
+                BinaryOperator::NotEq => Self::Bool(lhs != rhs),
+                _ => return None,
+            },
+
+            (Self::Str(lhs), Self::Str(rhs)) => match operator {
+                BinaryOperator::Add => Self::Str(format!("{}{}", lhs, rhs).into()),
+                BinaryOperator::Eq => Self::Bool(lhs == rhs),
+                BinaryOperator::NotEq => Self::Bool(lhs != rhs),
+                _ => return None,
+            },
+
+            _ => return None,
+        })
+    }
+
+    fn compare<T: PartialOrd>(operator: BinaryOperator, lhs: T, rhs: T) -> Option<bool> {
+        Some(match operator {
+            BinaryOperator::Eq => lhs == rhs,
+            BinaryOperator::NotEq => lhs != rhs,
+            BinaryOperator::Greater => lhs > rhs,
+            BinaryOperator::GreaterEq => lhs >= rhs,
+            BinaryOperator::Less => lhs < rhs,
+            BinaryOperator::LessEq => lhs <= rhs,
+            _ => return None,
+        })
+    }
+
+    pub fn unary(self, operator: UnaryOperator) -> Option<Value> {
+        Some(match (operator, self) {
+            (UnaryOperator::Neg, Self::Int(value, signed, size)) => {
+                Self::int(value.wrapping_neg(), signed, size)
+            }
+            (UnaryOperator::Neg, Self::Decimal(value, size)) => Self::decimal(-value, size),
+            (UnaryOperator::BitNot, Self::Int(value, signed, size)) => {
+                Self::int(!value, signed, size)
+            }
+            (UnaryOperator::LogNeg, Self::Bool(value)) => Self::Bool(!value),
+            _ => return None,
+        })
+    }
+
+    pub fn is_zero(&self) -> bool {
+        matches!(self, Self::Int(0, _, _))
+    }
+
+    /// Returns `None` for structs and variants, as their values don't know the name of their
+    /// declaration, and for arrays, as they could be empty.
+    pub fn kind(&self) -> Option<TypeKind> {
+        Some(match *self {
+            Self::Int(_, signed, size) => TypeKind::Int(signed, size),
+            Self::Decimal(_, size) => TypeKind::Decimal(size),
+            Self::Bool(_) => TypeKind::Bool,
+            Self::Str(_) => TypeKind::Str,
+            Self::Struct(_) | Self::Array(_) | Self::Variant(_, _) => return None,
+        })
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(value, _, _) => write!(f, "{}", value),
+            Self::Decimal(value, _) => write!(f, "{}", value),
+            Self::Bool(value) => write!(f, "{}", value),
+            Self::Str(value) => write!(f, "{}", value),
+            Self::Struct(fields) => {
+                write!(f, "{{ ")?;
+                for (index, field) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", field)?;
+                }
+                write!(f, " }}")
+            }
+            Self::Array(elements) => {
+                write!(f, "[")?;
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", element)?;
+                }
+                write!(f, "]")
+            }
+            Self::Variant(index, values) => {
+                write!(f, "#{}", index)?;
+                if values.is_empty() {
+                    return Ok(());
+                }
+
+                write!(f, "(")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn wrapping() {
+        assert_eq!(Value::int(256, false, 8), Value::Int(0, false, 8));
+        assert_eq!(Value::int(255, true, 8), Value::Int(-1, true, 8));
+        assert_eq!(
+            Value::int(-1, false, 64),
+            Value::Int(u64::MAX as i128, false, 64)
+        );
+
+        let max = Value::Int(i32::MAX as i128, true, 32);
+        let one = Value::Int(1, true, 32);
+        assert_eq!(
+            max.binary(BinaryOperator::Add, one),
+            Some(Value::Int(i32::MIN as i128, true, 32))
+        );
+    }
+
+    #[test]
+    fn division_by_zero() {
+        let one = Value::Int(1, true, 32);
+        let zero = Value::Int(0, true, 32);
+        assert_eq!(one.binary(BinaryOperator::Div, zero), None);
+    }
+
+    #[test]
+    fn cast() {
+        let value = Value::Int(300, false, 16);
+        assert_eq!(
+            value.cast(TypeKind::Int(false, 8)),
+            Value::Int(44, false, 8)
+        );
+
+        let value = Value::Decimal(0.1, 64);
+        assert_eq!(
+            value.cast(TypeKind::Decimal(32)),
+            Value::Decimal(0.1f32 as f64, 32)
+        );
+
+        let value = Value::Int(-1, true, 8);
+        assert_eq!(
+            value.cast(TypeKind::Int(false, 16)),
+            Value::Int(u16::MAX as i128, false, 16)
+        );
+
+        let value = Value::Decimal(-3.9, 64);
+        assert_eq!(
+            value.cast(TypeKind::Int(true, 32)),
+            Value::Int(-3, true, 32)
+        );
+
+        let value = Value::Decimal(1e10, 64);
+        assert_eq!(
+            value.cast(TypeKind::Int(false, 8)),
+            Value::Int(255, false, 8)
+        );
+
+        let value = Value::Decimal(f64::NAN, 64);
+        assert_eq!(value.cast(TypeKind::Int(true, 8)), Value::Int(0, true, 8));
+    }
+
+    #[test]
+    fn zero_array() {
+        let kind = TypeKind::Array(Box::new(TypeKind::Int(false, 8)), 2);
+        assert_eq!(
+            Value::zero(TypeKind::Array(Box::new(kind), 2)),
+            Value::Array(vec![
+                Value::Array(vec![Value::Int(0, false, 8), Value::Int(0, false, 8)]),
+                Value::Array(vec![Value::Int(0, false, 8), Value::Int(0, false, 8)]),
+            ])
+        );
+    }
+
+    #[test]
+    fn string() {
+        let lhs = Value::Str("Hello, ".into());
+        let rhs = Value::Str("World!".into());
+        assert_eq!(
+            lhs.clone().binary(BinaryOperator::Add, rhs.clone()),
+            Some(Value::Str("Hello, World!".into()))
+        );
+        assert_eq!(
+            lhs.binary(BinaryOperator::Eq, rhs),
+            Some(Value::Bool(false))
+        );
+    }
+
+    #[test]
+    fn bitwise() {
+        let value = Value::Int(0b1010, false, 8);
+        let other = Value::Int(0b0110, false, 8);
+        assert_eq!(
+            value.clone().binary(BinaryOperator::BitAnd, other.clone()),
+            Some(Value::Int(0b0010, false, 8))
+        );
+        assert_eq!(
+            value.clone().binary(BinaryOperator::BitXor, other),
+            Some(Value::Int(0b1100, false, 8))
+        );
+        assert_eq!(
+            value.unary(UnaryOperator::BitNot),
+            Some(Value::Int(0b1111_0101, false, 8))
+        );
+
+        let value = Value::Int(-16, true, 8);
+        assert_eq!(
+            value
+                .clone()
+                .binary(BinaryOperator::Shr, Value::Int(2, false, 32)),
+            Some(Value::Int(-4, true, 8))
+        );
+        assert_eq!(
+            value.binary(BinaryOperator::Shl, Value::Int(11, false, 32)),
+            Some(Value::Int(-128, true, 8))
+        );
+    }
+}
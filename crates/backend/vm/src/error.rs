@@ -0,0 +1,190 @@
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+
+use lasso::Rodeo;
+
+use diagnostics::report::{Report, ReportBuilder, Reportable, Serverity};
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct DivisionByZero {
+    function: String,
+}
+
+impl DivisionByZero {
+    pub fn new(function: impl Into<String>) -> Self {
+        Self {
+            function: function.into(),
+        }
+    }
+}
+
+impl From<DivisionByZero> for VMError {
+    fn from(value: DivisionByZero) -> Self {
+        Self::DivisionByZero(value)
+    }
+}
+
+impl Reportable for DivisionByZero {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        let message = format!(
+            "Attempted to divide by zero in the function '{}'.",
+            self.function
+        );
+
+        ReportBuilder::default()
+            .message(message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct MissingReturn {
+    function: String,
+}
+
+impl MissingReturn {
+    pub fn new(function: impl Into<String>) -> Self {
+        Self {
+            function: function.into(),
+        }
+    }
+}
+
+impl From<MissingReturn> for VMError {
+    fn from(value: MissingReturn) -> Self {
+        Self::MissingReturn(value)
+    }
+}
+
+impl Reportable for MissingReturn {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        let message = format!(
+            "The function '{}' reached its end without returning a value.",
+            self.function
+        );
+
+        ReportBuilder::default()
+            .message(message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct IndexOutOfBounds {
+    function: String,
+    index: i128,
+    length: usize,
+}
+
+impl IndexOutOfBounds {
+    pub fn new(function: impl Into<String>, index: i128, length: usize) -> Self {
+        Self {
+            function: function.into(),
+            index,
+            length,
+        }
+    }
+}
+
+impl From<IndexOutOfBounds> for VMError {
+    fn from(value: IndexOutOfBounds) -> Self {
+        Self::IndexOutOfBounds(value)
+    }
+}
+
+impl Reportable for IndexOutOfBounds {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        let message = format!(
+            "The index {} is out of bounds for a length of {} in the function '{}'.",
+            self.index, self.length, self.function
+        );
+
+        ReportBuilder::default()
+            .message(message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub enum VMError {
+    DivisionByZero(DivisionByZero),
+    MissingReturn(MissingReturn),
+    IndexOutOfBounds(IndexOutOfBounds),
+    InternalError(InternalError),
+}
+
+impl Reportable for VMError {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        match self {
+            Self::DivisionByZero(error) => error.into_report(interner),
+            Self::MissingReturn(error) => error.into_report(interner),
+            Self::IndexOutOfBounds(error) => error.into_report(interner),
+            Self::InternalError(error) => error.into_report(interner),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct InvalidBytecode {
+    function: String,
+    ip: usize,
+}
+
+impl InvalidBytecode {
+    pub fn new(function: impl Into<String>, ip: usize) -> Self {
+        Self {
+            function: function.into(),
+            ip,
+        }
+    }
+}
+
+impl From<InvalidBytecode> for VMError {
+    fn from(value: InvalidBytecode) -> Self {
+        Self::InternalError(InternalError::InvalidBytecode(value))
+    }
+}
+
+impl Reportable for InvalidBytecode {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        let message = format!(
+            "The instruction '{}' of the function '{}' couldn't be executed.",
+            self.ip, self.function
+        );
+
+        ReportBuilder::default()
+            .message(message)
+            .code(2)
+            .serverity(Serverity::Bug)
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub enum InternalError {
+    InvalidBytecode(InvalidBytecode),
+}
+
+impl Reportable for InternalError {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        match self {
+            Self::InvalidBytecode(error) => error.into_report(interner),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod error;
+pub mod vm;
+
+pub use crate::vm::*;
@@ -0,0 +1,271 @@
+use bytecode::{Function, Instruction, Module};
+use value::Value;
+
+use crate::error::{DivisionByZero, IndexOutOfBounds, InvalidBytecode, MissingReturn, VMError};
+
+type Result<T> = std::result::Result<T, VMError>;
+
+#[derive(Debug)]
+struct Frame<'a> {
+    function: &'a Function,
+    ip: usize,
+    locals: Vec<Option<Value>>,
+}
+
+impl<'a> Frame<'a> {
+    fn new(function: &'a Function, arguments: Vec<Value>) -> Self {
+        let mut locals = vec![None; function.locals as usize];
+        for (local, argument) in locals.iter_mut().zip(arguments) {
+            *local = Some(argument);
+        }
+
+        Self {
+            function,
+            ip: 0,
+            locals,
+        }
+    }
+}
+
+/// A stack machine, which executes the functions of a compiled module.
+#[derive(Debug)]
+pub struct VM<'a> {
+    module: &'a Module,
+    stack: Vec<Value>,
+    globals: Vec<Option<Value>>,
+    frames: Vec<Frame<'a>>,
+}
+
+impl<'a> VM<'a> {
+    pub fn new(module: &'a Module) -> Self {
+        Self {
+            module,
+            stack: Vec::new(),
+            globals: vec![None; module.globals as usize],
+            frames: Vec::new(),
+        }
+    }
+
+    /// Evaluates the global declarations of the module and calls its `main`
+    /// function. A `void` function doesn't result in a value.
+    pub fn run(&mut self) -> Result<Option<Value>> {
+        let module = self.module;
+        self.execute(&module.init, Vec::new())?;
+
+        let main = module
+            .functions
+            .get(module.main as usize)
+            .ok_or(InvalidBytecode::new(
+                &module.init.name,
+                module.init.code.len(),
+            ))?;
+
+        self.execute(main, Vec::new())
+    }
+
+    /// Executes the function until it returns. A `void` function results in
+    /// `None`, while reaching the end of any function means that it's missing
+    /// a return.
+    fn execute(&mut self, function: &'a Function, arguments: Vec<Value>) -> Result<Option<Value>> {
+        let base = self.frames.len();
+        self.frames.push(Frame::new(function, arguments));
+
+        loop {
+            let frame = self
+                .frames
+                .last_mut()
+                .expect("There is at least one frame.");
+            let (function, ip) = (frame.function, frame.ip);
+            let invalid = || VMError::from(InvalidBytecode::new(&function.name, ip));
+
+            let Some(instruction) = function.code.get(ip).cloned() else {
+                return Err(MissingReturn::new(&function.name).into());
+            };
+            frame.ip += 1;
+
+            match instruction {
+                Instruction::Constant(index) => {
+                    let value = self
+                        .module
+                        .constants
+                        .get(index as usize)
+                        .ok_or_else(invalid)?;
+                    self.stack.push(value.clone());
+                }
+                Instruction::GetLocal(index) => {
+                    let value = frame.locals.get(index as usize).cloned().flatten();
+                    self.stack.push(value.ok_or_else(invalid)?);
+                }
+                Instruction::SetLocal(index) => {
+                    let value = self.stack.pop().ok_or_else(invalid)?;
+                    *frame.locals.get_mut(index as usize).ok_or_else(invalid)? = Some(value);
+                }
+                Instruction::GetGlobal(index) => {
+                    let value = self.globals.get(index as usize).cloned().flatten();
+                    self.stack.push(value.ok_or_else(invalid)?);
+                }
+                Instruction::SetGlobal(index) => {
+                    let value = self.stack.pop().ok_or_else(invalid)?;
+                    *self.globals.get_mut(index as usize).ok_or_else(invalid)? = Some(value);
+                }
+                Instruction::Binary(operator) => {
+                    let rhs = self.stack.pop().ok_or_else(invalid)?;
+                    let lhs = self.stack.pop().ok_or_else(invalid)?;
+
+                    if operator.is_division() && rhs.is_zero() {
+                        return Err(DivisionByZero::new(&function.name).into());
+                    }
+
+                    let value = lhs.binary(operator, rhs).ok_or_else(invalid)?;
+                    self.stack.push(value);
+                }
+                Instruction::Unary(operator) => {
+                    let value = self.stack.pop().ok_or_else(invalid)?;
+                    self.stack.push(value.unary(operator).ok_or_else(invalid)?);
+                }
+                Instruction::Cast(kind) => {
+                    let value = self.stack.pop().ok_or_else(invalid)?;
+                    self.stack.push(value.cast(kind));
+                }
+                Instruction::Call(index) => {
+                    let callee = self
+                        .module
+                        .functions
+                        .get(index as usize)
+                        .ok_or_else(invalid)?;
+                    let start = self
+                        .stack
+                        .len()
+                        .checked_sub(callee.arity as usize)
+                        .ok_or_else(invalid)?;
+
+                    let arguments = self.stack.split_off(start);
+                    self.frames.push(Frame::new(callee, arguments));
+                }
+                Instruction::Return => {
+                    let value = self.stack.pop().ok_or_else(invalid)?;
+
+                    self.frames.pop();
+                    if self.frames.len() == base {
+                        return Ok(Some(value));
+                    }
+
+                    self.stack.push(value);
+                }
+                Instruction::ReturnVoid => {
+                    self.frames.pop();
+                    if self.frames.len() == base {
+                        return Ok(None);
+                    }
+                }
+                Instruction::Pop => {
+                    self.stack.pop().ok_or_else(invalid)?;
+                }
+                Instruction::Jump(target) => frame.ip = target as usize,
+                Instruction::JumpIfFalse(target) => match self.stack.pop() {
+                    Some(Value::Bool(true)) => {}
+                    Some(Value::Bool(false)) => frame.ip = target as usize,
+                    _ => return Err(invalid()),
+                },
+                Instruction::Struct(length) => {
+                    let start = self
+                        .stack
+                        .len()
+                        .checked_sub(length as usize)
+                        .ok_or_else(invalid)?;
+
+                    let fields = self.stack.split_off(start);
+                    self.stack.push(Value::Struct(fields));
+                }
+                Instruction::GetField(index) => {
+                    let value = match self.stack.pop() {
+                        Some(Value::Struct(fields)) => fields.into_iter().nth(index as usize),
+                        _ => None,
+                    };
+                    self.stack.push(value.ok_or_else(invalid)?);
+                }
+                Instruction::SetField(index) => {
+                    let value = self.stack.pop().ok_or_else(invalid)?;
+                    let Some(Value::Struct(mut fields)) = self.stack.pop() else {
+                        return Err(invalid());
+                    };
+
+                    *fields.get_mut(index as usize).ok_or_else(invalid)? = value;
+                    self.stack.push(Value::Struct(fields));
+                }
+                Instruction::Array(length) => {
+                    let start = self
+                        .stack
+                        .len()
+                        .checked_sub(length as usize)
+                        .ok_or_else(invalid)?;
+
+                    let elements = self.stack.split_off(start);
+                    self.stack.push(Value::Array(elements));
+                }
+                Instruction::GetIndex => {
+                    let Some(Value::Int(index, _, _)) = self.stack.pop() else {
+                        return Err(invalid());
+                    };
+                    let Some(Value::Array(mut elements)) = self.stack.pop() else {
+                        return Err(invalid());
+                    };
+
+                    let index = bounds(&function.name, index, elements.len())?;
+                    self.stack.push(elements.swap_remove(index));
+                }
+                Instruction::SetIndex => {
+                    let value = self.stack.pop().ok_or_else(invalid)?;
+                    let Some(Value::Int(index, _, _)) = self.stack.pop() else {
+                        return Err(invalid());
+                    };
+                    let Some(Value::Array(mut elements)) = self.stack.pop() else {
+                        return Err(invalid());
+                    };
+
+                    let index = bounds(&function.name, index, elements.len())?;
+                    elements[index] = value;
+                    self.stack.push(Value::Array(elements));
+                }
+                Instruction::Len => {
+                    let Some(Value::Array(elements)) = self.stack.pop() else {
+                        return Err(invalid());
+                    };
+                    self.stack
+                        .push(Value::Int(elements.len() as i128, false, 64));
+                }
+                Instruction::Variant(index, length) => {
+                    let start = self
+                        .stack
+                        .len()
+                        .checked_sub(length as usize)
+                        .ok_or_else(invalid)?;
+
+                    let values = self.stack.split_off(start);
+                    self.stack.push(Value::Variant(index as usize, values));
+                }
+                Instruction::IsVariant(index) => {
+                    let Some(Value::Variant(other, _)) = self.stack.pop() else {
+                        return Err(invalid());
+                    };
+                    self.stack.push(Value::Bool(other == index as usize));
+                }
+                Instruction::GetPayload(index) => {
+                    let value = match self.stack.pop() {
+                        Some(Value::Variant(_, values)) => values.into_iter().nth(index as usize),
+                        _ => None,
+                    };
+                    self.stack.push(value.ok_or_else(invalid)?);
+                }
+            }
+        }
+    }
+}
+
+/// Checks the index against the length of the array, which it's used on.
+fn bounds(function: &str, index: i128, length: usize) -> Result<usize> {
+    usize::try_from(index)
+        .ok()
+        .filter(|index| *index < length)
+        .ok_or(IndexOutOfBounds::new(function, index, length).into())
+}
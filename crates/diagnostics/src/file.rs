@@ -29,7 +29,7 @@ impl File {
         }
     }
 
-    fn line_ranges(source: &String) -> Vec<Span> {
+    fn line_ranges(source: &str) -> Vec<Span> {
         let mut lines = Vec::new();
 
         let mut start = 0;
@@ -45,6 +45,14 @@ impl Span {
         Span::new(index, index)
     }
 
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
     pub fn is_inside(&self, index: usize) -> bool {
         index >= self.start && index <= self.end
     }
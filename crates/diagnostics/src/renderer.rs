@@ -44,6 +44,10 @@ impl<'a, Writer: WriteColor> Renderer<'a, Writer> {
 
         writeln!(self.writer, ": {}", report.message).unwrap();
 
+        if report.labels.is_empty() {
+            return;
+        }
+
         let biggest_number = report
             .labels
             .iter()
@@ -47,7 +47,6 @@ impl Serverity {
 #[derive(Debug)]
 pub enum ReportBuilderError {
     UninitializedField(&'static str),
-    OverlappingLabels(Vec<(Span, Span)>),
 }
 
 impl From<UninitializedFieldError> for ReportBuilderError {
@@ -59,9 +58,6 @@ impl From<UninitializedFieldError> for ReportBuilderError {
 impl Display for ReportBuilderError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::OverlappingLabels(labels) => {
-                write!(f, "There are overlapping labels '{:?}'", labels)
-            }
             Self::UninitializedField(field) => write!(f, "Required field '{}' not set", field),
         }
     }
@@ -92,33 +88,28 @@ pub struct Report {
 }
 
 impl ReportBuilder {
+    /// Builds the report, while overlapping labels get merged into a single
+    /// label spanning all of them, so none of their messages get lost.
     pub fn build(&self) -> Result<Report, ReportBuilderError> {
-        self.check_overlap()?;
-        self.build_report()
+        let mut report = self.build_report()?;
+        report.labels = merge_overlapping(report.labels);
+        Ok(report)
     }
+}
 
-    fn check_overlap(&self) -> Result<(), ReportBuilderError> {
-        let labels = match self.labels {
-            Some(ref labels) => labels,
-            None => return Ok(()),
-        };
-
-        let mut overlapping = Vec::new();
-
-        for (index, label) in labels.iter().enumerate() {
-            for other in labels.iter().skip(index + 1) {
-                if label.span.span.intersect(&other.span.span) {
-                    overlapping.push((label.span.span, other.span.span));
-                }
-            }
-        }
+fn merge_overlapping(labels: Vec<Label>) -> Vec<Label> {
+    let mut merged: Vec<Label> = Vec::new();
 
-        if !overlapping.is_empty() {
-            return Err(ReportBuilderError::OverlappingLabels(overlapping));
+    for mut label in labels {
+        // The merged label can overlap labels, which the original one didn't.
+        while let Some(index) = merged.iter().position(|other| other.overlaps(&label)) {
+            label = merged.remove(index).merge(label);
         }
 
-        Ok(())
+        merged.push(label);
     }
+
+    merged
 }
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
@@ -135,6 +126,25 @@ pub struct Label {
 }
 
 impl Label {
+    /// Labels of different files never overlap, even if their spans do.
+    fn overlaps(&self, other: &Label) -> bool {
+        self.span.file_id == other.span.file_id && self.span.span.intersect(&other.span.span)
+    }
+
+    fn merge(self, other: Label) -> Label {
+        let message = match (self.message, other.message) {
+            (Some(first), Some(second)) => Some(format!("{}; {}", first, second)),
+            (first, second) => first.or(second),
+        };
+
+        Label {
+            span: self.span.combine(&other.span),
+            message,
+            line_span: None,
+            multiline: None,
+        }
+    }
+
     pub fn gather_data(&mut self, files: &Files) {
         let file = files
             .get(self.span.file_id)
@@ -157,7 +167,6 @@ mod test {
     use super::*;
 
     #[test]
-    #[should_panic]
     fn overlapping() {
         let mut files = Files::new();
 
@@ -170,14 +179,14 @@ mod test {
             .label(
                 LabelBuilder::default()
                     .span(LabelSpan::new(0..4, test_file))
-                    .message("")
+                    .message("This")
                     .build()
                     .unwrap(),
             )
             .label(
                 LabelBuilder::default()
                     .span(LabelSpan::new(3..6, test_file))
-                    .message("")
+                    .message("is")
                     .build()
                     .unwrap(),
             )
@@ -186,7 +195,39 @@ mod test {
             .build()
             .unwrap();
 
-        println!("{:#?}", report);
+        let merged = LabelBuilder::default()
+            .span(LabelSpan::new(0..6, test_file))
+            .message("This; is")
+            .build()
+            .unwrap();
+        assert_eq!(report.labels, vec![merged]);
+    }
+
+    #[test]
+    fn different_files() {
+        let mut files = Files::new();
+
+        let first_file = files.add("first.ark", "let a = 1;");
+        let second_file = files.add("second.ark", "let a = 2;");
+
+        ReportBuilder::default()
+            .message("")
+            .code(0)
+            .serverity(Serverity::Note)
+            .label(
+                LabelBuilder::default()
+                    .span(LabelSpan::new(4..5, first_file))
+                    .build()
+                    .unwrap(),
+            )
+            .label(
+                LabelBuilder::default()
+                    .span(LabelSpan::new(4..5, second_file))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
     }
 
     #[test]
@@ -7,6 +7,8 @@ use std::{
     rc::Rc,
 };
 
+use lasso::{Rodeo, Spur};
+
 use crate::{symbol::Symbol, traversal::Visitor};
 use diagnostics::positional::LabelSpan;
 use lexer::token::{Token, TokenKind};
@@ -29,9 +31,15 @@ impl Program {
 pub enum StmtKind {
     ExprStmt(Box<ExprStmt>),
     LetDecl(Box<LetDecl>),
+    DestructureDecl(Box<DestructureDecl>),
     FunDecl(Rc<RefCell<FunDecl>>),
+    StructDecl(Rc<RefCell<StructDecl>>),
+    EnumDecl(Rc<RefCell<EnumDecl>>),
     Block(Box<Block>),
     Return(Box<Return>),
+    While(Box<While>),
+    Break(Box<Break>),
+    Continue(Box<Continue>),
 }
 
 impl StmtKind {
@@ -39,9 +47,15 @@ impl StmtKind {
         match self {
             Self::ExprStmt(node) => node.expression.span(),
             Self::LetDecl(node) => node.span,
+            Self::DestructureDecl(node) => node.span,
             Self::FunDecl(node) => node.borrow().span,
+            Self::StructDecl(node) => node.borrow().span,
+            Self::EnumDecl(node) => node.borrow().span,
             Self::Block(node) => node.span,
             Self::Return(node) => node.span,
+            Self::While(node) => node.span,
+            Self::Break(node) => node.span,
+            Self::Continue(node) => node.span,
         }
     }
 }
@@ -50,11 +64,18 @@ impl StmtKind {
 #[derive(Debug, Clone)]
 pub struct ExprStmt {
     pub expression: ExprKind,
+    /// The type of the expression, which is only known after the type
+    /// checker ran. The REPL shows it next to the value.
+    #[serde(skip)]
+    pub type_: OnceCell<TypeKind>,
 }
 
 impl ExprStmt {
     pub fn new(expression: ExprKind) -> Self {
-        Self { expression }
+        Self {
+            expression,
+            type_: OnceCell::new(),
+        }
     }
 }
 
@@ -67,8 +88,13 @@ impl From<ExprStmt> for StmtKind {
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug, Clone)]
 pub struct LetDecl {
+    /// The doc comments in front of the declaration.
+    pub docs: Vec<Token>,
     pub id: Token,
-    pub type_: Type,
+    pub mutable: bool,
+    /// The type of the variable, which is inferred from the expression if
+    /// it's left out. Either way, the symbol holds the final type.
+    pub type_: Option<Type>,
     pub expression: Option<ExprKind>,
     pub span: LabelSpan,
     #[serde(skip)]
@@ -76,9 +102,18 @@ pub struct LetDecl {
 }
 
 impl LetDecl {
-    pub fn new(id: Token, type_: Type, expression: Option<ExprKind>, span: LabelSpan) -> Self {
+    pub fn new(
+        docs: Vec<Token>,
+        id: Token,
+        mutable: bool,
+        type_: Option<Type>,
+        expression: Option<ExprKind>,
+        span: LabelSpan,
+    ) -> Self {
         Self {
+            docs,
             id,
+            mutable,
             type_,
             expression,
             span,
@@ -93,9 +128,45 @@ impl From<LetDecl> for StmtKind {
     }
 }
 
+/// A `let`, which splits the value of a tuple into a variable per element.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct DestructureDecl {
+    pub pattern: Pattern,
+    /// The type of the whole value, which is inferred from the expression
+    /// if it's left out.
+    pub type_: Option<Type>,
+    pub expression: ExprKind,
+    pub span: LabelSpan,
+}
+
+impl DestructureDecl {
+    pub fn new(
+        pattern: Pattern,
+        type_: Option<Type>,
+        expression: ExprKind,
+        span: LabelSpan,
+    ) -> Self {
+        Self {
+            pattern,
+            type_,
+            expression,
+            span,
+        }
+    }
+}
+
+impl From<DestructureDecl> for StmtKind {
+    fn from(value: DestructureDecl) -> Self {
+        Self::DestructureDecl(Box::new(value))
+    }
+}
+
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug, Clone)]
 pub struct FunDecl {
+    /// The doc comments in front of the declaration.
+    pub docs: Vec<Token>,
     pub id: Token,
     pub parameters: Vec<Parameter>,
     pub type_: Type,
@@ -107,6 +178,7 @@ pub struct FunDecl {
 
 impl FunDecl {
     pub fn new(
+        docs: Vec<Token>,
         id: Token,
         parameters: Vec<Parameter>,
         type_: Type,
@@ -114,6 +186,7 @@ impl FunDecl {
         span: LabelSpan,
     ) -> Self {
         Self {
+            docs,
             id,
             parameters,
             type_,
@@ -130,16 +203,135 @@ impl From<FunDecl> for StmtKind {
     }
 }
 
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct StructDecl {
+    /// The doc comments in front of the declaration.
+    pub docs: Vec<Token>,
+    pub id: Token,
+    pub fields: Vec<FieldDecl>,
+    pub span: LabelSpan,
+    #[serde(skip)]
+    pub symbol: OnceCell<Rc<RefCell<Symbol>>>,
+}
+
+impl StructDecl {
+    pub fn new(docs: Vec<Token>, id: Token, fields: Vec<FieldDecl>, span: LabelSpan) -> Self {
+        Self {
+            docs,
+            id,
+            fields,
+            span,
+            symbol: OnceCell::new(),
+        }
+    }
+
+    /// The position of the field with the given name, which is also the
+    /// position of its value inside of a struct value.
+    pub fn field_index(&self, name: Spur) -> Option<usize> {
+        self.fields
+            .iter()
+            .position(|field| field.id.get_spur() == Some(name))
+    }
+}
+
+impl From<StructDecl> for StmtKind {
+    fn from(value: StructDecl) -> Self {
+        Self::StructDecl(Rc::new(RefCell::new(value)))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct FieldDecl {
+    pub id: Token,
+    pub type_: Type,
+    pub mutable: bool,
+    pub span: LabelSpan,
+}
+
+impl FieldDecl {
+    pub fn new(id: Token, type_: Type, mutable: bool, span: LabelSpan) -> Self {
+        Self {
+            id,
+            type_,
+            mutable,
+            span,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct EnumDecl {
+    /// The doc comments in front of the declaration.
+    pub docs: Vec<Token>,
+    pub id: Token,
+    pub variants: Vec<VariantDecl>,
+    pub span: LabelSpan,
+    #[serde(skip)]
+    pub symbol: OnceCell<Rc<RefCell<Symbol>>>,
+}
+
+impl EnumDecl {
+    pub fn new(docs: Vec<Token>, id: Token, variants: Vec<VariantDecl>, span: LabelSpan) -> Self {
+        Self {
+            docs,
+            id,
+            variants,
+            span,
+            symbol: OnceCell::new(),
+        }
+    }
+
+    /// The position of the variant with the given name, which is also the
+    /// tag of an enum value created from it.
+    pub fn variant_index(&self, name: Spur) -> Option<usize> {
+        self.variants
+            .iter()
+            .position(|variant| variant.id.get_spur() == Some(name))
+    }
+}
+
+impl From<EnumDecl> for StmtKind {
+    fn from(value: EnumDecl) -> Self {
+        Self::EnumDecl(Rc::new(RefCell::new(value)))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct VariantDecl {
+    pub id: Token,
+    /// The types of the values a variant carries, which is empty for a unit
+    /// variant.
+    pub types: Vec<Type>,
+    pub span: LabelSpan,
+}
+
+impl VariantDecl {
+    pub fn new(id: Token, types: Vec<Type>, span: LabelSpan) -> Self {
+        Self { id, types, span }
+    }
+}
+
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug, Clone)]
 pub struct Block {
     pub statements: Vec<StmtKind>,
+    /// The expression at the end of the block without a semicolon, which is
+    /// the value of the whole block.
+    pub tail: Option<ExprKind>,
     pub span: LabelSpan,
 }
 
 impl Block {
-    pub fn new(statements: Vec<StmtKind>, span: LabelSpan) -> Self {
-        Self { statements, span }
+    pub fn new(statements: Vec<StmtKind>, tail: Option<ExprKind>, span: LabelSpan) -> Self {
+        Self {
+            statements,
+            tail,
+            span,
+        }
     }
 }
 
@@ -149,6 +341,12 @@ impl From<Block> for StmtKind {
     }
 }
 
+impl From<Block> for ExprKind {
+    fn from(value: Block) -> Self {
+        Self::Block(Box::new(value))
+    }
+}
+
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug, Clone)]
 pub struct Return {
@@ -168,21 +366,83 @@ impl From<Return> for StmtKind {
     }
 }
 
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct While {
+    pub condition: ExprKind,
+    pub block: Box<Block>,
+    pub span: LabelSpan,
+}
+
+impl While {
+    pub fn new(condition: ExprKind, block: Box<Block>, span: LabelSpan) -> Self {
+        Self {
+            condition,
+            block,
+            span,
+        }
+    }
+}
+
+impl From<While> for StmtKind {
+    fn from(value: While) -> Self {
+        Self::While(Box::new(value))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct Break {
+    pub span: LabelSpan,
+}
+
+impl Break {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<Break> for StmtKind {
+    fn from(value: Break) -> Self {
+        Self::Break(Box::new(value))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct Continue {
+    pub span: LabelSpan,
+}
+
+impl Continue {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<Continue> for StmtKind {
+    fn from(value: Continue) -> Self {
+        Self::Continue(Box::new(value))
+    }
+}
+
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug, Clone)]
 pub struct Parameter {
     pub id: Token,
     pub type_: Type,
+    pub mutable: bool,
     pub span: LabelSpan,
     #[serde(skip)]
     pub symbol: OnceCell<Rc<RefCell<Symbol>>>,
 }
 
 impl Parameter {
-    pub fn new(id: Token, type_: Type, span: LabelSpan) -> Self {
+    pub fn new(id: Token, type_: Type, mutable: bool, span: LabelSpan) -> Self {
         Self {
             id,
             type_,
+            mutable,
             span,
             symbol: OnceCell::new(),
         }
@@ -190,11 +450,93 @@ impl Parameter {
 }
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TypeKind {
     Int(bool, usize),
+    /// A pointer-sized integer, whose width depends on the [`Target`].
+    Size(bool),
     Decimal(usize),
     Bool,
+    Str,
+    /// The return type of a function without a value.
+    Void,
+    /// A struct type, which is identified by the name of its declaration.
+    Struct(Spur),
+    /// An enum type, which is identified by the name of its declaration.
+    Enum(Spur),
+    /// An array with the type of its elements and its length.
+    Array(Box<TypeKind>, usize),
+    /// A view of an array with any length, but the same type of elements.
+    Slice(Box<TypeKind>),
+    /// The types of the elements of a tuple, which are accessed by position.
+    Tuple(Vec<TypeKind>),
+}
+
+impl TypeKind {
+    /// The type used to index into arrays.
+    pub const INDEX: TypeKind = TypeKind::Size(false);
+
+    /// Like the [`Display`] implementation, but also resolves the names of
+    /// struct and enum types.
+    pub fn name(&self, interner: &Rodeo) -> String {
+        match self {
+            Self::Struct(name) | Self::Enum(name) => interner.resolve(name).to_string(),
+            Self::Array(element, length) => format!("[{}; {}]", element.name(interner), length),
+            Self::Slice(element) => format!("[{}]", element.name(interner)),
+            Self::Tuple(elements) => {
+                let elements = elements
+                    .iter()
+                    .map(|element| element.name(interner))
+                    .collect::<Vec<_>>();
+                tuple_name(&elements)
+            }
+            kind => kind.to_string(),
+        }
+    }
+
+    /// The type of the elements, if the type can be indexed.
+    pub fn element(&self) -> Option<&TypeKind> {
+        match self {
+            Self::Array(element, _) | Self::Slice(element) => Some(element),
+            _ => None,
+        }
+    }
+
+    /// Whether the type is the other one or consists of it, such as an array
+    /// of its elements or a tuple with it as one of its elements.
+    pub fn contains(&self, other: &TypeKind) -> bool {
+        match self {
+            kind if kind == other => true,
+            Self::Array(element, _) | Self::Slice(element) => element.contains(other),
+            Self::Tuple(elements) => elements.iter().any(|element| element.contains(other)),
+            _ => false,
+        }
+    }
+
+    pub fn is_integer(&self) -> bool {
+        matches!(self, Self::Int(_, _) | Self::Size(_))
+    }
+
+    pub fn is_number(&self) -> bool {
+        self.is_integer() || matches!(self, Self::Decimal(_))
+    }
+
+    /// Replaces a pointer-sized integer with the integer of the same width,
+    /// which includes the elements of arrays, slices and tuples.
+    pub fn resolve(&self, target: Target) -> TypeKind {
+        match self {
+            Self::Size(signed) => Self::Int(*signed, target.size_width),
+            Self::Array(element, length) => Self::Array(Box::new(element.resolve(target)), *length),
+            Self::Slice(element) => Self::Slice(Box::new(element.resolve(target))),
+            Self::Tuple(elements) => Self::Tuple(
+                elements
+                    .iter()
+                    .map(|element| element.resolve(target))
+                    .collect(),
+            ),
+            kind => kind.clone(),
+        }
+    }
 }
 
 impl Display for TypeKind {
@@ -204,12 +546,35 @@ impl Display for TypeKind {
                 let prefix = if *signed { "i" } else { "u" };
                 write!(f, "{}{}", prefix, size)
             }
+            Self::Size(signed) => {
+                let prefix = if *signed { "i" } else { "u" };
+                write!(f, "{}size", prefix)
+            }
             Self::Decimal(size) => write!(f, "f{}", size),
             Self::Bool => write!(f, "bool"),
+            Self::Str => write!(f, "str"),
+            Self::Void => write!(f, "void"),
+            Self::Struct(_) => write!(f, "struct"),
+            Self::Enum(_) => write!(f, "enum"),
+            Self::Array(element, length) => write!(f, "[{}; {}]", element, length),
+            Self::Slice(element) => write!(f, "[{}]", element),
+            Self::Tuple(elements) => {
+                let elements = elements.iter().map(ToString::to_string).collect::<Vec<_>>();
+                write!(f, "{}", tuple_name(&elements))
+            }
         }
     }
 }
 
+/// A tuple with a single element keeps its trailing comma, so it can't be
+/// confused with a grouped type.
+fn tuple_name(elements: &[String]) -> String {
+    match elements {
+        [element] => format!("({},)", element),
+        elements => format!("({})", elements.join(", ")),
+    }
+}
+
 impl From<TokenKind> for TypeKind {
     fn from(value: TokenKind) -> Self {
         match value {
@@ -221,14 +586,32 @@ impl From<TokenKind> for TypeKind {
             TokenKind::I32 => TypeKind::Int(true, 32),
             TokenKind::U64 => TypeKind::Int(false, 64),
             TokenKind::I64 => TypeKind::Int(true, 64),
+            TokenKind::USize => TypeKind::Size(false),
+            TokenKind::ISize => TypeKind::Size(true),
             TokenKind::F32 => TypeKind::Decimal(32),
             TokenKind::F64 => TypeKind::Decimal(64),
             TokenKind::Bool => TypeKind::Bool,
+            TokenKind::Str => TypeKind::Str,
+            TokenKind::Void => TypeKind::Void,
             _ => panic!("This tokenkind can't be converted to a typekind."),
         }
     }
 }
 
+/// The properties of the machine a program gets compiled for.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Target {
+    /// The width of `usize` and `isize` in bits.
+    pub size_width: usize,
+}
+
+impl Default for Target {
+    fn default() -> Self {
+        Self { size_width: 64 }
+    }
+}
+
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug, Clone)]
 pub struct Type {
@@ -254,9 +637,21 @@ impl Type {
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug, Clone)]
 pub enum ExprKind {
+    Assign(Box<Assign>),
     Binary(Box<Binary>),
     Unary(Box<Unary>),
+    Cast(Box<Cast>),
     Call(Box<Call>),
+    If(Box<If>),
+    Match(Box<Match>),
+    Block(Box<Block>),
+    FieldAccess(Box<FieldAccess>),
+    StructLiteral(Box<StructLiteral>),
+    VariantLiteral(Box<VariantLiteral>),
+    Index(Box<Index>),
+    ArrayLiteral(Box<ArrayLiteral>),
+    TupleLiteral(Box<TupleLiteral>),
+    Len(Box<Len>),
     Grouping(Box<Grouping>),
     Literal(Box<Literal>),
     Id(Box<Id>),
@@ -265,9 +660,21 @@ pub enum ExprKind {
 impl ExprKind {
     pub fn span(&self) -> LabelSpan {
         match self {
+            Self::Assign(node) => node.span,
             Self::Binary(node) => node.span,
             Self::Unary(node) => node.span,
+            Self::Cast(node) => node.span,
             Self::Call(node) => node.span,
+            Self::If(node) => node.span,
+            Self::Match(node) => node.span,
+            Self::Block(node) => node.span,
+            Self::FieldAccess(node) => node.span,
+            Self::StructLiteral(node) => node.span,
+            Self::VariantLiteral(node) => node.span,
+            Self::Index(node) => node.span,
+            Self::ArrayLiteral(node) => node.span,
+            Self::TupleLiteral(node) => node.span,
+            Self::Len(node) => node.span,
             Self::Grouping(node) => node.span,
             Self::Literal(node) => node.token.span,
             Self::Id(node) => node.id.span,
@@ -278,6 +685,8 @@ impl ExprKind {
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum BinaryOperator {
+    LogOr,
+    LogAnd,
     Eq,
     NotEq,
     Greater,
@@ -288,9 +697,21 @@ pub enum BinaryOperator {
     Sub,
     Mul,
     Div,
+    Rem,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Shl,
+    Shr,
 }
 
 impl BinaryOperator {
+    /// The right operand of a logical operator is only evaluated, if the left
+    /// one doesn't already decide the result.
+    pub fn is_logical(&self) -> bool {
+        matches!(self, Self::LogOr | Self::LogAnd)
+    }
+
     pub fn is_equality(&self) -> bool {
         matches!(self, Self::Eq | Self::NotEq)
     }
@@ -307,23 +728,49 @@ impl BinaryOperator {
     }
 
     pub fn is_factor(&self) -> bool {
-        matches!(self, Self::Div | Self::Mul)
+        matches!(self, Self::Div | Self::Mul | Self::Rem)
+    }
+
+    pub fn is_bitwise(&self) -> bool {
+        matches!(self, Self::BitOr | Self::BitXor | Self::BitAnd)
+    }
+
+    pub fn is_shift(&self) -> bool {
+        matches!(self, Self::Shl | Self::Shr)
+    }
+
+    /// The operators, whose operands have the type of their result.
+    pub fn is_arithmetic(&self) -> bool {
+        self.is_term() || self.is_factor() || self.is_bitwise()
+    }
+
+    /// The operators that fail, if their right operand is zero.
+    pub fn is_division(&self) -> bool {
+        matches!(self, Self::Div | Self::Rem)
     }
 }
 
 impl Display for BinaryOperator {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
+            Self::LogOr => write!(f, "||"),
+            Self::LogAnd => write!(f, "&&"),
             Self::Eq => write!(f, "=="),
             Self::NotEq => write!(f, "!="),
             Self::Greater => write!(f, ">"),
             Self::GreaterEq => write!(f, ">="),
-            Self::Less => write!(f, "<="),
+            Self::Less => write!(f, "<"),
             Self::LessEq => write!(f, "<="),
             Self::Add => write!(f, "+"),
             Self::Sub => write!(f, "-"),
             Self::Mul => write!(f, "*"),
             Self::Div => write!(f, "/"),
+            Self::Rem => write!(f, "%"),
+            Self::BitOr => write!(f, "|"),
+            Self::BitXor => write!(f, "^"),
+            Self::BitAnd => write!(f, "&"),
+            Self::Shl => write!(f, "<<"),
+            Self::Shr => write!(f, ">>"),
         }
     }
 }
@@ -331,6 +778,8 @@ impl Display for BinaryOperator {
 impl From<Token> for BinaryOperator {
     fn from(value: Token) -> Self {
         match value.kind {
+            TokenKind::PipePipe => Self::LogOr,
+            TokenKind::AmpersandAmpersand => Self::LogAnd,
             TokenKind::EqEq => Self::Eq,
             TokenKind::NotEq => Self::NotEq,
             TokenKind::Greater => Self::Greater,
@@ -341,6 +790,12 @@ impl From<Token> for BinaryOperator {
             TokenKind::Minus => Self::Sub,
             TokenKind::Asterisk => Self::Mul,
             TokenKind::Slash => Self::Div,
+            TokenKind::Percent => Self::Rem,
+            TokenKind::Pipe => Self::BitOr,
+            TokenKind::Caret => Self::BitXor,
+            TokenKind::Ampersand => Self::BitAnd,
+            TokenKind::LessLess => Self::Shl,
+            TokenKind::GreaterGreater => Self::Shr,
             _ => todo!("This convertion is not implemented."),
         }
     }
@@ -377,11 +832,79 @@ impl From<Binary> for ExprKind {
     }
 }
 
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AssignOperator {
+    Assign,
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl AssignOperator {
+    /// The operator, which combines the old value of a compound assignment
+    /// with the new one.
+    pub fn binary(&self) -> Option<BinaryOperator> {
+        match self {
+            Self::Assign => None,
+            Self::Add => Some(BinaryOperator::Add),
+            Self::Sub => Some(BinaryOperator::Sub),
+            Self::Mul => Some(BinaryOperator::Mul),
+            Self::Div => Some(BinaryOperator::Div),
+        }
+    }
+}
+
+impl Display for AssignOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Assign => write!(f, "="),
+            Self::Add => write!(f, "+="),
+            Self::Sub => write!(f, "-="),
+            Self::Mul => write!(f, "*="),
+            Self::Div => write!(f, "/="),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct Assign {
+    pub target: ExprKind,
+    pub operator: AssignOperator,
+    pub value: ExprKind,
+    pub span: LabelSpan,
+}
+
+impl Assign {
+    pub fn new(
+        target: ExprKind,
+        operator: AssignOperator,
+        value: ExprKind,
+        span: LabelSpan,
+    ) -> Self {
+        Self {
+            target,
+            operator,
+            value,
+            span,
+        }
+    }
+}
+
+impl From<Assign> for ExprKind {
+    fn from(value: Assign) -> Self {
+        Self::Assign(Box::new(value))
+    }
+}
+
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum UnaryOperator {
     Neg,
     LogNeg,
+    BitNot,
 }
 
 impl Display for UnaryOperator {
@@ -389,6 +912,7 @@ impl Display for UnaryOperator {
         match self {
             Self::Neg => write!(f, "-"),
             Self::LogNeg => write!(f, "!"),
+            Self::BitNot => write!(f, "~"),
         }
     }
 }
@@ -398,6 +922,7 @@ impl From<Token> for UnaryOperator {
         match value.kind {
             TokenKind::Minus => Self::Neg,
             TokenKind::Apostrophe => Self::LogNeg,
+            TokenKind::Tilde => Self::BitNot,
             _ => todo!("This convertion is not implemented."),
         }
     }
@@ -427,6 +952,31 @@ impl From<Unary> for ExprKind {
     }
 }
 
+/// Explicitly converts the value of the expression to another type.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct Cast {
+    pub expression: ExprKind,
+    pub type_: Type,
+    pub span: LabelSpan,
+}
+
+impl Cast {
+    pub fn new(expression: ExprKind, type_: Type, span: LabelSpan) -> Self {
+        Self {
+            expression,
+            type_,
+            span,
+        }
+    }
+}
+
+impl From<Cast> for ExprKind {
+    fn from(value: Cast) -> Self {
+        Self::Cast(Box::new(value))
+    }
+}
+
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug, Clone)]
 pub struct Call {
@@ -456,16 +1006,457 @@ impl From<Call> for ExprKind {
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug, Clone)]
-pub struct Grouping {
-    pub expression: ExprKind,
-    span: LabelSpan,
+pub struct If {
+    pub condition: ExprKind,
+    pub then: Box<Block>,
+    pub else_: Option<Else>,
+    pub span: LabelSpan,
 }
 
-impl Grouping {
-    pub fn new(expression: ExprKind, span: LabelSpan) -> Self {
-        Self { expression, span }
-    }
-}
+impl If {
+    pub fn new(
+        condition: ExprKind,
+        then: Box<Block>,
+        else_: Option<Else>,
+        span: LabelSpan,
+    ) -> Self {
+        Self {
+            condition,
+            then,
+            else_,
+            span,
+        }
+    }
+}
+
+impl From<If> for ExprKind {
+    fn from(value: If) -> Self {
+        Self::If(Box::new(value))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub enum Else {
+    Block(Box<Block>),
+    If(Box<If>),
+}
+
+impl Else {
+    pub fn span(&self) -> LabelSpan {
+        match self {
+            Self::Block(node) => node.span,
+            Self::If(node) => node.span,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct Match {
+    pub expression: ExprKind,
+    pub arms: Vec<MatchArm>,
+    pub span: LabelSpan,
+}
+
+impl Match {
+    pub fn new(expression: ExprKind, arms: Vec<MatchArm>, span: LabelSpan) -> Self {
+        Self {
+            expression,
+            arms,
+            span,
+        }
+    }
+}
+
+impl From<Match> for ExprKind {
+    fn from(value: Match) -> Self {
+        Self::Match(Box::new(value))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct MatchArm {
+    pub pattern: Pattern,
+    pub expression: ExprKind,
+    pub span: LabelSpan,
+}
+
+impl MatchArm {
+    pub fn new(pattern: Pattern, expression: ExprKind, span: LabelSpan) -> Self {
+        Self {
+            pattern,
+            expression,
+            span,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    Wildcard(Box<Wildcard>),
+    Binding(Box<Binding>),
+    Literal(Box<LiteralPattern>),
+    Variant(Box<VariantPattern>),
+    Tuple(Box<TuplePattern>),
+}
+
+impl Pattern {
+    pub fn span(&self) -> LabelSpan {
+        match self {
+            Self::Wildcard(node) => node.span,
+            Self::Binding(node) => node.id.span,
+            Self::Literal(node) => node.span,
+            Self::Variant(node) => node.span,
+            Self::Tuple(node) => node.span,
+        }
+    }
+
+    /// The bindings inside of the pattern from left to right.
+    pub fn bindings(&self) -> Vec<&Binding> {
+        match self {
+            Self::Binding(node) => vec![node.as_ref()],
+            Self::Variant(node) => node.patterns.iter().flat_map(Pattern::bindings).collect(),
+            Self::Tuple(node) => node.patterns.iter().flat_map(Pattern::bindings).collect(),
+            Self::Wildcard(_) | Self::Literal(_) => Vec::new(),
+        }
+    }
+}
+
+/// The `_` pattern, which matches any value without binding it.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct Wildcard {
+    pub span: LabelSpan,
+}
+
+impl Wildcard {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<Wildcard> for Pattern {
+    fn from(value: Wildcard) -> Self {
+        Self::Wildcard(Box::new(value))
+    }
+}
+
+/// A pattern, which matches any value and binds it to a variable.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub id: Token,
+    pub mutable: bool,
+    #[serde(skip)]
+    pub symbol: OnceCell<Rc<RefCell<Symbol>>>,
+}
+
+impl Binding {
+    pub fn new(id: Token, mutable: bool) -> Self {
+        Self {
+            id,
+            mutable,
+            symbol: OnceCell::new(),
+        }
+    }
+}
+
+impl From<Binding> for Pattern {
+    fn from(value: Binding) -> Self {
+        Self::Binding(Box::new(value))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct LiteralPattern {
+    pub literal: Literal,
+    /// Whether the number was written with a leading `-`.
+    pub negated: bool,
+    pub span: LabelSpan,
+}
+
+impl LiteralPattern {
+    pub fn new(literal: Literal, negated: bool, span: LabelSpan) -> Self {
+        Self {
+            literal,
+            negated,
+            span,
+        }
+    }
+}
+
+impl From<LiteralPattern> for Pattern {
+    fn from(value: LiteralPattern) -> Self {
+        Self::Literal(Box::new(value))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct VariantPattern {
+    pub id: Token,
+    pub variant: Token,
+    /// The patterns for the values of the variant, which are matched from
+    /// left to right.
+    pub patterns: Vec<Pattern>,
+    pub span: LabelSpan,
+    #[serde(skip)]
+    pub symbol: OnceCell<Rc<RefCell<Symbol>>>,
+    /// The position of the variant inside of the enum declaration.
+    #[serde(skip)]
+    pub index: OnceCell<usize>,
+}
+
+impl VariantPattern {
+    pub fn new(id: Token, variant: Token, patterns: Vec<Pattern>, span: LabelSpan) -> Self {
+        Self {
+            id,
+            variant,
+            patterns,
+            span,
+            symbol: OnceCell::new(),
+            index: OnceCell::new(),
+        }
+    }
+}
+
+impl From<VariantPattern> for Pattern {
+    fn from(value: VariantPattern) -> Self {
+        Self::Variant(Box::new(value))
+    }
+}
+
+/// Matches a tuple by matching each of its elements with a pattern.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct TuplePattern {
+    pub patterns: Vec<Pattern>,
+    pub span: LabelSpan,
+}
+
+impl TuplePattern {
+    pub fn new(patterns: Vec<Pattern>, span: LabelSpan) -> Self {
+        Self { patterns, span }
+    }
+}
+
+impl From<TuplePattern> for Pattern {
+    fn from(value: TuplePattern) -> Self {
+        Self::Tuple(Box::new(value))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct FieldAccess {
+    pub target: ExprKind,
+    pub field: Token,
+    pub span: LabelSpan,
+    /// The position of the field inside of the struct or the element inside
+    /// of the tuple, which is only known after the type of the target got
+    /// checked.
+    #[serde(skip)]
+    pub index: OnceCell<usize>,
+}
+
+impl FieldAccess {
+    pub fn new(target: ExprKind, field: Token, span: LabelSpan) -> Self {
+        Self {
+            target,
+            field,
+            span,
+            index: OnceCell::new(),
+        }
+    }
+}
+
+impl From<FieldAccess> for ExprKind {
+    fn from(value: FieldAccess) -> Self {
+        Self::FieldAccess(Box::new(value))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct StructLiteral {
+    pub id: Token,
+    pub fields: Vec<FieldInit>,
+    pub span: LabelSpan,
+    #[serde(skip)]
+    pub symbol: OnceCell<Rc<RefCell<Symbol>>>,
+}
+
+impl StructLiteral {
+    pub fn new(id: Token, fields: Vec<FieldInit>, span: LabelSpan) -> Self {
+        Self {
+            id,
+            fields,
+            span,
+            symbol: OnceCell::new(),
+        }
+    }
+}
+
+impl From<StructLiteral> for ExprKind {
+    fn from(value: StructLiteral) -> Self {
+        Self::StructLiteral(Box::new(value))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct FieldInit {
+    pub id: Token,
+    pub expression: ExprKind,
+    pub span: LabelSpan,
+    /// The position of the field inside of the struct declaration.
+    #[serde(skip)]
+    pub index: OnceCell<usize>,
+}
+
+impl FieldInit {
+    pub fn new(id: Token, expression: ExprKind, span: LabelSpan) -> Self {
+        Self {
+            id,
+            expression,
+            span,
+            index: OnceCell::new(),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct VariantLiteral {
+    pub id: Token,
+    pub variant: Token,
+    /// The values of the variant, which is empty for a unit variant.
+    pub arguments: Vec<ExprKind>,
+    pub span: LabelSpan,
+    #[serde(skip)]
+    pub symbol: OnceCell<Rc<RefCell<Symbol>>>,
+    /// The position of the variant inside of the enum declaration.
+    #[serde(skip)]
+    pub index: OnceCell<usize>,
+}
+
+impl VariantLiteral {
+    pub fn new(id: Token, variant: Token, arguments: Vec<ExprKind>, span: LabelSpan) -> Self {
+        Self {
+            id,
+            variant,
+            arguments,
+            span,
+            symbol: OnceCell::new(),
+            index: OnceCell::new(),
+        }
+    }
+}
+
+impl From<VariantLiteral> for ExprKind {
+    fn from(value: VariantLiteral) -> Self {
+        Self::VariantLiteral(Box::new(value))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct Index {
+    pub target: ExprKind,
+    pub index: ExprKind,
+    pub span: LabelSpan,
+}
+
+impl Index {
+    pub fn new(target: ExprKind, index: ExprKind, span: LabelSpan) -> Self {
+        Self {
+            target,
+            index,
+            span,
+        }
+    }
+}
+
+impl From<Index> for ExprKind {
+    fn from(value: Index) -> Self {
+        Self::Index(Box::new(value))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct ArrayLiteral {
+    pub elements: Vec<ExprKind>,
+    pub span: LabelSpan,
+}
+
+impl ArrayLiteral {
+    pub fn new(elements: Vec<ExprKind>, span: LabelSpan) -> Self {
+        Self { elements, span }
+    }
+}
+
+impl From<ArrayLiteral> for ExprKind {
+    fn from(value: ArrayLiteral) -> Self {
+        Self::ArrayLiteral(Box::new(value))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct TupleLiteral {
+    pub elements: Vec<ExprKind>,
+    pub span: LabelSpan,
+}
+
+impl TupleLiteral {
+    pub fn new(elements: Vec<ExprKind>, span: LabelSpan) -> Self {
+        Self { elements, span }
+    }
+}
+
+impl From<TupleLiteral> for ExprKind {
+    fn from(value: TupleLiteral) -> Self {
+        Self::TupleLiteral(Box::new(value))
+    }
+}
+
+/// The builtin, which results in the amount of elements of an array or slice.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct Len {
+    pub expression: ExprKind,
+    pub span: LabelSpan,
+}
+
+impl Len {
+    pub fn new(expression: ExprKind, span: LabelSpan) -> Self {
+        Self { expression, span }
+    }
+}
+
+impl From<Len> for ExprKind {
+    fn from(value: Len) -> Self {
+        Self::Len(Box::new(value))
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct Grouping {
+    pub expression: ExprKind,
+    span: LabelSpan,
+}
+
+impl Grouping {
+    pub fn new(expression: ExprKind, span: LabelSpan) -> Self {
+        Self { expression, span }
+    }
+}
 
 impl From<Grouping> for ExprKind {
     fn from(value: Grouping) -> Self {
@@ -510,11 +1501,23 @@ pub enum LiteralKind {
 pub struct Literal {
     pub token: Token,
     pub kind: LiteralKind,
+    /// The type given by the suffix of a number, such as the `u8` of `42u8`.
+    pub suffix: Option<TypeKind>,
+    /// The type of the literal, which is only known after the type checker
+    /// found the type expected by its context.
+    #[serde(skip)]
+    pub type_: OnceCell<TypeKind>,
 }
 
 impl Literal {
     pub fn new(token: Token, kind: LiteralKind) -> Self {
-        Self { token, kind }
+        let suffix = token.get_suffix().map(TypeKind::from);
+        Self {
+            token,
+            kind,
+            suffix,
+            type_: OnceCell::new(),
+        }
     }
 }
 
@@ -11,7 +11,7 @@ use lasso::Spur;
 
 use diagnostics::positional::LabelSpan;
 
-use crate::{FunDecl, Type};
+use crate::{EnumDecl, FunDecl, StructDecl, Type};
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug, Clone)]
@@ -20,6 +20,8 @@ pub enum SymbolKind {
     GlobalVar,
     Parameter,
     Function(Rc<RefCell<FunDecl>>),
+    Struct(Rc<RefCell<StructDecl>>),
+    Enum(Rc<RefCell<EnumDecl>>),
 }
 
 impl Display for SymbolKind {
@@ -29,6 +31,8 @@ impl Display for SymbolKind {
             Self::GlobalVar => write!(f, "global variable"),
             Self::Parameter => write!(f, "parameter"),
             Self::Function(_) => write!(f, "function"),
+            Self::Struct(_) => write!(f, "struct"),
+            Self::Enum(_) => write!(f, "enum"),
         }
     }
 }
@@ -40,6 +44,8 @@ impl PartialEq for SymbolKind {
             (Self::GlobalVar, Self::GlobalVar) => true,
             (Self::Parameter, Self::Parameter) => true,
             (Self::Function(first), Self::Function(second)) => Rc::ptr_eq(first, second),
+            (Self::Struct(first), Self::Struct(second)) => Rc::ptr_eq(first, second),
+            (Self::Enum(first), Self::Enum(second)) => Rc::ptr_eq(first, second),
             _ => false,
         }
     }
@@ -53,15 +59,18 @@ pub struct Symbol {
     #[serde(skip)]
     pub type_: OnceCell<Type>,
     pub span: LabelSpan,
+    /// Whether the symbol was declared with a `!` and can be assigned to.
+    pub mutable: bool,
 }
 
 impl Symbol {
-    pub fn new(name: Spur, span: LabelSpan, kind: SymbolKind) -> Self {
+    pub fn new(name: Spur, span: LabelSpan, kind: SymbolKind, mutable: bool) -> Self {
         Self {
             name,
             span,
             kind,
             type_: OnceCell::new(),
+            mutable,
         }
     }
 }
@@ -5,8 +5,10 @@ use std::{cell::RefCell, rc::Rc};
 
 use crate::{
     ast::{
-        Block, Call, ExprKind, ExprStmt, FunDecl, Grouping, Id, LetDecl, Literal, Parameter,
-        Program, StmtKind, Type, Unary,
+        ArrayLiteral, Assign, Block, Break, Call, Cast, Continue, DestructureDecl, Else, EnumDecl,
+        ExprKind, ExprStmt, FieldAccess, FieldDecl, FieldInit, FunDecl, Grouping, Id, If, Index,
+        Len, LetDecl, Literal, Match, MatchArm, Parameter, Pattern, Program, StmtKind, StructDecl,
+        StructLiteral, TupleLiteral, Type, Unary, VariantDecl, VariantLiteral, While,
     },
     Binary, Return,
 };
@@ -33,6 +35,13 @@ pub trait Visitor: Sized {
         node.walk(self)
     }
 
+    fn visit_destructure_decl(
+        &mut self,
+        node: &mut DestructureDecl,
+    ) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
     fn visit_fun_decl(
         &mut self,
         node: &mut Rc<RefCell<FunDecl>>,
@@ -44,6 +53,28 @@ pub trait Visitor: Sized {
         node.walk(self)
     }
 
+    fn visit_struct_decl(
+        &mut self,
+        node: &mut Rc<RefCell<StructDecl>>,
+    ) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_field_decl(&mut self, node: &mut FieldDecl) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_enum_decl(
+        &mut self,
+        node: &mut Rc<RefCell<EnumDecl>>,
+    ) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_variant_decl(&mut self, node: &mut VariantDecl) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
     fn visit_block(&mut self, node: &mut Block) -> Result<Self::Return, Self::Error> {
         node.walk(self)
     }
@@ -52,10 +83,26 @@ pub trait Visitor: Sized {
         node.walk(self)
     }
 
+    fn visit_while(&mut self, node: &mut While) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_break(&mut self, node: &mut Break) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_continue(&mut self, node: &mut Continue) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
     fn visit_expr(&mut self, node: &mut ExprKind) -> Result<Self::Return, Self::Error> {
         node.walk(self)
     }
 
+    fn visit_assign(&mut self, node: &mut Assign) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
     fn visit_binary(&mut self, node: &mut Binary) -> Result<Self::Return, Self::Error> {
         node.walk(self)
     }
@@ -64,10 +111,78 @@ pub trait Visitor: Sized {
         node.walk(self)
     }
 
+    fn visit_cast(&mut self, node: &mut Cast) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
     fn visit_call(&mut self, node: &mut Call) -> Result<Self::Return, Self::Error> {
         node.walk(self)
     }
 
+    fn visit_if(&mut self, node: &mut If) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_else(&mut self, node: &mut Else) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_match(&mut self, node: &mut Match) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_match_arm(&mut self, node: &mut MatchArm) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_pattern(&mut self, node: &mut Pattern) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_field_access(&mut self, node: &mut FieldAccess) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_struct_literal(
+        &mut self,
+        node: &mut StructLiteral,
+    ) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_field_init(&mut self, node: &mut FieldInit) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_variant_literal(
+        &mut self,
+        node: &mut VariantLiteral,
+    ) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_index(&mut self, node: &mut Index) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_array_literal(
+        &mut self,
+        node: &mut ArrayLiteral,
+    ) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_tuple_literal(
+        &mut self,
+        node: &mut TupleLiteral,
+    ) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
+    fn visit_len(&mut self, node: &mut Len) -> Result<Self::Return, Self::Error> {
+        node.walk(self)
+    }
+
     fn visit_grouping(&mut self, node: &mut Grouping) -> Result<Self::Return, Self::Error> {
         node.walk(self)
     }
@@ -117,9 +232,15 @@ impl<V: Visitor> Walkable<V> for StmtKind {
         match self {
             Self::ExprStmt(node) => node.accept(visitor),
             Self::LetDecl(node) => node.accept(visitor),
+            Self::DestructureDecl(node) => node.accept(visitor),
             Self::FunDecl(node) => node.accept(visitor),
+            Self::StructDecl(node) => node.accept(visitor),
+            Self::EnumDecl(node) => node.accept(visitor),
             Self::Block(node) => node.accept(visitor),
             Self::Return(node) => node.accept(visitor),
+            Self::While(node) => node.accept(visitor),
+            Self::Break(node) => node.accept(visitor),
+            Self::Continue(node) => node.accept(visitor),
         }
     }
 }
@@ -144,7 +265,9 @@ impl<V: Visitor> Visitable<V> for ExprStmt {
 
 impl<V: Visitor> Walkable<V> for LetDecl {
     fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
-        self.type_.accept(visitor)?;
+        if let Some(ref mut type_) = self.type_ {
+            type_.accept(visitor)?;
+        }
 
         if let Some(ref mut expression) = self.expression {
             expression.accept(visitor)?;
@@ -160,6 +283,25 @@ impl<V: Visitor> Visitable<V> for LetDecl {
     }
 }
 
+impl<V: Visitor> Walkable<V> for DestructureDecl {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        if let Some(ref mut type_) = self.type_ {
+            type_.accept(visitor)?;
+        }
+
+        self.expression.accept(visitor)?;
+        self.pattern.accept(visitor)?;
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for DestructureDecl {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_destructure_decl(self)
+    }
+}
+
 impl<V: Visitor> Walkable<V> for Rc<RefCell<FunDecl>> {
     fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
         self.borrow_mut()
@@ -184,6 +326,74 @@ impl<V: Visitor> Visitable<V> for Rc<RefCell<FunDecl>> {
     }
 }
 
+impl<V: Visitor> Walkable<V> for Rc<RefCell<StructDecl>> {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.borrow_mut().fields.iter_mut().try_for_each(|field| {
+            field.accept(visitor)?;
+            Ok(())
+        })?;
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for Rc<RefCell<StructDecl>> {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_struct_decl(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for FieldDecl {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.type_.accept(visitor)?;
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for FieldDecl {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_field_decl(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for Rc<RefCell<EnumDecl>> {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.borrow_mut()
+            .variants
+            .iter_mut()
+            .try_for_each(|variant| {
+                variant.accept(visitor)?;
+                Ok(())
+            })?;
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for Rc<RefCell<EnumDecl>> {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_enum_decl(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for VariantDecl {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.types.iter_mut().try_for_each(|type_| {
+            type_.accept(visitor)?;
+            Ok(())
+        })?;
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for VariantDecl {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_variant_decl(self)
+    }
+}
+
 impl<V: Visitor> Walkable<V> for Parameter {
     fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
         self.type_.accept(visitor)?;
@@ -205,7 +415,10 @@ impl<V: Visitor> Walkable<V> for Block {
             Ok(())
         })?;
 
-        V::default_result()
+        match self.tail {
+            Some(ref mut tail) => tail.accept(visitor),
+            None => V::default_result(),
+        }
     }
 }
 
@@ -230,12 +443,55 @@ impl<V: Visitor> Visitable<V> for Return {
     }
 }
 
+impl<V: Visitor> Walkable<V> for While {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.condition.accept(visitor)?;
+        self.block.accept(visitor)?;
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for While {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_while(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for Break {}
+
+impl<V: Visitor> Visitable<V> for Break {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_break(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for Continue {}
+
+impl<V: Visitor> Visitable<V> for Continue {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_continue(self)
+    }
+}
+
 impl<V: Visitor> Walkable<V> for ExprKind {
     fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
         match self {
+            ExprKind::Assign(node) => node.accept(visitor),
             ExprKind::Binary(node) => node.accept(visitor),
             ExprKind::Unary(node) => node.accept(visitor),
+            ExprKind::Cast(node) => node.accept(visitor),
             ExprKind::Call(node) => node.accept(visitor),
+            ExprKind::If(node) => node.accept(visitor),
+            ExprKind::Match(node) => node.accept(visitor),
+            ExprKind::Block(node) => node.accept(visitor),
+            ExprKind::FieldAccess(node) => node.accept(visitor),
+            ExprKind::StructLiteral(node) => node.accept(visitor),
+            ExprKind::VariantLiteral(node) => node.accept(visitor),
+            ExprKind::Index(node) => node.accept(visitor),
+            ExprKind::ArrayLiteral(node) => node.accept(visitor),
+            ExprKind::TupleLiteral(node) => node.accept(visitor),
+            ExprKind::Len(node) => node.accept(visitor),
             ExprKind::Grouping(node) => node.accept(visitor),
             ExprKind::Literal(node) => node.accept(visitor),
             ExprKind::Id(node) => node.accept(visitor),
@@ -249,6 +505,21 @@ impl<V: Visitor> Visitable<V> for ExprKind {
     }
 }
 
+impl<V: Visitor> Walkable<V> for Assign {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.target.accept(visitor)?;
+        self.value.accept(visitor)?;
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for Assign {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_assign(self)
+    }
+}
+
 impl<V: Visitor> Walkable<V> for Binary {
     fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
         self.lhs.accept(visitor)?;
@@ -278,6 +549,21 @@ impl<V: Visitor> Visitable<V> for Unary {
     }
 }
 
+impl<V: Visitor> Walkable<V> for Cast {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.expression.accept(visitor)?;
+        self.type_.accept(visitor)?;
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for Cast {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_cast(self)
+    }
+}
+
 impl<V: Visitor> Walkable<V> for Call {
     fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
         self.callee.accept(visitor)?;
@@ -297,6 +583,216 @@ impl<V: Visitor> Visitable<V> for Call {
     }
 }
 
+impl<V: Visitor> Walkable<V> for If {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.condition.accept(visitor)?;
+        self.then.accept(visitor)?;
+
+        if let Some(ref mut else_) = self.else_ {
+            else_.accept(visitor)?;
+        }
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for If {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_if(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for Else {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        match self {
+            Self::Block(node) => node.accept(visitor),
+            Self::If(node) => node.accept(visitor),
+        }
+    }
+}
+
+impl<V: Visitor> Visitable<V> for Else {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_else(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for Match {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.expression.accept(visitor)?;
+
+        self.arms.iter_mut().try_for_each(|arm| {
+            arm.accept(visitor)?;
+            Ok(())
+        })?;
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for Match {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_match(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for MatchArm {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.pattern.accept(visitor)?;
+        self.expression.accept(visitor)
+    }
+}
+
+impl<V: Visitor> Visitable<V> for MatchArm {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_match_arm(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for Pattern {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        let patterns = match self {
+            Self::Variant(node) => &mut node.patterns,
+            Self::Tuple(node) => &mut node.patterns,
+            _ => return V::default_result(),
+        };
+
+        patterns.iter_mut().try_for_each(|pattern| {
+            pattern.accept(visitor)?;
+            Ok(())
+        })?;
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for Pattern {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_pattern(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for FieldAccess {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.target.accept(visitor)?;
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for FieldAccess {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_field_access(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for StructLiteral {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.fields.iter_mut().try_for_each(|field| {
+            field.accept(visitor)?;
+            Ok(())
+        })?;
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for StructLiteral {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_struct_literal(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for FieldInit {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.expression.accept(visitor)
+    }
+}
+
+impl<V: Visitor> Visitable<V> for FieldInit {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_field_init(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for VariantLiteral {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.arguments.iter_mut().try_for_each(|argument| {
+            argument.accept(visitor)?;
+            Ok(())
+        })?;
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for VariantLiteral {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_variant_literal(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for Index {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.target.accept(visitor)?;
+        self.index.accept(visitor)?;
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for Index {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_index(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for ArrayLiteral {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.elements.iter_mut().try_for_each(|element| {
+            element.accept(visitor)?;
+            Ok(())
+        })?;
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for ArrayLiteral {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_array_literal(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for TupleLiteral {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.elements.iter_mut().try_for_each(|element| {
+            element.accept(visitor)?;
+            Ok(())
+        })?;
+
+        V::default_result()
+    }
+}
+
+impl<V: Visitor> Visitable<V> for TupleLiteral {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_tuple_literal(self)
+    }
+}
+
+impl<V: Visitor> Walkable<V> for Len {
+    fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        self.expression.accept(visitor)
+    }
+}
+
+impl<V: Visitor> Visitable<V> for Len {
+    fn accept(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
+        visitor.visit_len(self)
+    }
+}
+
 impl<V: Visitor> Walkable<V> for Grouping {
     fn walk(&mut self, visitor: &mut V) -> Result<V::Return, V::Error> {
         self.expression.accept(visitor)
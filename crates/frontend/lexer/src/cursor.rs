@@ -49,6 +49,10 @@ impl<'a> Cursor<'a> {
         LabelSpan::new(Span::new(self.start, self.current_index()), self.file_id)
     }
 
+    pub fn slice(&self, span: &Span) -> Option<&'a str> {
+        self.files.slice(self.file_id, span)
+    }
+
     // TODO: Remove the expect
     pub fn as_str(&mut self) -> &'a str {
         let span = self.as_span();
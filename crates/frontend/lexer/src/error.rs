@@ -7,6 +7,8 @@ use diagnostics::{
 };
 use lasso::Rodeo;
 
+use crate::token::TokenKind;
+
 pub type Result<T> = std::result::Result<T, LexerError>;
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
@@ -57,10 +59,232 @@ impl Reportable for DidntExpect {
     }
 }
 
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct InconsistentDedent {
+    span: LabelSpan,
+}
+
+impl InconsistentDedent {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<InconsistentDedent> for LexerError {
+    fn from(value: InconsistentDedent) -> Self {
+        Self::InconsistentDedent(value)
+    }
+}
+
+impl Reportable for InconsistentDedent {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("The indentation doesn't match any of the enclosing blocks.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message("This line needs to be indented like one of the lines before")
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct InvalidEscape {
+    span: LabelSpan,
+}
+
+impl InvalidEscape {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<InvalidEscape> for LexerError {
+    fn from(value: InvalidEscape) -> Self {
+        Self::InvalidEscape(value)
+    }
+}
+
+impl Reportable for InvalidEscape {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("The string contains an invalid escape sequence.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message(r#"Expected one of \n, \t, \r, \0, \", \\ or \u{...}"#)
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct UnterminatedString {
+    span: LabelSpan,
+}
+
+impl UnterminatedString {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<UnterminatedString> for LexerError {
+    fn from(value: UnterminatedString) -> Self {
+        Self::UnterminatedString(value)
+    }
+}
+
+impl Reportable for UnterminatedString {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("The string is missing its closing quote.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct UnterminatedComment {
+    span: LabelSpan,
+}
+
+impl UnterminatedComment {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<UnterminatedComment> for LexerError {
+    fn from(value: UnterminatedComment) -> Self {
+        Self::UnterminatedComment(value)
+    }
+}
+
+impl Reportable for UnterminatedComment {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("The block comment is never closed.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message("Expected a matching '*/' for this opening")
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct InvalidSuffix {
+    span: LabelSpan,
+}
+
+impl InvalidSuffix {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<InvalidSuffix> for LexerError {
+    fn from(value: InvalidSuffix) -> Self {
+        Self::InvalidSuffix(value)
+    }
+}
+
+impl Reportable for InvalidSuffix {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("The number has an invalid type suffix.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message("Expected one of u8, i8, u16, i16, u32, i32, u64, i64, usize, isize or f32, f64 for decimals")
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct NumberOutOfRange {
+    span: LabelSpan,
+    suffix: Option<TokenKind>,
+}
+
+impl NumberOutOfRange {
+    pub fn new(span: LabelSpan, suffix: Option<TokenKind>) -> Self {
+        Self { span, suffix }
+    }
+}
+
+impl From<NumberOutOfRange> for LexerError {
+    fn from(value: NumberOutOfRange) -> Self {
+        Self::NumberOutOfRange(value)
+    }
+}
+
+impl Reportable for NumberOutOfRange {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        let label_message = match self.suffix {
+            Some(suffix) => format!("This number doesn't fit into '{}'", suffix),
+            None => "This number doesn't fit into 64 bits".to_string(),
+        };
+
+        ReportBuilder::default()
+            .message("The number is out of range.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message(label_message)
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug)]
 pub enum LexerError {
     DidntExpect(DidntExpect),
+    InconsistentDedent(InconsistentDedent),
+    InvalidEscape(InvalidEscape),
+    UnterminatedString(UnterminatedString),
+    UnterminatedComment(UnterminatedComment),
+    InvalidSuffix(InvalidSuffix),
+    NumberOutOfRange(NumberOutOfRange),
     InternalError(InternalError),
 }
 
@@ -68,6 +292,12 @@ impl Reportable for LexerError {
     fn into_report(self, interner: &Rodeo) -> Report {
         match self {
             Self::DidntExpect(error) => error.into_report(interner),
+            Self::InconsistentDedent(error) => error.into_report(interner),
+            Self::InvalidEscape(error) => error.into_report(interner),
+            Self::UnterminatedString(error) => error.into_report(interner),
+            Self::UnterminatedComment(error) => error.into_report(interner),
+            Self::InvalidSuffix(error) => error.into_report(interner),
+            Self::NumberOutOfRange(error) => error.into_report(interner),
             Self::InternalError(error) => error.into_report(interner),
         }
     }
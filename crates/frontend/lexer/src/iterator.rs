@@ -1,21 +1,51 @@
 #[cfg(feature = "serialize")]
 use serde::Serialize;
 
+use std::collections::VecDeque;
+
 use crate::{
-    error::LexerError,
-    token::{Token, TokenKind},
-    Lexer,
+    error::{InconsistentDedent, LexerError},
+    token::{Token, TokenKind, TokenValue},
+    Layout, Lexer,
 };
+use diagnostics::positional::{LabelSpan, Span};
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug)]
 pub struct TokenIterator<'a> {
     lexer: Lexer<'a>,
+    /// The tokens, which are returned before the next one gets lexed.
+    pending: VecDeque<Token>,
+    /// The widths of the enclosing indentation levels.
+    indents: Vec<usize>,
+    /// The amount of open parentheses, brackets and braces. The lines inside
+    /// of them are joined, thus their indentation is ignored.
+    depth: usize,
+    /// Where the previous token ended, used to find the start of a new line.
+    previous: Option<usize>,
+    /// The doc comments, which are emitted right before the next token.
+    docs: Vec<Token>,
 }
 
 impl<'a> TokenIterator<'a> {
     pub fn new(lexer: Lexer<'a>) -> Self {
-        Self { lexer }
+        Self {
+            lexer,
+            pending: VecDeque::new(),
+            indents: Vec::new(),
+            depth: 0,
+            previous: None,
+            docs: Vec::new(),
+        }
+    }
+
+    pub fn layout(&self) -> Layout {
+        self.lexer.layout
+    }
+
+    /// Takes the errors, which were found while lexing the tokens so far.
+    pub fn take_errors(&mut self) -> Vec<LexerError> {
+        std::mem::take(&mut self.lexer.errors)
     }
 }
 
@@ -24,9 +54,16 @@ impl<'a> TokenIterator<'a> {
         let token_kind = match self.lexer.next_token_kind() {
             Ok(token_kind) => token_kind,
             Err(error) => match error {
-                LexerError::InternalError(_) => {
+                // Reaching the end of the file just ends the iteration.
+                LexerError::InternalError(_) => return None,
+                // A number out of range still results in its literal, thus
+                // the parser doesn't report another error for it.
+                LexerError::NumberOutOfRange(_) => {
                     self.lexer.errors.push(error);
-                    return None;
+                    match self.lexer.number {
+                        Some(TokenValue::Decimal(..)) => TokenKind::Decimal,
+                        _ => TokenKind::Int,
+                    }
                 }
                 _ => {
                     self.lexer.errors.push(error);
@@ -39,21 +76,14 @@ impl<'a> TokenIterator<'a> {
         let span = self.lexer.cursor.as_span();
 
         let value = match token_kind {
-            TokenKind::Int => {
-                let content = content.parse::<usize>().unwrap().into();
-                Some(content)
-            }
-            TokenKind::Decimal => {
-                let content = content.parse::<f64>().unwrap().into();
-                Some(content)
-            }
-            TokenKind::Id => {
+            TokenKind::Int | TokenKind::Decimal => self.lexer.number.take(),
+            TokenKind::Id | TokenKind::Self_ => {
                 let mut interner = self.lexer.interner.borrow_mut();
                 let content = interner.get_or_intern(content).into();
                 Some(content)
             }
-            TokenKind::String => {
-                let content = &content[1..content.len() - 1];
+            TokenKind::String | TokenKind::DocComment => {
+                let content = std::mem::take(&mut self.lexer.string);
                 let mut interner = self.lexer.interner.borrow_mut();
                 let content = interner.get_or_intern(content).into();
                 Some(content)
@@ -67,11 +97,115 @@ impl<'a> TokenIterator<'a> {
     }
 }
 
+impl<'a> TokenIterator<'a> {
+    /// Emits the layout tokens in front of the given one. A line that is
+    /// indented more than the previous one results in an indent, while a line
+    /// that is indented less closes every deeper level with a dedent. Two lines
+    /// of the same level are separated by a newline.
+    fn layout_token(&mut self, token: Token) {
+        // A doc comment doesn't take part in the layout, as it would otherwise
+        // end up in front of the layout tokens of the declaration it belongs to.
+        if token.kind == TokenKind::DocComment {
+            self.docs.push(token);
+            return;
+        }
+
+        let start = token.span.span.start();
+        let indentation = match self.depth {
+            0 => self.indentation(start),
+            _ => None,
+        };
+
+        if let Some(width) = indentation {
+            let current = self.indents.last().copied().unwrap_or(0);
+            if width > current {
+                self.indents.push(width);
+                self.push_layout(TokenKind::Indent, start);
+            } else if width == current && self.previous.is_some() {
+                self.push_layout(TokenKind::Newline, self.previous.unwrap_or(start));
+            } else if width < current {
+                let end = self.previous.unwrap_or(start);
+                while self.indents.last().is_some_and(|level| *level > width) {
+                    self.indents.pop();
+                    self.push_layout(TokenKind::Dedent, end);
+                }
+
+                if self.indents.last().copied().unwrap_or(0) != width {
+                    let span = LabelSpan::new(Span::single(start), self.lexer.file_id);
+                    self.lexer.errors.push(InconsistentDedent::new(span).into());
+                }
+            }
+        }
+
+        match token.kind {
+            TokenKind::Parent(true) | TokenKind::Bracket(true) | TokenKind::Brace(true) => {
+                self.depth += 1;
+            }
+            TokenKind::Parent(false) | TokenKind::Bracket(false) | TokenKind::Brace(false) => {
+                self.depth = self.depth.saturating_sub(1);
+            }
+            _ => {}
+        }
+
+        self.previous = Some(token.span.span.end());
+        self.pending.extend(self.docs.drain(..));
+        self.pending.push_back(token);
+    }
+
+    /// The width of the indentation, if the token at the given position is the
+    /// first one of its line.
+    fn indentation(&self, start: usize) -> Option<usize> {
+        let before = self
+            .lexer
+            .cursor
+            .slice(&Span::new(self.previous.unwrap_or(0), start))?;
+
+        // Only the whitespace counts, as a block comment can be in front of
+        // the token.
+        let width = |line: &str| line.chars().take_while(|char| char.is_whitespace()).count();
+        match before.rfind('\n') {
+            Some(index) => Some(width(&before[index + 1..])),
+            None if self.previous.is_none() => Some(width(before)),
+            None => None,
+        }
+    }
+
+    fn push_layout(&mut self, kind: TokenKind, position: usize) {
+        let span = LabelSpan::new(Span::single(position), self.lexer.file_id);
+        let token = Token::new(span, self.lexer.file_id, None, kind);
+        self.pending.push_back(token);
+    }
+}
+
 impl<'a> Iterator for TokenIterator<'a> {
     type Item = Token;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.next_token()
+        if let Some(token) = self.pending.pop_front() {
+            return Some(token);
+        }
+
+        if self.lexer.layout == Layout::Braces {
+            return self.next_token();
+        }
+
+        while self.pending.is_empty() {
+            match self.next_token() {
+                Some(token) => self.layout_token(token),
+                // Every block that is still open gets closed at the end of the file.
+                None => {
+                    let end = self.previous.unwrap_or(0);
+                    while self.indents.pop().is_some() {
+                        self.push_layout(TokenKind::Dedent, end);
+                    }
+
+                    self.pending.extend(self.docs.drain(..));
+                    break;
+                }
+            }
+        }
+
+        self.pending.pop_front()
     }
 }
 
@@ -2,14 +2,42 @@
 use serde::Serialize;
 
 use std::cell::RefCell;
+use std::path::Path;
 use std::rc::Rc;
 
 use lasso::Rodeo;
 
 use crate::cursor::Cursor;
-use crate::error::{EndOfFile, LexerError, Result};
-use crate::token::TokenKind;
+use crate::error::{
+    DidntExpect, EndOfFile, InvalidEscape, InvalidSuffix, LexerError, NumberOutOfRange, Result,
+    UnterminatedComment, UnterminatedString,
+};
+use crate::token::{TokenKind, TokenValue};
 use diagnostics::file::{FileID, Files};
+use diagnostics::positional::{LabelSpan, Span};
+
+/// The extension of the files, which use the indentation to delimit their blocks.
+pub const INDENTATION_EXTENSION: &str = "arki";
+
+/// How the blocks of a file are delimited.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// Blocks are surrounded by braces and statements end with a semicolon.
+    Braces,
+    /// Blocks start with a colon followed by an indented line, which results
+    /// in the additional indent, dedent and newline tokens.
+    Indentation,
+}
+
+impl Layout {
+    pub fn from_path(path: &str) -> Self {
+        match Path::new(path).extension() {
+            Some(extension) if extension == INDENTATION_EXTENSION => Self::Indentation,
+            _ => Self::Braces,
+        }
+    }
+}
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug)]
@@ -17,15 +45,32 @@ pub struct Lexer<'a> {
     pub(crate) cursor: Cursor<'a>,
     pub(crate) interner: Rc<RefCell<Rodeo>>,
     pub(crate) file_id: FileID,
+    /// The decoded content of the last string literal or doc comment.
+    pub(crate) string: String,
+    /// The value of the last number literal.
+    pub(crate) number: Option<TokenValue>,
+    /// Whether the last token was a period. A number right after it is the
+    /// index of a tuple element, thus `tuple.0.1` doesn't contain a decimal.
+    pub(crate) after_period: bool,
+    pub layout: Layout,
     pub errors: Vec<LexerError>,
 }
 
 impl<'a> Lexer<'a> {
+    /// The layout of the file is chosen by the extension of its path.
     pub fn new(files: &'a Files, file_id: FileID, interner: Rc<RefCell<Rodeo>>) -> Lexer<'a> {
+        let layout = files
+            .path(file_id)
+            .map_or(Layout::Braces, Layout::from_path);
+
         Self {
             cursor: Cursor::new(file_id, files),
             interner,
             errors: Vec::new(),
+            string: String::new(),
+            number: None,
+            after_period: false,
+            layout,
             file_id,
         }
     }
@@ -37,15 +82,19 @@ impl<'a> Lexer<'a> {
         };
 
         self.cursor.mark_start();
-        match current {
+        let token_kind = match current {
             char if char.is_alphabetic() => self.read_identifier(),
             char if char.is_numeric() => self.read_number(),
             '"' => self.read_string(),
             _ => self.read_symbol(),
-        }
+        };
+
+        self.after_period = matches!(token_kind, Ok(TokenKind::Period));
+        token_kind
     }
 
     pub fn read_symbol(&mut self) -> Result<TokenKind> {
+        let start = self.cursor.current_index();
         let mut token = match self.cursor.try_consume() {
             Some(char) if char.is_whitespace() => self.next_token_kind()?,
             Some('{') => TokenKind::Brace(true),
@@ -57,15 +106,22 @@ impl<'a> Lexer<'a> {
             Some('@') => TokenKind::At,
             Some(',') => TokenKind::Comma,
             Some('.') => TokenKind::Period,
+            Some(':') => TokenKind::Colon,
             Some('+') => TokenKind::Plus,
             Some('-') => TokenKind::Minus,
             Some('*') => TokenKind::Asterisk,
             Some('/') => TokenKind::Slash,
+            Some('%') => TokenKind::Percent,
+            Some('^') => TokenKind::Caret,
+            Some('~') => TokenKind::Tilde,
             Some('<') => TokenKind::Less,
             Some('>') => TokenKind::Greater,
             Some('=') => TokenKind::Eq,
             Some('!') => TokenKind::Apostrophe,
+            Some('&') => TokenKind::Ampersand,
+            Some('|') => TokenKind::Pipe,
             Some(';') => TokenKind::Semicolon,
+            Some('_') => TokenKind::Underscore,
             Some(char) => TokenKind::Unknown(char),
             None => return Err(EndOfFile.into()),
         };
@@ -75,15 +131,25 @@ impl<'a> Lexer<'a> {
             None => return Ok(token),
         };
 
+        if token == TokenKind::Slash && (current == '/' || current == '*') {
+            return self.read_comment(start);
+        }
+
         token = match (token, current) {
             (TokenKind::Plus, '=') => TokenKind::PlusEq,
             (TokenKind::Minus, '=') => TokenKind::MinusEq,
             (TokenKind::Asterisk, '=') => TokenKind::AsteriskEq,
             (TokenKind::Slash, '=') => TokenKind::SlashEq,
             (TokenKind::Less, '=') => TokenKind::LessEq,
+            (TokenKind::Less, '<') => TokenKind::LessLess,
             (TokenKind::Greater, '=') => TokenKind::GreaterEq,
+            (TokenKind::Greater, '>') => TokenKind::GreaterGreater,
+            (TokenKind::Colon, ':') => TokenKind::ColonColon,
             (TokenKind::Eq, '=') => TokenKind::EqEq,
+            (TokenKind::Eq, '>') => TokenKind::FatArrow,
             (TokenKind::Apostrophe, '=') => TokenKind::NotEq,
+            (TokenKind::Ampersand, '&') => TokenKind::AmpersandAmpersand,
+            (TokenKind::Pipe, '|') => TokenKind::PipePipe,
             (token, _) => return Ok(token),
         };
 
@@ -102,8 +168,16 @@ impl<'a> Lexer<'a> {
             "true" => TokenKind::True,
             "false" => TokenKind::False,
             "struct" => TokenKind::Struct,
+            "enum" => TokenKind::Enum,
             "return" => TokenKind::Return,
             "let" => TokenKind::Let,
+            "if" => TokenKind::If,
+            "else" => TokenKind::Else,
+            "while" => TokenKind::While,
+            "break" => TokenKind::Break,
+            "continue" => TokenKind::Continue,
+            "as" => TokenKind::As,
+            "match" => TokenKind::Match,
             "self" => TokenKind::Self_,
             "fun" => TokenKind::Fun,
             "u8" => TokenKind::U8,
@@ -119,31 +193,268 @@ impl<'a> Lexer<'a> {
             "f32" => TokenKind::F32,
             "f64" => TokenKind::F64,
             "bool" => TokenKind::Bool,
+            "str" => TokenKind::Str,
+            "void" => TokenKind::Void,
             _ => TokenKind::Id,
         })
     }
 
+    /// Reads an integer with an optional `0x`, `0o` or `0b` prefix or a
+    /// decimal with an optional fraction and exponent. Both can contain `_`
+    /// as a separator and end with a type suffix, such as `42u8` or `1.5f32`.
+    /// Right after a period, the number can't have a fraction.
     pub fn read_number(&mut self) -> Result<TokenKind> {
-        self.cursor.eat_if(char::is_numeric, "0-9")?;
+        let start = self.cursor.current_index();
+        let first = self.cursor.eat_if(|char| char.is_ascii_digit(), "0-9")?;
 
-        self.cursor.eat_while(char::is_numeric);
+        let (radix, digits) = match (first, self.cursor.peek()) {
+            ('0', Some('x')) => (16, "0-9a-fA-F"),
+            ('0', Some('o')) => (8, "0-7"),
+            ('0', Some('b')) => (2, "0-1"),
+            _ => (10, "0-9"),
+        };
 
-        if self.cursor.try_eat('.').is_ok() {
-            self.cursor.eat_while(char::is_numeric);
-            Ok(TokenKind::Decimal)
+        let mut decimal = false;
+        if radix != 10 {
+            self.cursor.try_consume();
+            self.cursor.eat_if(|char| char.is_digit(radix), digits)?;
+            self.eat_digits(radix, digits)?;
         } else {
-            Ok(TokenKind::Int)
+            self.eat_digits(radix, digits)?;
+
+            if !self.after_period && self.cursor.try_eat('.').is_ok() {
+                decimal = true;
+                self.eat_digits(radix, digits)?;
+            }
+
+            if let Some('e' | 'E') = self.cursor.peek() {
+                decimal = true;
+                self.cursor.try_consume();
+                let _ = self.cursor.eat_if(|char| char == '+' || char == '-', "+-");
+                self.cursor.eat_if(|char| char.is_ascii_digit(), digits)?;
+                self.eat_digits(radix, digits)?;
+            }
+        }
+
+        let end = self.cursor.current_index();
+        let suffix = self.read_suffix()?;
+        let decimal = decimal || matches!(suffix, Some(TokenKind::F32 | TokenKind::F64));
+
+        let span = LabelSpan::new(Span::new(start, self.cursor.current_index()), self.file_id);
+        let content = self
+            .cursor
+            .slice(&Span::new(start, end))
+            .unwrap_or_default()
+            .replace('_', "");
+
+        if decimal {
+            let max = match suffix {
+                Some(TokenKind::F32) => f32::MAX as f64,
+                Some(TokenKind::F64) | None => f64::MAX,
+                Some(_) => return Err(InvalidSuffix::new(span).into()),
+            };
+
+            let value = content.parse::<f64>().unwrap_or(f64::INFINITY);
+            if value > max {
+                // The literal is still kept with a value of zero, thus the
+                // number results in this error only.
+                self.number = Some(TokenValue::Decimal(0.0, suffix));
+                return Err(NumberOutOfRange::new(span, suffix).into());
+            }
+
+            self.number = Some(TokenValue::Decimal(value, suffix));
+            return Ok(TokenKind::Decimal);
         }
+
+        let digits = if radix == 10 { &content } else { &content[2..] };
+        let value = usize::from_str_radix(digits, radix).ok().filter(|value| {
+            suffix
+                .and_then(integer_max)
+                .is_none_or(|max| *value as u64 <= max)
+        });
+        let Some(value) = value else {
+            self.number = Some(TokenValue::Integer(0, suffix));
+            return Err(NumberOutOfRange::new(span, suffix).into());
+        };
+
+        self.number = Some(TokenValue::Integer(value, suffix));
+        Ok(TokenKind::Int)
     }
 
-    pub fn read_string(&mut self) -> Result<TokenKind> {
-        self.cursor.try_eat('"')?;
+    /// Eats the digits and separators of a number. A decimal digit is still
+    /// part of a binary or octal number, but results in an error.
+    fn eat_digits(&mut self, radix: u32, digits: &'static str) -> Result<()> {
+        let mut invalid = None;
+        while let Some((index, char)) = self.cursor.peek_indexed() {
+            if char != '_' && !char.is_ascii_digit() && !char.is_digit(radix) {
+                break;
+            }
+
+            self.cursor.try_consume();
+            if char != '_' && !char.is_digit(radix) {
+                invalid.get_or_insert((index, char));
+            }
+        }
 
+        match invalid {
+            Some((index, char)) => {
+                let span = LabelSpan::new(Span::single(index), self.file_id);
+                Err(DidntExpect::new(char, span, digits).into())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Reads the type suffix directly following a number.
+    fn read_suffix(&mut self) -> Result<Option<TokenKind>> {
+        if !self.cursor.peek().is_some_and(char::is_alphabetic) {
+            return Ok(None);
+        }
+
+        let start = self.cursor.current_index();
         self.cursor
-            .eat_windowed_while(|prev, curr| curr != '"' || prev == '\\');
+            .eat_while(|char| char.is_alphanumeric() || char == '_');
 
+        let span = Span::new(start, self.cursor.current_index());
+        Ok(Some(match self.cursor.slice(&span).unwrap_or_default() {
+            "u8" => TokenKind::U8,
+            "i8" => TokenKind::I8,
+            "u16" => TokenKind::U16,
+            "i16" => TokenKind::I16,
+            "u32" => TokenKind::U32,
+            "i32" => TokenKind::I32,
+            "u64" => TokenKind::U64,
+            "i64" => TokenKind::I64,
+            "usize" => TokenKind::USize,
+            "isize" => TokenKind::ISize,
+            "f32" => TokenKind::F32,
+            "f64" => TokenKind::F64,
+            _ => return Err(InvalidSuffix::new(LabelSpan::new(span, self.file_id)).into()),
+        }))
+    }
+
+    /// Skips the comment after its first slash and continues with the next
+    /// token. Block comments can be nested, while a line comment starting with
+    /// exactly three slashes is a doc comment and thus results in a token.
+    fn read_comment(&mut self, start: usize) -> Result<TokenKind> {
+        if self.cursor.try_eat('*').is_ok() {
+            let mut depth = 1;
+            while depth > 0 {
+                match self.cursor.try_consume() {
+                    Some('/') if self.cursor.try_eat('*').is_ok() => depth += 1,
+                    Some('*') if self.cursor.try_eat('/').is_ok() => depth -= 1,
+                    Some(_) => {}
+                    None => {
+                        let span = LabelSpan::new(Span::new(start, start + 2), self.file_id);
+                        return Err(UnterminatedComment::new(span).into());
+                    }
+                }
+            }
+
+            return self.next_token_kind();
+        }
+
+        self.cursor.try_eat('/')?;
+        let doc = self.cursor.try_eat('/').is_ok() && self.cursor.peek() != Some('/');
+
+        let content = self.cursor.current_index();
+        self.cursor.eat_while(|char| char != '\n');
+        if !doc {
+            return self.next_token_kind();
+        }
+
+        let span = Span::new(content, self.cursor.current_index());
+        let content = self.cursor.slice(&span).unwrap_or_default();
+        self.string = content
+            .strip_prefix(' ')
+            .unwrap_or(content)
+            .trim_end()
+            .to_string();
+
+        Ok(TokenKind::DocComment)
+    }
+
+    /// Reads the string up to its closing quote, even if one of its escape
+    /// sequences is invalid. Thus, the lexer can continue after the string.
+    pub fn read_string(&mut self) -> Result<TokenKind> {
+        let start = self.cursor.current_index();
         self.cursor.try_eat('"')?;
 
+        let mut content = String::new();
+        let mut invalid = None;
+        loop {
+            let escape = self.cursor.current_index();
+            match self.cursor.try_consume() {
+                Some('"') => break,
+                Some('\\') => match self.read_escape() {
+                    Some(char) => content.push(char),
+                    None => {
+                        let span = Span::new(escape, self.cursor.current_index());
+                        invalid.get_or_insert(LabelSpan::new(span, self.file_id));
+                    }
+                },
+                Some(char) => content.push(char),
+                None => {
+                    // Only the opening quote is labeled, as the string runs
+                    // until the end of the file.
+                    let span = Span::single(start);
+                    return Err(UnterminatedString::new(LabelSpan::new(span, self.file_id)).into());
+                }
+            }
+        }
+
+        if let Some(span) = invalid {
+            return Err(InvalidEscape::new(span).into());
+        }
+
+        self.string = content;
         Ok(TokenKind::String)
     }
+
+    /// Decodes the escape sequence after a backslash, which results in `None`
+    /// if it's invalid.
+    fn read_escape(&mut self) -> Option<char> {
+        Some(match self.cursor.try_consume()? {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            '0' => '\0',
+            '"' => '"',
+            '\\' => '\\',
+            'u' => {
+                self.cursor.try_eat('{').ok()?;
+
+                let start = self.cursor.current_index();
+                self.cursor.eat_while(|char| char.is_ascii_hexdigit());
+                let end = self.cursor.current_index();
+                let digits = self.cursor.slice(&Span::new(start, end))?;
+
+                self.cursor.try_eat('}').ok()?;
+
+                if digits.is_empty() || digits.len() > 6 {
+                    return None;
+                }
+                char::from_u32(u32::from_str_radix(digits, 16).ok()?)?
+            }
+            _ => return None,
+        })
+    }
+}
+
+/// The largest number an integer suffix allows. A signed type allows the
+/// magnitude of its minimum, so that the number can be negated. Pointer-sized
+/// integers are limited to the widest target, the type checker knows the
+/// actual width.
+fn integer_max(suffix: TokenKind) -> Option<u64> {
+    Some(match suffix {
+        TokenKind::U8 => u8::MAX as u64,
+        TokenKind::I8 => 1 << 7,
+        TokenKind::U16 => u16::MAX as u64,
+        TokenKind::I16 => 1 << 15,
+        TokenKind::U32 => u32::MAX as u64,
+        TokenKind::I32 => 1 << 31,
+        TokenKind::U64 | TokenKind::USize => u64::MAX,
+        TokenKind::I64 | TokenKind::ISize => 1 << 63,
+        _ => return None,
+    })
 }
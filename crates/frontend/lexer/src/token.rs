@@ -40,14 +40,22 @@ impl Token {
 
     pub fn get_int(&self) -> Option<usize> {
         match self.value {
-            Some(TokenValue::Integer(value)) => Some(value),
+            Some(TokenValue::Integer(value, _)) => Some(value),
             _ => None,
         }
     }
 
     pub fn get_dec(&self) -> Option<f64> {
         match self.value {
-            Some(TokenValue::Decimal(value)) => Some(value),
+            Some(TokenValue::Decimal(value, _)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The type suffix of a number, such as the `u8` of `42u8`.
+    pub fn get_suffix(&self) -> Option<TokenKind> {
+        match self.value {
+            Some(TokenValue::Integer(_, suffix)) | Some(TokenValue::Decimal(_, suffix)) => suffix,
             _ => None,
         }
     }
@@ -63,21 +71,22 @@ impl Token {
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug, PartialEq, Clone)]
 pub enum TokenValue {
-    Integer(usize),
-    Decimal(f64),
+    /// A number together with its optional type suffix.
+    Integer(usize, Option<TokenKind>),
+    Decimal(f64, Option<TokenKind>),
     String(Spur),
     Bool(bool),
 }
 
 impl From<usize> for TokenValue {
     fn from(value: usize) -> Self {
-        TokenValue::Integer(value)
+        TokenValue::Integer(value, None)
     }
 }
 
 impl From<f64> for TokenValue {
     fn from(value: f64) -> Self {
-        TokenValue::Decimal(value)
+        TokenValue::Decimal(value, None)
     }
 }
 
@@ -99,13 +108,22 @@ pub enum TokenKind {
     Decimal,
     Id,
     String,
+    DocComment,
     True,
     False,
 
     Struct,
+    Enum,
     Fun,
     Let,
     Return,
+    If,
+    Else,
+    While,
+    Break,
+    Continue,
+    As,
+    Match,
 
     Brace(bool),
     Parent(bool),
@@ -114,7 +132,16 @@ pub enum TokenKind {
     Apostrophe,
     Comma,
     Period,
+    ColonColon,
+    Colon,
     Semicolon,
+    Underscore,
+
+    /// The layout tokens, which are only emitted for files that use the
+    /// indentation instead of braces to delimit their blocks.
+    Indent,
+    Dedent,
+    Newline,
 
     PlusEq,
     Plus,
@@ -124,13 +151,23 @@ pub enum TokenKind {
     Asterisk,
     SlashEq,
     Slash,
+    Percent,
+    Caret,
+    Tilde,
+    LessLess,
     LessEq,
     Less,
+    GreaterGreater,
     GreaterEq,
     Greater,
     EqEq,
     NotEq,
+    FatArrow,
     Eq,
+    AmpersandAmpersand,
+    Ampersand,
+    PipePipe,
+    Pipe,
 
     Self_,
     U8,
@@ -146,6 +183,8 @@ pub enum TokenKind {
     F32,
     F64,
     Bool,
+    Str,
+    Void,
 
     Unknown(char),
 }
@@ -166,22 +205,38 @@ impl Display for TokenKind {
             Self::Decimal => write!(f, "decimal"),
             Self::Id => write!(f, "identifier"),
             Self::String => write!(f, "string"),
+            Self::DocComment => write!(f, "doc comment"),
             Self::True => write!(f, "true"),
             Self::False => write!(f, "false"),
 
             Self::Struct => write!(f, "struct"),
+            Self::Enum => write!(f, "enum"),
             Self::Fun => write!(f, "fun"),
             Self::Let => write!(f, "let"),
             Self::Return => write!(f, "return"),
+            Self::If => write!(f, "if"),
+            Self::Else => write!(f, "else"),
+            Self::While => write!(f, "while"),
+            Self::Break => write!(f, "break"),
+            Self::Continue => write!(f, "continue"),
+            Self::As => write!(f, "as"),
+            Self::Match => write!(f, "match"),
 
-            Self::Bracket(opening) => write!(f, "{}", if *opening { "[" } else { "}" }),
+            Self::Bracket(opening) => write!(f, "{}", if *opening { "[" } else { "]" }),
             Self::Parent(opening) => write!(f, "{}", if *opening { "(" } else { ")" }),
             Self::Brace(opening) => write!(f, "{}", if *opening { "{" } else { "}" }),
             Self::At => write!(f, "@"),
             Self::Apostrophe => write!(f, "!"),
             Self::Comma => write!(f, ","),
             Self::Period => write!(f, "."),
+            Self::ColonColon => write!(f, "::"),
+            Self::Colon => write!(f, ":"),
             Self::Semicolon => write!(f, ";"),
+            Self::Underscore => write!(f, "_"),
+
+            Self::Indent => write!(f, "indent"),
+            Self::Dedent => write!(f, "dedent"),
+            Self::Newline => write!(f, "newline"),
 
             Self::PlusEq => write!(f, "+="),
             Self::Plus => write!(f, "+"),
@@ -191,13 +246,23 @@ impl Display for TokenKind {
             Self::Asterisk => write!(f, "*"),
             Self::SlashEq => write!(f, "/="),
             Self::Slash => write!(f, "/"),
+            Self::Percent => write!(f, "%"),
+            Self::Caret => write!(f, "^"),
+            Self::Tilde => write!(f, "~"),
+            Self::LessLess => write!(f, "<<"),
             Self::LessEq => write!(f, "<="),
             Self::Less => write!(f, "<"),
+            Self::GreaterGreater => write!(f, ">>"),
             Self::GreaterEq => write!(f, ">="),
             Self::Greater => write!(f, ">"),
             Self::EqEq => write!(f, "=="),
             Self::NotEq => write!(f, "!="),
+            Self::FatArrow => write!(f, "=>"),
             Self::Eq => write!(f, "="),
+            Self::AmpersandAmpersand => write!(f, "&&"),
+            Self::Ampersand => write!(f, "&"),
+            Self::PipePipe => write!(f, "||"),
+            Self::Pipe => write!(f, "|"),
 
             Self::Self_ => write!(f, "self"),
             Self::U8 => write!(f, "u8"),
@@ -210,9 +275,11 @@ impl Display for TokenKind {
             Self::I64 => write!(f, "i64"),
             Self::USize => write!(f, "usize"),
             Self::ISize => write!(f, "isize"),
-            Self::F32 => write!(f, "f323"),
+            Self::F32 => write!(f, "f32"),
             Self::F64 => write!(f, "f64"),
             Self::Bool => write!(f, "bool"),
+            Self::Str => write!(f, "str"),
+            Self::Void => write!(f, "void"),
 
             Self::Unknown(char) => write!(f, "{}", char),
         }
@@ -39,22 +39,86 @@ macro_rules! test_token {
 test_token!(success_decimal, "4.2" => TokenKind::Decimal);
 test_token!(success_integer, "42" => TokenKind::Int);
 test_token!(FAIL: fail_number, read_number, "number");
+test_token!(success_hexadecimal, "0xFF_ff" => TokenKind::Int);
+test_token!(success_octal, "0o17" => TokenKind::Int);
+test_token!(success_binary, "0b1010_1010" => TokenKind::Int);
+test_token!(success_separator, "1_000_000" => TokenKind::Int);
+test_token!(success_exponent, "1e-3" => TokenKind::Decimal);
+test_token!(success_fraction_exponent, "2.5E+10" => TokenKind::Decimal);
+test_token!(success_integer_suffix, "42u8" => TokenKind::Int);
+test_token!(success_decimal_suffix, "1.5f32" => TokenKind::Decimal);
+test_token!(success_integer_decimal_suffix, "1f64" => TokenKind::Decimal);
+test_token!(success_size_suffix, "42usize" => TokenKind::Int);
+test_token!(FAIL: fail_binary_digit, read_number, "0b102");
+test_token!(FAIL: fail_hexadecimal_empty, read_number, "0x");
+test_token!(FAIL: fail_exponent, read_number, "1e");
+test_token!(FAIL: fail_suffix, read_number, "42u7");
+test_token!(FAIL: fail_decimal_suffix, read_number, "1.5u8");
+test_token!(FAIL: fail_size_decimal_suffix, read_number, "1.5isize");
+test_token!(FAIL: fail_out_of_range, read_number, "18446744073709551616");
+test_token!(FAIL: fail_suffix_out_of_range, read_number, "256u8");
+test_token!(FAIL: fail_signed_out_of_range, read_number, "129i8");
+test_token!(FAIL: fail_decimal_out_of_range, read_number, "1e39f32");
+
+#[test]
+fn fail_out_of_range_keeps_literal() {
+    let mut files = Files::default();
+    let file_id = files.add("test.ark", "256u8 + 1e39f32");
+
+    let interner = Rc::new(RefCell::new(Rodeo::default()));
+
+    let mut iterator = Lexer::new(&files, file_id, interner).into_iter();
+    let tokens = iterator
+        .by_ref()
+        .map(|token| token.kind)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tokens,
+        vec![TokenKind::Int, TokenKind::Plus, TokenKind::Decimal]
+    );
+    assert_eq!(iterator.take_errors().len(), 2);
+}
 
 test_token!(success_string, "\"Hello World!\"" => TokenKind::String);
 test_token!(FAIL: fail_string, read_string, "Hello World!");
+test_token!(success_string_escape, r#""\"\n\t\r\0\\\u{1F600}""# => TokenKind::String);
+test_token!(FAIL: fail_string_escape, read_string, r#""\q""#);
+test_token!(FAIL: fail_string_unicode, read_string, r#""\u{110000}""#);
+test_token!(FAIL: fail_string_unterminated, read_string, r#""Hello"#);
+
+test_token!(success_line_comment, "// Hello\n42" => TokenKind::Int);
+test_token!(success_block_comment, "/* Hello /* World */ ! */ 42" => TokenKind::Int);
+test_token!(success_doc_comment, "/// Hello World!" => TokenKind::DocComment);
+test_token!(success_comment_slashes, "//// Hello\n42" => TokenKind::Int);
+test_token!(FAIL: fail_block_comment, next_token_kind, "/* Hello /* World */");
 
 test_token!(success_true, "true" => TokenKind::True);
 test_token!(success_false, "false" => TokenKind::False);
 
+test_token!(success_if, "if" => TokenKind::If);
+test_token!(success_else, "else" => TokenKind::Else);
+test_token!(success_while, "while" => TokenKind::While);
+test_token!(success_break, "break" => TokenKind::Break);
+test_token!(success_continue, "continue" => TokenKind::Continue);
+test_token!(success_as, "as" => TokenKind::As);
+test_token!(success_len_identifier, "len" => TokenKind::Id);
+test_token!(success_enum, "enum" => TokenKind::Enum);
+test_token!(success_match, "match" => TokenKind::Match);
+
 test_token!(success_obracket, "{" => TokenKind::Brace(true));
 test_token!(success_cbracket, "}" => TokenKind::Brace(false));
 test_token!(success_oparent, "(" => TokenKind::Parent(true));
 test_token!(success_cparent, ")" => TokenKind::Parent(false));
+test_token!(success_osquare, "[" => TokenKind::Bracket(true));
+test_token!(success_csquare, "]" => TokenKind::Bracket(false));
 test_token!(success_at, "@" => TokenKind::At);
 test_token!(success_apostrophe, "!" => TokenKind::Apostrophe);
 test_token!(success_comma, "," => TokenKind::Comma);
 test_token!(success_period, "." => TokenKind::Period);
+test_token!(success_colon, ":" => TokenKind::Colon);
+test_token!(success_coloncolon, "::" => TokenKind::ColonColon);
 test_token!(success_semicolon, ";" => TokenKind::Semicolon);
+test_token!(success_underscore, "_" => TokenKind::Underscore);
 test_token!(success_addassign, "+=" => TokenKind::PlusEq);
 test_token!(success_plus, "+" => TokenKind::Plus);
 test_token!(success_minusassing, "-=" => TokenKind::MinusEq);
@@ -69,7 +133,17 @@ test_token!(success_greaterequal, ">=" => TokenKind::GreaterEq);
 test_token!(success_greater, ">" => TokenKind::Greater);
 test_token!(success_equal, "==" => TokenKind::EqEq);
 test_token!(success_notequal, "!=" => TokenKind::NotEq);
+test_token!(success_and, "&&" => TokenKind::AmpersandAmpersand);
+test_token!(success_or, "||" => TokenKind::PipePipe);
+test_token!(success_percent, "%" => TokenKind::Percent);
+test_token!(success_ampersand, "&" => TokenKind::Ampersand);
+test_token!(success_pipe, "|" => TokenKind::Pipe);
+test_token!(success_caret, "^" => TokenKind::Caret);
+test_token!(success_tilde, "~" => TokenKind::Tilde);
+test_token!(success_shift_left, "<<" => TokenKind::LessLess);
+test_token!(success_shift_right, ">>" => TokenKind::GreaterGreater);
 test_token!(success_assign, "=" => TokenKind::Eq);
+test_token!(success_fat_arrow, "=>" => TokenKind::FatArrow);
 
 test_token!(success_self, "self" => TokenKind::Self_);
 test_token!(success_u8, "u8" => TokenKind::U8);
@@ -85,3 +159,79 @@ test_token!(success_isize, "isize" => TokenKind::ISize);
 test_token!(success_f32, "f32" => TokenKind::F32);
 test_token!(success_f64, "f64" => TokenKind::F64);
 test_token!(success_bool, "bool" => TokenKind::Bool);
+test_token!(success_str, "str" => TokenKind::Str);
+test_token!(success_void, "void" => TokenKind::Void);
+
+macro_rules! test_layout {
+    (FAIL: $name:ident, $source:expr) => {
+        #[test]
+        fn $name() {
+            let mut files = Files::default();
+            let file_id = files.add("test.arki", $source);
+
+            let interner = Rc::new(RefCell::new(Rodeo::default()));
+
+            let mut iterator = Lexer::new(&files, file_id, interner).into_iter();
+            iterator.by_ref().for_each(drop);
+            assert!(!iterator.take_errors().is_empty(), "Input was {:?}", $source);
+        }
+    };
+    ($name:ident, $source:expr => [$($expected:expr),* $(,)?]) => {
+        #[test]
+        fn $name() {
+            let mut files = Files::default();
+            let file_id = files.add("test.arki", $source);
+
+            let interner = Rc::new(RefCell::new(Rodeo::default()));
+
+            let iterator = Lexer::new(&files, file_id, interner).into_iter();
+            let tokens = iterator.map(|token| token.kind).collect::<Vec<_>>();
+            assert_eq!(tokens, vec![$($expected),*], "Input was {:?}", $source);
+        }
+    };
+}
+
+test_layout!(success_layout_newline, "a\nb" => [
+    TokenKind::Id,
+    TokenKind::Newline,
+    TokenKind::Id,
+]);
+test_layout!(success_layout_indent, "a:\n\tb\n\tc\nd" => [
+    TokenKind::Id,
+    TokenKind::Colon,
+    TokenKind::Indent,
+    TokenKind::Id,
+    TokenKind::Newline,
+    TokenKind::Id,
+    TokenKind::Dedent,
+    TokenKind::Id,
+]);
+test_layout!(success_layout_eof, "a:\n  b:\n    c\n\n" => [
+    TokenKind::Id,
+    TokenKind::Colon,
+    TokenKind::Indent,
+    TokenKind::Id,
+    TokenKind::Colon,
+    TokenKind::Indent,
+    TokenKind::Id,
+    TokenKind::Dedent,
+    TokenKind::Dedent,
+]);
+test_layout!(success_layout_brackets, "a(\n  b,\nc)" => [
+    TokenKind::Id,
+    TokenKind::Parent(true),
+    TokenKind::Id,
+    TokenKind::Comma,
+    TokenKind::Id,
+    TokenKind::Parent(false),
+]);
+test_layout!(success_layout_comments, "a:\n  /// b\n  b // c\n/* d\n   e */\nf" => [
+    TokenKind::Id,
+    TokenKind::Colon,
+    TokenKind::Indent,
+    TokenKind::DocComment,
+    TokenKind::Id,
+    TokenKind::Dedent,
+    TokenKind::Id,
+]);
+test_layout!(FAIL: fail_layout_dedent, "a:\n    b\n  c");
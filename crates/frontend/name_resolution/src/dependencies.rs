@@ -0,0 +1,178 @@
+//! Orders the top-level statements of a program, so that a global variable
+//! is initialized before any other statement reads it. A variable read inside
+//! of a function is read by every statement, which calls the function.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use crate::error::{CyclicInitializer, ResolutionError};
+use ast::{
+    symbol::{Symbol, SymbolKind},
+    StmtKind,
+};
+use diagnostics::positional::LabelSpan;
+
+type SymbolRef = *const RefCell<Symbol>;
+
+/// The part of a program, which uses a symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum User {
+    /// A top-level statement by its position inside of the program.
+    Statement(usize),
+    Function(SymbolRef),
+}
+
+#[derive(Debug, Clone)]
+struct Use {
+    symbol: Rc<RefCell<Symbol>>,
+    span: LabelSpan,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State {
+    /// The statement is waiting for the statements it depends on.
+    Active,
+    Done,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Dependencies {
+    uses: HashMap<User, Vec<Use>>,
+}
+
+impl Dependencies {
+    pub fn record(&mut self, user: User, symbol: Rc<RefCell<Symbol>>, span: LabelSpan) {
+        self.uses
+            .entry(user)
+            .or_default()
+            .push(Use { symbol, span });
+    }
+
+    /// Moves every statement behind the ones declaring the variables it
+    /// reads. Otherwise, the statements keep their order, while the
+    /// declarations of functions and types don't move at all.
+    pub fn sort(&self, statements: &mut Vec<StmtKind>) -> Result<(), Vec<ResolutionError>> {
+        let mut declarations = HashMap::new();
+        for (index, statement) in statements.iter().enumerate() {
+            let symbols = match statement {
+                StmtKind::LetDecl(node) => node.symbol.get().into_iter().collect(),
+                StmtKind::DestructureDecl(node) => node
+                    .pattern
+                    .bindings()
+                    .into_iter()
+                    .filter_map(|binding| binding.symbol.get())
+                    .collect(),
+                _ => Vec::new(),
+            };
+
+            for symbol in symbols {
+                declarations.insert(Rc::as_ptr(symbol), index);
+            }
+        }
+
+        let mut sorter = Sorter {
+            dependencies: self,
+            declarations,
+            states: HashMap::new(),
+            order: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        let slots = statements
+            .iter()
+            .enumerate()
+            .filter(|(_, statement)| !is_declaration(statement))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        for index in slots.iter() {
+            sorter.visit(*index);
+        }
+
+        if !sorter.errors.is_empty() {
+            return Err(sorter.errors);
+        }
+
+        let mut positions = (0..statements.len()).collect::<Vec<_>>();
+        for (slot, index) in slots.into_iter().zip(sorter.order) {
+            positions[slot] = index;
+        }
+
+        let mut taken = statements.drain(..).map(Some).collect::<Vec<_>>();
+        statements.extend(
+            positions
+                .into_iter()
+                .filter_map(|position| taken[position].take()),
+        );
+
+        Ok(())
+    }
+}
+
+struct Sorter<'a> {
+    dependencies: &'a Dependencies,
+    /// The statements declaring the global variables.
+    declarations: HashMap<SymbolRef, usize>,
+    states: HashMap<usize, State>,
+    order: Vec<usize>,
+    errors: Vec<ResolutionError>,
+}
+
+impl<'a> Sorter<'a> {
+    /// Puts the statements, which the given one depends on, in front of it.
+    fn visit(&mut self, index: usize) {
+        if self.states.contains_key(&index) {
+            return;
+        }
+
+        self.states.insert(index, State::Active);
+
+        for (dependency, use_) in self.reads(index) {
+            match self.states.get(&dependency) {
+                Some(State::Active) => {
+                    let symbol = use_.symbol.borrow();
+                    let error = CyclicInitializer::new(symbol.name, symbol.span, use_.span);
+                    self.errors.push(error.into());
+                }
+                Some(State::Done) => {}
+                None => self.visit(dependency),
+            }
+        }
+
+        self.states.insert(index, State::Done);
+        self.order.push(index);
+    }
+
+    /// The statements declaring the global variables, which the statement
+    /// reads directly or through the functions it calls.
+    fn reads(&self, index: usize) -> Vec<(usize, &'a Use)> {
+        let mut reads = Vec::new();
+        let mut users = vec![User::Statement(index)];
+        let mut called = HashSet::new();
+
+        while let Some(user) = users.pop() {
+            for use_ in self.dependencies.uses.get(&user).into_iter().flatten() {
+                let symbol = Rc::as_ptr(&use_.symbol);
+                if let Some(declaration) = self.declarations.get(&symbol) {
+                    reads.push((*declaration, use_));
+                } else if let SymbolKind::Function(_) = use_.symbol.borrow().kind {
+                    if called.insert(symbol) {
+                        users.push(User::Function(symbol));
+                    }
+                }
+            }
+        }
+
+        reads
+    }
+}
+
+/// Whether the statement only declares something, thus doesn't run.
+pub fn is_declaration(statement: &StmtKind) -> bool {
+    matches!(
+        statement,
+        StmtKind::FunDecl(_) | StmtKind::StructDecl(_) | StmtKind::EnumDecl(_)
+    )
+}
@@ -97,7 +97,353 @@ impl Reportable for NameAlreadyUsed {
             .label(
                 LabelBuilder::default()
                     .message("Second occurance")
-                    .span(self.original)
+                    .span(self.other)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct OutsideOfLoop {
+    keyword: String,
+    span: LabelSpan,
+}
+
+impl OutsideOfLoop {
+    pub fn new(keyword: impl Into<String>, span: LabelSpan) -> Self {
+        Self {
+            keyword: keyword.into(),
+            span,
+        }
+    }
+}
+
+impl From<OutsideOfLoop> for ResolutionError {
+    fn from(value: OutsideOfLoop) -> Self {
+        Self::OutsideOfLoop(value)
+    }
+}
+
+impl Reportable for OutsideOfLoop {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        let message = format!("'{}' can only be used inside of a loop.", self.keyword);
+
+        ReportBuilder::default()
+            .message(message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct BuiltinArity {
+    builtin: String,
+    expected: usize,
+    got: usize,
+    span: LabelSpan,
+}
+
+impl BuiltinArity {
+    pub fn new(builtin: impl Into<String>, expected: usize, got: usize, span: LabelSpan) -> Self {
+        Self {
+            builtin: builtin.into(),
+            expected,
+            got,
+            span,
+        }
+    }
+}
+
+impl From<BuiltinArity> for ResolutionError {
+    fn from(value: BuiltinArity) -> Self {
+        Self::BuiltinArity(value)
+    }
+}
+
+impl Reportable for BuiltinArity {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        let message = format!(
+            "The builtin '{}' expected '{}' arguments but instead got '{}' arguments.",
+            self.builtin, self.expected, self.got
+        );
+
+        ReportBuilder::default()
+            .message(message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct InvalidAssignTarget {
+    span: LabelSpan,
+}
+
+impl InvalidAssignTarget {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<InvalidAssignTarget> for ResolutionError {
+    fn from(value: InvalidAssignTarget) -> Self {
+        Self::InvalidAssignTarget(value)
+    }
+}
+
+impl Reportable for InvalidAssignTarget {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("Can't assign a value to this expression.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message("Only variables and parameters can be assigned to")
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct UnknownField {
+    struct_: Spur,
+    field: Spur,
+    span: LabelSpan,
+}
+
+impl UnknownField {
+    pub fn new(struct_: Spur, field: Spur, span: LabelSpan) -> Self {
+        Self {
+            struct_,
+            field,
+            span,
+        }
+    }
+}
+
+impl From<UnknownField> for ResolutionError {
+    fn from(value: UnknownField) -> Self {
+        Self::UnknownField(value)
+    }
+}
+
+impl Reportable for UnknownField {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let message = format!(
+            "The struct '{}' has no field named '{}'.",
+            interner.resolve(&self.struct_),
+            interner.resolve(&self.field)
+        );
+
+        ReportBuilder::default()
+            .message(message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct UnknownVariant {
+    enum_: Spur,
+    variant: Spur,
+    span: LabelSpan,
+}
+
+impl UnknownVariant {
+    pub fn new(enum_: Spur, variant: Spur, span: LabelSpan) -> Self {
+        Self {
+            enum_,
+            variant,
+            span,
+        }
+    }
+}
+
+impl From<UnknownVariant> for ResolutionError {
+    fn from(value: UnknownVariant) -> Self {
+        Self::UnknownVariant(value)
+    }
+}
+
+impl Reportable for UnknownVariant {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let message = format!(
+            "The enum '{}' has no variant named '{}'.",
+            interner.resolve(&self.enum_),
+            interner.resolve(&self.variant)
+        );
+
+        ReportBuilder::default()
+            .message(message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct MissingFields {
+    fields: Vec<Spur>,
+    span: LabelSpan,
+}
+
+impl MissingFields {
+    pub fn new(fields: Vec<Spur>, span: LabelSpan) -> Self {
+        Self { fields, span }
+    }
+}
+
+impl From<MissingFields> for ResolutionError {
+    fn from(value: MissingFields) -> Self {
+        Self::MissingFields(value)
+    }
+}
+
+impl Reportable for MissingFields {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| format!("'{}'", interner.resolve(field)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let message = format!("The struct literal is missing the fields {}.", fields);
+
+        ReportBuilder::default()
+            .message(message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct ImmutableAssign {
+    name: Spur,
+    declaration: LabelSpan,
+    span: LabelSpan,
+}
+
+impl ImmutableAssign {
+    pub fn new(name: Spur, declaration: LabelSpan, span: LabelSpan) -> Self {
+        Self {
+            name,
+            declaration,
+            span,
+        }
+    }
+}
+
+impl From<ImmutableAssign> for ResolutionError {
+    fn from(value: ImmutableAssign) -> Self {
+        Self::ImmutableAssign(value)
+    }
+}
+
+impl Reportable for ImmutableAssign {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let message = format!(
+            "Can't assign to '{}', as it isn't mutable.",
+            interner.resolve(&self.name)
+        );
+
+        ReportBuilder::default()
+            .message(message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message("The value is assigned here")
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .label(
+                LabelBuilder::default()
+                    .message("Declared without a '!' here")
+                    .span(self.declaration)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct CyclicInitializer {
+    name: Spur,
+    declaration: LabelSpan,
+    span: LabelSpan,
+}
+
+impl CyclicInitializer {
+    pub fn new(name: Spur, declaration: LabelSpan, span: LabelSpan) -> Self {
+        Self {
+            name,
+            declaration,
+            span,
+        }
+    }
+}
+
+impl From<CyclicInitializer> for ResolutionError {
+    fn from(value: CyclicInitializer) -> Self {
+        Self::CyclicInitializer(value)
+    }
+}
+
+impl Reportable for CyclicInitializer {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let message = format!(
+            "The initial value of '{}' depends on itself.",
+            interner.resolve(&self.name)
+        );
+
+        ReportBuilder::default()
+            .message(message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message("The value is read here, before it's initialized")
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .label(
+                LabelBuilder::default()
+                    .message("Declared here")
+                    .span(self.declaration)
                     .build()
                     .unwrap(),
             )
@@ -111,6 +457,14 @@ impl Reportable for NameAlreadyUsed {
 pub enum ResolutionError {
     InvalidSymbolKind(InvalidSymbolKind),
     NameAlreadyUsed(NameAlreadyUsed),
+    OutsideOfLoop(OutsideOfLoop),
+    BuiltinArity(BuiltinArity),
+    InvalidAssignTarget(InvalidAssignTarget),
+    UnknownField(UnknownField),
+    UnknownVariant(UnknownVariant),
+    MissingFields(MissingFields),
+    ImmutableAssign(ImmutableAssign),
+    CyclicInitializer(CyclicInitializer),
     InternalError(InternalError),
 }
 
@@ -119,6 +473,14 @@ impl Reportable for ResolutionError {
         match self {
             Self::InvalidSymbolKind(error) => error.into_report(interner),
             Self::NameAlreadyUsed(error) => error.into_report(interner),
+            Self::OutsideOfLoop(error) => error.into_report(interner),
+            Self::BuiltinArity(error) => error.into_report(interner),
+            Self::InvalidAssignTarget(error) => error.into_report(interner),
+            Self::UnknownField(error) => error.into_report(interner),
+            Self::UnknownVariant(error) => error.into_report(interner),
+            Self::MissingFields(error) => error.into_report(interner),
+            Self::ImmutableAssign(error) => error.into_report(interner),
+            Self::CyclicInitializer(error) => error.into_report(interner),
             Self::InternalError(error) => error.into_report(interner),
         }
     }
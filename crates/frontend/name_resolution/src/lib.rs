@@ -1,3 +1,4 @@
+pub mod dependencies;
 pub mod error;
 pub mod name_resolution;
 pub mod table;
@@ -1,23 +1,44 @@
 #[cfg(feature = "serialize")]
 use serde::Serialize;
 
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use crate::{
-    error::{InvalidSymbolKind, ResolutionError, Result},
+    dependencies::{self, Dependencies, User},
+    error::{
+        BuiltinArity, ImmutableAssign, InvalidAssignTarget, InvalidSymbolKind, MissingFields,
+        NameAlreadyUsed, OutsideOfLoop, ResolutionError, Result, UnknownField, UnknownVariant,
+    },
     table::SymbolTable,
 };
 use ast::{
     symbol::{Symbol, SymbolKind},
     traversal::{Visitable, Visitor, Walkable},
-    Binary, Block, Call, FunDecl, Id, LetDecl, Parameter, Program, Return, Unary,
+    ArrayLiteral, Assign, Binary, Block, Break, Call, Cast, Continue, DestructureDecl, EnumDecl,
+    ExprKind, ExprStmt, FieldAccess, FieldInit, FunDecl, Id, If, Index, Len, LetDecl, Match,
+    MatchArm, Parameter, Pattern, Program, Return, StmtKind, StructDecl, StructLiteral,
+    TupleLiteral, Type, TypeKind, Unary, VariantLiteral, While,
 };
 use diagnostics::positional::LabelSpan;
+use lasso::{Rodeo, Spur};
+use lexer::token::Token;
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
-#[derive(Debug, Default)]
+#[derive(Debug, Clone)]
 pub struct NameResolution {
     table: SymbolTable,
+    /// The name of the `len` builtin. Calls to it become a [`Len`], as long
+    /// as no declared symbol shadows the name.
+    len: Spur,
+    /// The amount of loops around the current statement.
+    loop_depth: usize,
+    /// The top-level statement or function, which is currently resolved.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    user: Option<User>,
+    /// The symbols used by the statements and functions of the program,
+    /// which decide the order its global variables get initialized in.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    dependencies: Dependencies,
     pub errors: Vec<ResolutionError>,
 }
 
@@ -30,48 +51,92 @@ impl Visitor for NameResolution {
     }
 
     fn visit_program(&mut self, node: &mut Program) -> Result {
-        node.statements
-            .iter_mut()
-            .for_each(|statement| match statement.accept(self) {
-                Ok(_) => {}
-                Err(error) => self.errors.push(error),
-            });
+        // Every top-level declaration is known before anything gets resolved,
+        // thus it can be used before it appears. The types come first, as the
+        // patterns of destructuring declarations may refer to enums.
+        let (types, others): (Vec<_>, Vec<_>) = node.statements.iter_mut().partition(|statement| {
+            matches!(statement, StmtKind::StructDecl(_) | StmtKind::EnumDecl(_))
+        });
+        for statement in types.into_iter().chain(others) {
+            if let Err(error) = self.declare(statement) {
+                self.errors.push(error);
+            }
+        }
+
+        for (index, statement) in node.statements.iter_mut().enumerate() {
+            self.user = match dependencies::is_declaration(statement) {
+                true => None,
+                false => Some(User::Statement(index)),
+            };
+
+            if let Err(error) = statement.accept(self) {
+                self.errors.push(error);
+            }
+        }
+        self.user = None;
+
+        let dependencies = std::mem::take(&mut self.dependencies);
+        if let Err(errors) = dependencies.sort(&mut node.statements) {
+            self.errors.extend(errors);
+        }
 
         Self::default_result()
     }
 
-    fn visit_let_decl(&mut self, node: &mut LetDecl) -> Result {
-        let should_shadow = !self.table.is_global();
+    fn visit_expr_stmt(&mut self, node: &mut ExprStmt) -> Result {
+        let symbol = node.expression.accept(self)?;
+        self.is_potential_variable_symbol(symbol, node.expression.span())?;
 
-        let id = node.id.get_spur().unwrap();
-        let id_span = node.id.span;
+        Self::default_result()
+    }
 
-        let kind = if should_shadow {
-            SymbolKind::GlobalVar
-        } else {
-            SymbolKind::LocalVar
-        };
+    fn visit_expr(&mut self, node: &mut ExprKind) -> Result {
+        if let Some(len) = self.builtin_len(node)? {
+            *node = len.into();
+        }
+
+        node.walk(self)
+    }
 
+    fn visit_let_decl(&mut self, node: &mut LetDecl) -> Result {
         let result = node.walk(self);
 
-        let symbol = Symbol::new(id, id_span, kind);
-        let symbol = self.table.insert(id, id_span, symbol, should_shadow)?;
+        // Global variables got declared together with the whole program.
+        if self.table.is_global() {
+            return result;
+        }
+
+        let id = node.id.get_spur().unwrap();
+        let id_span = node.id.span;
+
+        let symbol = Symbol::new(id, id_span, SymbolKind::LocalVar, node.mutable);
+        let symbol = self.table.insert(id, id_span, symbol, true)?;
         node.symbol.set(symbol).ok();
 
         result
     }
 
-    fn visit_fun_decl(&mut self, node: &mut Rc<RefCell<FunDecl>>) -> Result {
-        let global = self.table.global_scope();
+    fn visit_destructure_decl(&mut self, node: &mut DestructureDecl) -> Result {
+        if let Some(ref mut type_) = node.type_ {
+            type_.accept(self)?;
+        }
+
+        let symbol = node.expression.accept(self)?;
+        self.is_potential_variable_symbol(symbol, node.expression.span())?;
+
+        if self.table.is_global() {
+            return Self::default_result();
+        }
 
-        let function = SymbolKind::Function(node.clone());
+        self.declare_pattern(&mut node.pattern, true)?;
 
-        let id = node.borrow().id.get_spur().unwrap();
-        let id_span = node.borrow().id.span;
+        Self::default_result()
+    }
 
-        let symbol = Symbol::new(id, id_span, function);
-        let symbol = global.insert(id, id_span, symbol, false)?;
-        node.borrow_mut().symbol.set(symbol).ok();
+    fn visit_fun_decl(&mut self, node: &mut Rc<RefCell<FunDecl>>) -> Result {
+        // The variables read by the function are read by its callers too.
+        let symbol = node.borrow().symbol.get().map(Rc::as_ptr);
+        let user = std::mem::replace(&mut self.user, symbol.map(User::Function));
 
         self.table.enter();
 
@@ -83,11 +148,14 @@ impl Visitor for NameResolution {
                 Err(error) => self.errors.push(error),
             });
 
-        node.borrow_mut().type_.accept(self)?;
-
-        node.borrow_mut().block.accept(self)?;
+        let mut result = node.borrow_mut().type_.accept(self);
+        if result.is_ok() {
+            result = node.borrow_mut().block.accept(self);
+        }
 
         self.table.exit();
+        self.user = user;
+        result?;
 
         Self::default_result()
     }
@@ -96,7 +164,7 @@ impl Visitor for NameResolution {
         let id = node.id.get_spur().unwrap();
         let id_span = node.id.span;
 
-        let symbol = Symbol::new(id, id_span, SymbolKind::Parameter);
+        let symbol = Symbol::new(id, id_span, SymbolKind::Parameter, node.mutable);
         let symbol = self.table.insert(id, id_span, symbol, false)?;
         node.symbol.set(symbol).ok();
 
@@ -113,7 +181,295 @@ impl Visitor for NameResolution {
                 Err(error) => self.errors.push(error),
             });
 
+        if let Some(ref mut tail) = node.tail {
+            let result = tail
+                .accept(self)
+                .and_then(|symbol| self.is_potential_variable_symbol(symbol, tail.span()));
+            if let Err(error) = result {
+                self.errors.push(error);
+            }
+        }
+
+        self.table.exit();
+
+        Self::default_result()
+    }
+
+    fn visit_if(&mut self, node: &mut If) -> Result {
+        let symbol = node.condition.accept(self)?;
+        self.is_potential_variable_symbol(symbol, node.condition.span())?;
+
+        node.then.accept(self)?;
+
+        if let Some(ref mut else_) = node.else_ {
+            else_.accept(self)?;
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_while(&mut self, node: &mut While) -> Result {
+        let symbol = node.condition.accept(self)?;
+        self.is_potential_variable_symbol(symbol, node.condition.span())?;
+
+        self.loop_depth += 1;
+        let result = node.block.accept(self);
+        self.loop_depth -= 1;
+        result?;
+
+        Self::default_result()
+    }
+
+    fn visit_break(&mut self, node: &mut Break) -> Result {
+        if self.loop_depth == 0 {
+            return Err(OutsideOfLoop::new("break", node.span).into());
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_continue(&mut self, node: &mut Continue) -> Result {
+        if self.loop_depth == 0 {
+            return Err(OutsideOfLoop::new("continue", node.span).into());
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_struct_decl(&mut self, node: &mut Rc<RefCell<StructDecl>>) -> Result {
+        let mut names = HashMap::new();
+        for field in node.borrow_mut().fields.iter_mut() {
+            let name = field.id.get_spur().unwrap();
+            if let Some(original) = names.insert(name, field.id.span) {
+                let error = NameAlreadyUsed::new(name, original, field.id.span);
+                self.errors.push(error.into());
+            }
+
+            if let Err(error) = field.accept(self) {
+                self.errors.push(error);
+            }
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_enum_decl(&mut self, node: &mut Rc<RefCell<EnumDecl>>) -> Result {
+        let mut names = HashMap::new();
+        for variant in node.borrow_mut().variants.iter_mut() {
+            let name = variant.id.get_spur().unwrap();
+            if let Some(original) = names.insert(name, variant.id.span) {
+                let error = NameAlreadyUsed::new(name, original, variant.id.span);
+                self.errors.push(error.into());
+            }
+
+            if let Err(error) = variant.accept(self) {
+                self.errors.push(error);
+            }
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_type(&mut self, node: &mut Type) -> Result {
+        self.resolve_kind(&mut node.kind, node.span)?;
+
+        Self::default_result()
+    }
+
+    fn visit_field_access(&mut self, node: &mut FieldAccess) -> Result {
+        let symbol = node.target.accept(self)?;
+        self.is_potential_variable_symbol(symbol, node.target.span())?;
+
+        Self::default_result()
+    }
+
+    fn visit_index(&mut self, node: &mut Index) -> Result {
+        let symbol = node.target.accept(self)?;
+        self.is_potential_variable_symbol(symbol, node.target.span())?;
+
+        let symbol = node.index.accept(self)?;
+        self.is_potential_variable_symbol(symbol, node.index.span())?;
+
+        Self::default_result()
+    }
+
+    fn visit_array_literal(&mut self, node: &mut ArrayLiteral) -> Result {
+        node.elements
+            .iter_mut()
+            .for_each(|element| match element.accept(self) {
+                Ok(symbol) => {
+                    let result = self.is_potential_variable_symbol(symbol, element.span());
+                    if let Err(error) = result {
+                        self.errors.push(error);
+                    }
+                }
+                Err(error) => self.errors.push(error),
+            });
+
+        Self::default_result()
+    }
+
+    fn visit_tuple_literal(&mut self, node: &mut TupleLiteral) -> Result {
+        node.elements
+            .iter_mut()
+            .for_each(|element| match element.accept(self) {
+                Ok(symbol) => {
+                    let result = self.is_potential_variable_symbol(symbol, element.span());
+                    if let Err(error) = result {
+                        self.errors.push(error);
+                    }
+                }
+                Err(error) => self.errors.push(error),
+            });
+
+        Self::default_result()
+    }
+
+    fn visit_len(&mut self, node: &mut Len) -> Result {
+        let symbol = node.expression.accept(self)?;
+        self.is_potential_variable_symbol(symbol, node.expression.span())?;
+
+        Self::default_result()
+    }
+
+    fn visit_struct_literal(&mut self, node: &mut StructLiteral) -> Result {
+        let id = node.id.get_spur().unwrap();
+        let id_span = node.id.span;
+
+        let symbol = self.table.lookup(id, id_span)?;
+        let kind = symbol.borrow().kind.clone();
+        let struct_decl = match kind {
+            SymbolKind::Struct(struct_decl) => struct_decl,
+            kind => return Err(InvalidSymbolKind::new(kind, "struct", id_span).into()),
+        };
+        node.symbol.set(symbol).ok();
+
+        let struct_decl = struct_decl.borrow();
+        let mut names = HashMap::new();
+        for field in node.fields.iter_mut() {
+            let name = field.id.get_spur().unwrap();
+            if let Some(original) = names.insert(name, field.id.span) {
+                let error = NameAlreadyUsed::new(name, original, field.id.span);
+                self.errors.push(error.into());
+            }
+
+            match struct_decl.field_index(name) {
+                Some(index) => {
+                    field.index.set(index).ok();
+                }
+                None => {
+                    let error = UnknownField::new(id, name, field.id.span);
+                    self.errors.push(error.into());
+                }
+            }
+
+            if let Err(error) = field.accept(self) {
+                self.errors.push(error);
+            }
+        }
+
+        let missing = struct_decl
+            .fields
+            .iter()
+            .filter_map(|field| field.id.get_spur())
+            .filter(|name| !names.contains_key(name))
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(MissingFields::new(missing, node.span).into());
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_variant_literal(&mut self, node: &mut VariantLiteral) -> Result {
+        let symbol = self.lookup_enum(&node.id)?;
+        let index = self.variant_index(&symbol, &node.variant);
+        node.symbol.set(symbol).ok();
+
+        match index {
+            Ok(index) => {
+                node.index.set(index).ok();
+            }
+            Err(error) => self.errors.push(error),
+        }
+
+        node.arguments
+            .iter_mut()
+            .for_each(|argument| match argument.accept(self) {
+                Ok(symbol) => {
+                    let result = self.is_potential_variable_symbol(symbol, argument.span());
+                    if let Err(error) = result {
+                        self.errors.push(error);
+                    }
+                }
+                Err(error) => self.errors.push(error),
+            });
+
+        Self::default_result()
+    }
+
+    fn visit_match(&mut self, node: &mut Match) -> Result {
+        let symbol = node.expression.accept(self)?;
+        self.is_potential_variable_symbol(symbol, node.expression.span())?;
+
+        node.arms.iter_mut().for_each(|arm| {
+            if let Err(error) = arm.accept(self) {
+                self.errors.push(error);
+            }
+        });
+
+        Self::default_result()
+    }
+
+    fn visit_match_arm(&mut self, node: &mut MatchArm) -> Result {
+        // The bindings of the pattern are only visible inside of the arm.
+        self.table.enter();
+
+        let result = node.pattern.accept(self).and_then(|_| {
+            let symbol = node.expression.accept(self)?;
+            self.is_potential_variable_symbol(symbol, node.expression.span())
+        });
+
         self.table.exit();
+        result?;
+
+        Self::default_result()
+    }
+
+    fn visit_pattern(&mut self, node: &mut Pattern) -> Result {
+        match node {
+            Pattern::Binding(node) => {
+                let id = node.id.get_spur().unwrap();
+                let id_span = node.id.span;
+
+                let symbol = Symbol::new(id, id_span, SymbolKind::LocalVar, node.mutable);
+                let symbol = self.table.insert(id, id_span, symbol, false)?;
+                node.symbol.set(symbol).ok();
+            }
+            Pattern::Variant(node) => {
+                let symbol = self.lookup_enum(&node.id)?;
+                let index = self.variant_index(&symbol, &node.variant)?;
+                node.symbol.set(symbol).ok();
+                node.index.set(index).ok();
+
+                for pattern in node.patterns.iter_mut() {
+                    pattern.accept(self)?;
+                }
+            }
+            Pattern::Tuple(node) => {
+                for pattern in node.patterns.iter_mut() {
+                    pattern.accept(self)?;
+                }
+            }
+            Pattern::Wildcard(_) | Pattern::Literal(_) => {}
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_field_init(&mut self, node: &mut FieldInit) -> Result {
+        let symbol = node.expression.accept(self)?;
+        self.is_potential_variable_symbol(symbol, node.expression.span())?;
 
         Self::default_result()
     }
@@ -136,6 +492,33 @@ impl Visitor for NameResolution {
         Self::default_result()
     }
 
+    fn visit_assign(&mut self, node: &mut Assign) -> Result {
+        let target_span = node.target.span();
+        if place_base(&node.target).is_none() {
+            return Err(InvalidAssignTarget::new(target_span).into());
+        }
+
+        node.target.accept(self)?;
+
+        let base = place_base(&node.target).and_then(|base| base.symbol.get());
+        if let Some(base) = base {
+            let base = base.borrow();
+            if let SymbolKind::Function(_) | SymbolKind::Struct(_) | SymbolKind::Enum(_) = base.kind
+            {
+                return Err(InvalidAssignTarget::new(target_span).into());
+            }
+
+            if !base.mutable {
+                return Err(ImmutableAssign::new(base.name, base.span, target_span).into());
+            }
+        }
+
+        let value = node.value.accept(self)?;
+        self.is_potential_variable_symbol(value, node.value.span())?;
+
+        Self::default_result()
+    }
+
     fn visit_binary(&mut self, node: &mut Binary) -> Result {
         let lhs = node.lhs.accept(self)?;
         self.is_potential_variable_symbol(lhs, node.lhs.span())?;
@@ -153,6 +536,15 @@ impl Visitor for NameResolution {
         Self::default_result()
     }
 
+    fn visit_cast(&mut self, node: &mut Cast) -> Result {
+        let symbol = node.expression.accept(self)?;
+        self.is_potential_variable_symbol(symbol, node.expression.span())?;
+
+        node.type_.accept(self)?;
+
+        Self::default_result()
+    }
+
     fn visit_return(&mut self, node: &mut Return) -> Result {
         if let Some(ref mut expression) = node.expression {
             let symbol = expression.accept(self)?;
@@ -169,11 +561,169 @@ impl Visitor for NameResolution {
         let symbol = self.table.lookup(id, id_span)?;
         node.symbol.set(symbol.clone()).ok();
 
+        if let Some(user) = self.user {
+            self.dependencies.record(user, symbol.clone(), id_span);
+        }
+
         Ok(Some(symbol))
     }
 }
 
 impl NameResolution {
+    pub fn new(interner: &mut Rodeo) -> Self {
+        Self {
+            table: SymbolTable::default(),
+            len: interner.get_or_intern_static("len"),
+            loop_depth: 0,
+            user: None,
+            dependencies: Dependencies::default(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Takes the argument out of a call to the `len` builtin, which isn't
+    /// shadowed by a declared symbol, so it can be replaced by a [`Len`].
+    fn builtin_len(
+        &self,
+        node: &mut ExprKind,
+    ) -> std::result::Result<Option<Len>, ResolutionError> {
+        let ExprKind::Call(call) = node else {
+            return Ok(None);
+        };
+
+        let ExprKind::Id(ref callee) = call.callee else {
+            return Ok(None);
+        };
+
+        let id = callee.id.get_spur().unwrap();
+        if id != self.len || self.table.lookup(id, callee.id.span).is_ok() {
+            return Ok(None);
+        }
+
+        if call.arguments.len() != 1 {
+            return Err(BuiltinArity::new("len", 1, call.arguments.len(), call.span).into());
+        }
+
+        let expression = call.arguments.remove(0);
+        Ok(Some(Len::new(expression, call.span)))
+    }
+
+    /// Inserts the symbol of a top-level declaration into the global scope.
+    fn declare(&mut self, statement: &mut StmtKind) -> std::result::Result<(), ResolutionError> {
+        let (id, mutable, kind) = match statement {
+            StmtKind::FunDecl(node) => {
+                let id = node.borrow().id.clone();
+                (id, false, SymbolKind::Function(node.clone()))
+            }
+            StmtKind::StructDecl(node) => {
+                let id = node.borrow().id.clone();
+                (id, false, SymbolKind::Struct(node.clone()))
+            }
+            StmtKind::EnumDecl(node) => {
+                let id = node.borrow().id.clone();
+                (id, false, SymbolKind::Enum(node.clone()))
+            }
+            StmtKind::LetDecl(node) => (node.id.clone(), node.mutable, SymbolKind::GlobalVar),
+            StmtKind::DestructureDecl(node) => {
+                return self.declare_pattern(&mut node.pattern, false)
+            }
+            _ => return Ok(()),
+        };
+
+        let name = id.get_spur().unwrap();
+        let symbol = Symbol::new(name, id.span, kind, mutable);
+        let symbol = self
+            .table
+            .global_scope()
+            .insert(name, id.span, symbol, false)?;
+
+        match statement {
+            StmtKind::FunDecl(node) => node.borrow_mut().symbol.set(symbol).ok(),
+            StmtKind::StructDecl(node) => node.borrow_mut().symbol.set(symbol).ok(),
+            StmtKind::EnumDecl(node) => node.borrow_mut().symbol.set(symbol).ok(),
+            StmtKind::LetDecl(node) => node.symbol.set(symbol).ok(),
+            _ => None,
+        };
+
+        Ok(())
+    }
+
+    /// The bindings are collected in a scope of their own first, as they
+    /// may shadow other variables, but not each other.
+    fn declare_pattern(
+        &mut self,
+        pattern: &mut Pattern,
+        shadow: bool,
+    ) -> std::result::Result<(), ResolutionError> {
+        self.table.enter();
+        let result = pattern.accept(self);
+        let bindings = self.table.exit().expect("The scope was just entered.");
+        result?;
+
+        self.table.extend(bindings, shadow)
+    }
+
+    /// The elements of arrays, slices and tuples can be structs too.
+    fn resolve_kind(
+        &self,
+        kind: &mut TypeKind,
+        span: LabelSpan,
+    ) -> std::result::Result<(), ResolutionError> {
+        let name = match kind {
+            TypeKind::Array(element, _) | TypeKind::Slice(element) => {
+                return self.resolve_kind(element, span);
+            }
+            TypeKind::Tuple(elements) => {
+                return elements
+                    .iter_mut()
+                    .try_for_each(|element| self.resolve_kind(element, span));
+            }
+            TypeKind::Struct(name) => *name,
+            _ => return Ok(()),
+        };
+
+        let symbol = self.table.lookup(name, span)?;
+        let symbol_kind = symbol.borrow().kind.clone();
+        match symbol_kind {
+            SymbolKind::Struct(_) => Ok(()),
+            // The parser can't tell the name of an enum apart from the one of
+            // a struct, which is why it gets corrected here.
+            SymbolKind::Enum(_) => {
+                *kind = TypeKind::Enum(name);
+                Ok(())
+            }
+            symbol_kind => Err(InvalidSymbolKind::new(symbol_kind, "struct or enum", span).into()),
+        }
+    }
+
+    fn lookup_enum(&self, id: &Token) -> std::result::Result<Rc<RefCell<Symbol>>, ResolutionError> {
+        let symbol = self.table.lookup(id.get_spur().unwrap(), id.span)?;
+
+        let kind = symbol.borrow().kind.clone();
+        match kind {
+            SymbolKind::Enum(_) => Ok(symbol),
+            kind => Err(InvalidSymbolKind::new(kind, "enum", id.span).into()),
+        }
+    }
+
+    /// The position of the named variant inside of the enum, which the
+    /// symbol was declared by.
+    fn variant_index(
+        &self,
+        symbol: &Rc<RefCell<Symbol>>,
+        variant: &Token,
+    ) -> std::result::Result<usize, ResolutionError> {
+        let kind = symbol.borrow().kind.clone();
+        let enum_decl = match kind {
+            SymbolKind::Enum(enum_decl) => enum_decl,
+            kind => return Err(InvalidSymbolKind::new(kind, "enum", variant.span).into()),
+        };
+
+        let name = variant.get_spur().unwrap();
+        let index = enum_decl.borrow().variant_index(name);
+        index.ok_or(UnknownVariant::new(symbol.borrow().name, name, variant.span).into())
+    }
+
     fn is_potential_function_symbol(
         &self,
         symbol: Option<Rc<RefCell<Symbol>>>,
@@ -208,3 +758,14 @@ impl NameResolution {
         }
     }
 }
+
+/// Returns the identifier at the root of a place expression, which is
+/// something that can be assigned to, or `None` if it isn't a place at all.
+fn place_base(expression: &ExprKind) -> Option<&Id> {
+    match expression {
+        ExprKind::Id(node) => Some(node),
+        ExprKind::FieldAccess(node) => place_base(&node.target),
+        ExprKind::Index(node) => place_base(&node.target),
+        _ => None,
+    }
+}
@@ -10,7 +10,7 @@ use crate::error::{NameAlreadyUsed, ResolutionError, SymbolNotFound};
 use diagnostics::positional::LabelSpan;
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Scope {
     symbols: HashMap<Spur, Rc<RefCell<Symbol>>>,
 }
@@ -37,10 +37,27 @@ impl Scope {
     pub fn lookup(&self, name: Spur) -> Option<Rc<RefCell<Symbol>>> {
         self.symbols.get(&name).cloned()
     }
+
+    /// Moves the symbols of another scope into this one, while they keep
+    /// being the same symbols.
+    pub fn extend(&mut self, other: Scope, shadow: bool) -> Result<(), ResolutionError> {
+        for (name, symbol) in other.symbols {
+            if !shadow {
+                if let Some(original) = self.lookup(name) {
+                    let span = symbol.borrow().span;
+                    return Err(NameAlreadyUsed::new(name, original.borrow().span, span).into());
+                }
+            }
+
+            self.symbols.insert(name, symbol);
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SymbolTable {
     scopes: Vec<Scope>,
 }
@@ -84,6 +101,14 @@ impl SymbolTable {
         scope.insert(name, span, symbol, shadow)
     }
 
+    pub fn extend(&mut self, other: Scope, shadow: bool) -> Result<(), ResolutionError> {
+        let scope = self
+            .scopes
+            .last_mut()
+            .expect("There should at least be one scope (global).");
+        scope.extend(other, shadow)
+    }
+
     pub fn lookup(
         &self,
         name: Spur,
@@ -1,10 +1,9 @@
 #[cfg(feature = "serialize")]
 use serde::Serialize;
 
-use std::iter::Peekable;
-
 use crate::error::{EndOfFile, Result, Unexpected, UnexpectedEOF};
 use lexer::{
+    error::LexerError,
     iterator::TokenIterator,
     token::{Token, TokenKind},
 };
@@ -13,16 +12,41 @@ use lexer::{
 #[derive(Debug)]
 pub(crate) struct Cursor<'a> {
     #[serde(skip)]
-    iterator: Peekable<TokenIterator<'a>>,
+    iterator: TokenIterator<'a>,
+    peeked: Option<Token>,
+    previous: Option<Token>,
+    /// The doc comments in front of the peeked token.
+    peeked_docs: Vec<Token>,
+    /// The doc comments in front of the token that was consumed last.
+    docs: Vec<Token>,
 }
 
 impl<'a> Cursor<'a> {
     pub fn new(iterator: TokenIterator<'a>) -> Cursor<'a> {
         Self {
-            iterator: iterator.peekable(),
+            iterator,
+            peeked: None,
+            previous: None,
+            peeked_docs: Vec::new(),
+            docs: Vec::new(),
         }
     }
 
+    pub fn take_lexer_errors(&mut self) -> Vec<LexerError> {
+        self.iterator.take_errors()
+    }
+
+    /// The token that was consumed last.
+    pub fn previous(&self) -> Option<&Token> {
+        self.previous.as_ref()
+    }
+
+    /// Takes the doc comments in front of the token that was consumed last.
+    /// They are dropped if no declaration takes them.
+    pub fn take_docs(&mut self) -> Vec<Token> {
+        std::mem::take(&mut self.docs)
+    }
+
     // TODO: Improve this method
     pub fn synchronize_program(&mut self) {
         if let Some(token) = self.consume() {
@@ -33,8 +57,8 @@ impl<'a> Cursor<'a> {
 
         while let Ok(token) = self.peek() {
             match token.kind {
-                TokenKind::Fun | TokenKind::Struct | TokenKind::Let => return,
-                TokenKind::Semicolon | TokenKind::Bracket(false) => {
+                TokenKind::Fun | TokenKind::Struct | TokenKind::Enum | TokenKind::Let => return,
+                TokenKind::Semicolon | TokenKind::Newline | TokenKind::Bracket(false) => {
                     self.consume();
                     return;
                 }
@@ -55,8 +79,13 @@ impl<'a> Cursor<'a> {
 
         while let Ok(token) = self.peek() {
             match token.kind {
-                TokenKind::Let | TokenKind::Return => return,
-                TokenKind::Semicolon | TokenKind::Bracket(false) => {
+                TokenKind::Let
+                | TokenKind::Return
+                | TokenKind::While
+                | TokenKind::Break
+                | TokenKind::Continue
+                | TokenKind::Dedent => return,
+                TokenKind::Semicolon | TokenKind::Newline | TokenKind::Bracket(false) => {
                     self.consume();
                     return;
                 }
@@ -68,11 +97,31 @@ impl<'a> Cursor<'a> {
     }
 
     pub fn consume(&mut self) -> Option<Token> {
-        self.iterator.next()
+        let token = self.peeked.take().or_else(|| self.next_token())?;
+        self.docs = std::mem::take(&mut self.peeked_docs);
+        self.previous = Some(token.clone());
+        Some(token)
     }
 
     pub fn peek(&mut self) -> Result<&Token> {
-        self.iterator.peek().ok_or(EndOfFile.into())
+        if self.peeked.is_none() {
+            self.peeked = self.next_token();
+        }
+
+        self.peeked.as_ref().ok_or(EndOfFile.into())
+    }
+
+    /// The next token of the iterator, while the doc comments in front of it
+    /// are set aside.
+    fn next_token(&mut self) -> Option<Token> {
+        loop {
+            let token = self.iterator.next()?;
+            if token.kind != TokenKind::DocComment {
+                return Some(token);
+            }
+
+            self.peeked_docs.push(token);
+        }
     }
 
     pub fn is_peek(&mut self, expected: TokenKind) -> Option<&Token> {
@@ -102,7 +151,7 @@ impl<'a> Cursor<'a> {
         };
 
         if expected.iter().any(|kind| kind == &token.kind) {
-            return Ok(self.iterator.next().unwrap());
+            return Ok(self.consume().unwrap());
         }
 
         let expected = expected
@@ -123,7 +172,7 @@ impl<'a> Cursor<'a> {
         };
 
         if expected == token.kind {
-            return Ok(self.iterator.next().unwrap());
+            return Ok(self.consume().unwrap());
         }
 
         Err(Unexpected::new(token.kind.to_string(), token.span, expected.to_string()).into())
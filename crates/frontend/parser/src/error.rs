@@ -7,6 +7,7 @@ use diagnostics::{
     positional::LabelSpan,
     report::{LabelBuilder, Report, ReportBuilder, Reportable, Serverity},
 };
+use lexer::error::LexerError;
 
 pub(crate) type Result<T> = std::result::Result<T, ParserError>;
 
@@ -99,14 +100,22 @@ impl Reportable for UnexpectedEOF {
 pub enum ParserError {
     Unexpected(Unexpected),
     UnexpectedEOF(UnexpectedEOF),
+    Lexer(LexerError),
     InternalError(InternalError),
 }
 
+impl From<LexerError> for ParserError {
+    fn from(value: LexerError) -> Self {
+        Self::Lexer(value)
+    }
+}
+
 impl Reportable for ParserError {
     fn into_report(self, interner: &Rodeo) -> Report {
         match self {
             Self::UnexpectedEOF(error) => error.into_report(interner),
             Self::Unexpected(error) => error.into_report(interner),
+            Self::Lexer(error) => error.into_report(interner),
             Self::InternalError(error) => error.into_report(interner),
         }
     }
@@ -4,24 +4,35 @@ use serde::Serialize;
 use crate::cursor::Cursor;
 use crate::error::{InternalError, ParserError, Result, Unexpected, UnoptionalParsing};
 use ast::{
-    Binary, Block, Call, ExprKind, ExprStmt, FunDecl, Grouping, Id, LetDecl, Literal, LiteralKind,
-    Parameter, Program, Return, StmtKind, Type, Unary,
+    ArrayLiteral, Assign, AssignOperator, Binary, Binding, Block, Break, Call, Cast, Continue,
+    DestructureDecl, Else, EnumDecl, ExprKind, ExprStmt, FieldAccess, FieldDecl, FieldInit,
+    FunDecl, Grouping, Id, If, Index, LetDecl, Literal, LiteralKind, LiteralPattern, Match,
+    MatchArm, Parameter, Pattern, Program, Return, StmtKind, StructDecl, StructLiteral,
+    TupleLiteral, TuplePattern, Type, TypeKind, Unary, VariantDecl, VariantLiteral, VariantPattern,
+    While, Wildcard,
 };
 use diagnostics::positional::LabelSpan;
 use lexer::iterator::TokenIterator;
-use lexer::token::TokenKind;
+use lexer::token::{Token, TokenKind};
+use lexer::Layout;
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug)]
 pub struct Parser<'a> {
     cursor: Cursor<'a>,
+    /// Struct literals aren't allowed in conditions, as the opening brace
+    /// would be ambiguous with the one of the following block.
+    struct_literals: bool,
+    layout: Layout,
     pub errors: Vec<ParserError>,
 }
 
 impl<'a> Parser<'a> {
     pub fn new(iterator: TokenIterator<'a>) -> Parser<'a> {
         Self {
+            layout: iterator.layout(),
             cursor: Cursor::new(iterator),
+            struct_literals: true,
             errors: Vec::new(),
         }
     }
@@ -42,6 +53,8 @@ impl<'a> Parser<'a> {
             };
         }
 
+        self.take_lexer_errors();
+
         let span = match (stmts.first(), stmts.last()) {
             (Some(first), Some(last)) => first.span().combine(&last.span()),
             (_, _) => LabelSpan::default(),
@@ -50,8 +63,67 @@ impl<'a> Parser<'a> {
         Program::new(stmts, span)
     }
 
+    /// ```ebnf
+    /// repl = ( program_declaration | expression ";"? )* EOF ;
+    /// ```
+    pub fn parse_repl(&mut self) -> Program {
+        let mut stmts = Vec::new();
+        loop {
+            match self.parse_repl_stmt() {
+                Ok(stmt) => stmts.push(stmt),
+                Err(ParserError::InternalError(InternalError::EndOfFile(_))) => break,
+                Err(error) => {
+                    self.errors.push(error);
+                    self.cursor.synchronize_program();
+                }
+            };
+        }
+
+        self.take_lexer_errors();
+
+        let span = match (stmts.first(), stmts.last()) {
+            (Some(first), Some(last)) => first.span().combine(&last.span()),
+            (_, _) => LabelSpan::default(),
+        };
+
+        Program::new(stmts, span)
+    }
+
+    fn parse_repl_stmt(&mut self) -> Result<StmtKind> {
+        if let Some(result) = self.try_parse_fun_decl()? {
+            return Ok(result);
+        }
+
+        if let Some(result) = self.try_parse_struct_decl()? {
+            return Ok(result);
+        }
+
+        if let Some(result) = self.try_parse_enum_decl()? {
+            return Ok(result);
+        }
+
+        if let Some(result) = self.try_parse_let_decl()? {
+            return Ok(result);
+        }
+
+        if let Some(expr) = self.try_parse_expr()? {
+            let _ = self.cursor.eat(TokenKind::Semicolon);
+            return Ok(ExprStmt::new(expr).into());
+        }
+
+        let token = self.cursor.peek()?;
+        Err(Unexpected::new(
+            token.kind.to_string(),
+            token.span,
+            "declaration or expression",
+        )
+        .into())
+    }
+
     /// ```ebnf
     /// program_stmts = fun_declaration
+    ///                    | struct_declaration
+    ///                    | enum_declaration
     ///                    | let_declaration ;
     /// ```
     fn parse_program_stmt(&mut self) -> Result<StmtKind> {
@@ -59,12 +131,32 @@ impl<'a> Parser<'a> {
             return Ok(result);
         }
 
+        if let Some(result) = self.try_parse_struct_decl()? {
+            return Ok(result);
+        }
+
+        if let Some(result) = self.try_parse_enum_decl()? {
+            return Ok(result);
+        }
+
         if let Some(result) = self.try_parse_let_decl()? {
             return Ok(result);
         }
 
+        if self.layout == Layout::Indentation {
+            if let Ok(id) = self.cursor.eat(TokenKind::Id) {
+                let docs = self.cursor.take_docs();
+                return Ok(self.finish_parse_let_decl(docs, id.span, id)?.into());
+            }
+        }
+
         let token = self.cursor.peek()?;
-        Err(Unexpected::new(token.kind.to_string(), token.span, "fun or let declaration").into())
+        Err(Unexpected::new(
+            token.kind.to_string(),
+            token.span,
+            "fun, struct, enum or let declaration",
+        )
+        .into())
     }
 
     /// ```ebnf
@@ -85,7 +177,8 @@ impl<'a> Parser<'a> {
     }
 
     /// ```ebnf
-    /// expr_stmt = expression ";" ;
+    /// expr_stmt = expression terminator
+    ///           | if ;
     /// ```
     fn try_parse_expr_stmt(&mut self) -> Result<Option<StmtKind>> {
         let expr = match self.try_parse_expr()? {
@@ -93,32 +186,157 @@ impl<'a> Parser<'a> {
             None => return Ok(None),
         };
 
-        self.cursor.eat(TokenKind::Semicolon)?;
+        self.finish_parse_expr_stmt(expr).map(Some)
+    }
+
+    /// The errors of the lexer come first, as they are usually the cause of
+    /// the errors found while parsing.
+    fn take_lexer_errors(&mut self) {
+        let errors = self.cursor.take_lexer_errors();
+        self.errors.splice(0..0, errors.into_iter().map(Into::into));
+    }
+
+    /// ```ebnf
+    /// expr_stmt = expression terminator
+    ///           | if
+    ///           | match
+    ///           | block ;
+    /// ```
+    fn finish_parse_expr_stmt(&mut self, expr: ExprKind) -> Result<StmtKind> {
+        // An if, match or block already ends with a block, thus it doesn't
+        // need a terminator.
+        match expr {
+            ExprKind::Block(_) | ExprKind::If(_) | ExprKind::Match(_) => {
+                let _ = self.eat_terminator();
+            }
+            _ => {
+                self.eat_terminator()?;
+            }
+        }
+
+        match expr {
+            ExprKind::Block(block) => Ok(StmtKind::Block(block)),
+            expr => Ok(ExprStmt::new(expr).into()),
+        }
+    }
+
+    /// A statement ends with a semicolon. With the indentation layout, a
+    /// newline does so too, while it can be left out entirely at the end of
+    /// a block or after a statement that ended with a block.
+    ///
+    /// ```ebnf
+    /// terminator = ";" | ";"? NEWLINE ;
+    /// ```
+    fn eat_terminator(&mut self) -> Result<LabelSpan> {
+        let semicolon = self.cursor.eat(TokenKind::Semicolon);
+        if self.layout == Layout::Braces {
+            return semicolon.map(|token| token.span);
+        }
 
-        Ok(Some(ExprStmt::new(expr).into()))
+        let newline = self.cursor.eat(TokenKind::Newline);
+        match (semicolon, newline) {
+            (Ok(token), _) | (_, Ok(token)) => Ok(token.span),
+            (Err(_), Err(error)) => {
+                let previous = self.cursor.previous().cloned();
+                let ended = self.cursor.is_peek(TokenKind::Dedent).is_some()
+                    || self.cursor.peek().is_err()
+                    || previous
+                        .as_ref()
+                        .is_some_and(|token| token.kind == TokenKind::Dedent);
+
+                match (ended, previous) {
+                    (true, Some(previous)) => Ok(previous.span),
+                    _ => Err(error),
+                }
+            }
+        }
     }
 
     /// ```ebnf
-    /// block = "{" block_declaration* "}" ;
+    /// block = "{" block_declaration* expression? "}"
+    ///       | ":" INDENT block_declaration* expression? DEDENT ;
     /// ```
     fn try_parse_block(&mut self) -> Result<Option<StmtKind>> {
-        let start = match self.cursor.eat(TokenKind::Brace(true)) {
-            Ok(token) => token,
-            Err(_) => return Ok(None),
+        let start = match self.try_eat_block_start()? {
+            Some(token) => token,
+            None => return Ok(None),
         };
 
-        if let Ok(end) = self.cursor.eat(TokenKind::Brace(false)) {
+        Ok(Some(self.finish_parse_block(start)?.into()))
+    }
+
+    /// A colon followed by an indented line only starts a block with the
+    /// indentation layout.
+    ///
+    /// ```ebnf
+    /// block_start = "{" | ":" INDENT ;
+    /// ```
+    fn try_eat_block_start(&mut self) -> Result<Option<Token>> {
+        if let Ok(token) = self.cursor.eat(TokenKind::Brace(true)) {
+            return Ok(Some(token));
+        }
+
+        if self.layout == Layout::Indentation {
+            if let Ok(token) = self.cursor.eat(TokenKind::Colon) {
+                self.cursor.eat(TokenKind::Indent)?;
+                return Ok(Some(token));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// ```ebnf
+    /// block = "{" block_declaration* expression? "}"
+    ///       | ":" INDENT block_declaration* expression? DEDENT ;
+    /// ```
+    fn finish_parse_block(&mut self, start: Token) -> Result<Block> {
+        // A block inside of a condition can contain struct literals again.
+        let previous = std::mem::replace(&mut self.struct_literals, true);
+        let result = self.finish_parse_block_body(start);
+        self.struct_literals = previous;
+
+        result
+    }
+
+    fn finish_parse_block_body(&mut self, start: Token) -> Result<Block> {
+        let closing = block_end(&start);
+        if let Ok(end) = self.cursor.eat(closing) {
             let span = start.span.combine(&end.span);
-            return Ok(Some(Block::new(Vec::new(), span).into()));
+            return Ok(Block::new(Vec::new(), None, span));
         }
 
         let mut stmts = Vec::new();
+        let mut tail = None;
         loop {
-            if self.cursor.is_peek(TokenKind::Brace(false)).is_some() {
+            if self.cursor.is_peek(closing).is_some() {
                 break;
             }
 
-            match self.parse_block_decl() {
+            // An expression directly followed by the end of the block is the
+            // value of the block.
+            let result = match self.try_parse_expr() {
+                Ok(Some(expr)) if self.cursor.is_peek(closing).is_some() => {
+                    tail = Some(expr);
+                    break;
+                }
+                // The indentation layout doesn't need the `let` in front of a declaration.
+                Ok(Some(ExprKind::Id(id)))
+                    if self.layout == Layout::Indentation
+                        && (self.cursor.is_peek(TokenKind::Apostrophe).is_some()
+                            || self.cursor.is_peek(TokenKind::At).is_some()) =>
+                {
+                    let docs = self.cursor.take_docs();
+                    let id = id.id;
+                    self.finish_parse_let_decl(docs, id.span, id)
+                        .map(Into::into)
+                }
+                Ok(Some(expr)) => self.finish_parse_expr_stmt(expr),
+                Ok(None) => self.parse_block_decl(),
+                Err(error) => Err(error),
+            };
+
+            match result {
                 Ok(stmt) => stmts.push(stmt),
                 Err(ParserError::InternalError(InternalError::EndOfFile(_))) => break,
                 Err(error) => {
@@ -128,10 +346,10 @@ impl<'a> Parser<'a> {
             };
         }
 
-        let end = self.cursor.eat(TokenKind::Brace(false))?;
+        let end = self.cursor.eat(closing)?;
 
         let span = start.span.combine(&end.span);
-        Ok(Some(Block::new(stmts, span).into()))
+        Ok(Block::new(stmts, tail, span))
     }
 
     fn parse_block(&mut self) -> Result<StmtKind> {
@@ -144,6 +362,9 @@ impl<'a> Parser<'a> {
     /// ```ebnf
     /// block_declaration = let_declaration
     ///                   | return_stmt
+    ///                   | while_stmt
+    ///                   | break_stmt
+    ///                   | continue_stmt
     ///                   | stmt ;
     /// ```
     fn parse_block_decl(&mut self) -> Result<StmtKind> {
@@ -155,6 +376,18 @@ impl<'a> Parser<'a> {
             return Ok(result);
         }
 
+        if let Some(result) = self.try_parse_while_stmt()? {
+            return Ok(result);
+        }
+
+        if let Some(result) = self.try_parse_break_stmt()? {
+            return Ok(result);
+        }
+
+        if let Some(result) = self.try_parse_continue_stmt()? {
+            return Ok(result);
+        }
+
         if let Ok(result) = self.try_parse_stmt() {
             return Ok(result);
         }
@@ -164,7 +397,7 @@ impl<'a> Parser<'a> {
     }
 
     /// ```ebnf
-    /// return_stmt = return expr? ";" ;
+    /// return_stmt = return expr? terminator ;
     /// ```
     fn try_parse_return_stmt(&mut self) -> Result<Option<StmtKind>> {
         let start = match self.cursor.eat(TokenKind::Return) {
@@ -174,20 +407,71 @@ impl<'a> Parser<'a> {
 
         let expr = self.try_parse_expr()?;
 
-        let end = self.cursor.eat(TokenKind::Semicolon)?;
+        let end = self.eat_terminator()?;
 
-        let span = start.span.combine(&end.span);
+        let span = start.span.combine(&end);
         Ok(Some(Return::new(expr, span).into()))
     }
 
     /// ```ebnf
-    /// fun_declaration = "fun" IDENTIFIER "(" parameters? ")" type block ;
+    /// while_stmt = "while" expr block ;
+    /// ```
+    fn try_parse_while_stmt(&mut self) -> Result<Option<StmtKind>> {
+        let start = match self.cursor.eat(TokenKind::While) {
+            Ok(token) => token,
+            Err(_) => return Ok(None),
+        };
+
+        let condition = self.parse_condition()?;
+
+        let block = match self.parse_block()? {
+            StmtKind::Block(node) => node,
+            _ => panic!("Couldn't unbox the block. This shouldn't have happened."),
+        };
+
+        let span = start.span.combine(&block.span);
+        Ok(Some(While::new(condition, block, span).into()))
+    }
+
+    /// ```ebnf
+    /// break_stmt = "break" terminator ;
+    /// ```
+    fn try_parse_break_stmt(&mut self) -> Result<Option<StmtKind>> {
+        let start = match self.cursor.eat(TokenKind::Break) {
+            Ok(token) => token,
+            Err(_) => return Ok(None),
+        };
+
+        let end = self.eat_terminator()?;
+
+        let span = start.span.combine(&end);
+        Ok(Some(Break::new(span).into()))
+    }
+
+    /// ```ebnf
+    /// continue_stmt = "continue" terminator ;
+    /// ```
+    fn try_parse_continue_stmt(&mut self) -> Result<Option<StmtKind>> {
+        let start = match self.cursor.eat(TokenKind::Continue) {
+            Ok(token) => token,
+            Err(_) => return Ok(None),
+        };
+
+        let end = self.eat_terminator()?;
+
+        let span = start.span.combine(&end);
+        Ok(Some(Continue::new(span).into()))
+    }
+
+    /// ```ebnf
+    /// fun_declaration = DOC_COMMENT* "fun" IDENTIFIER "(" parameters? ")" type block ;
     /// ```
     fn try_parse_fun_decl(&mut self) -> Result<Option<StmtKind>> {
         let start = match self.cursor.eat(TokenKind::Fun) {
             Ok(token) => token,
             Err(_) => return Ok(None),
         };
+        let docs = self.cursor.take_docs();
 
         let id = self.cursor.eat(TokenKind::Id)?;
 
@@ -212,21 +496,129 @@ impl<'a> Parser<'a> {
         };
 
         let span = start.span.combine(&block.span);
-        Ok(Some(FunDecl::new(id, params, type_, block, span).into()))
+        Ok(Some(
+            FunDecl::new(docs, id, params, type_, block, span).into(),
+        ))
     }
 
     /// ```ebnf
-    /// parameters = IDENTIFIER type ( "," IDENTIFIER type )* ;
+    /// struct_declaration = DOC_COMMENT* "struct" IDENTIFIER "{" ( field ( "," field )* ","? )? "}"
+    ///                    | DOC_COMMENT* "struct" IDENTIFIER ":" INDENT field ( NEWLINE field )* DEDENT ;
+    /// field = IDENTIFIER type "!"? ;
+    /// ```
+    fn try_parse_struct_decl(&mut self) -> Result<Option<StmtKind>> {
+        let start = match self.cursor.eat(TokenKind::Struct) {
+            Ok(token) => token,
+            Err(_) => return Ok(None),
+        };
+        let docs = self.cursor.take_docs();
+
+        let id = self.cursor.eat(TokenKind::Id)?;
+
+        let opening = match self.try_eat_block_start()? {
+            Some(token) => token,
+            None => self.cursor.eat(TokenKind::Brace(true))?,
+        };
+        let closing = block_end(&opening);
+
+        let mut fields = Vec::new();
+        let end = loop {
+            if let Ok(end) = self.cursor.eat(closing) {
+                break end;
+            }
+
+            let id = self.cursor.eat(TokenKind::Id)?;
+            let type_ = self.parse_type()?;
+            let mutable = self.cursor.eat(TokenKind::Apostrophe).ok();
+
+            let end = mutable.as_ref().map_or(type_.span, |token| token.span);
+            let span = id.span.combine(&end);
+            fields.push(FieldDecl::new(id, type_, mutable.is_some(), span));
+
+            // The fields of the indentation layout are separated by newlines.
+            let comma = self.cursor.eat(TokenKind::Comma).is_ok();
+            let newline = self.cursor.eat(TokenKind::Newline).is_ok();
+            if !comma && !newline {
+                break self.cursor.eat(closing)?;
+            }
+        };
+
+        let span = start.span.combine(&end.span);
+        Ok(Some(StructDecl::new(docs, id, fields, span).into()))
+    }
+
+    /// ```ebnf
+    /// enum_declaration = DOC_COMMENT* "enum" IDENTIFIER "{" ( variant ( "," variant )* ","? )? "}"
+    ///                  | DOC_COMMENT* "enum" IDENTIFIER ":" INDENT variant ( NEWLINE variant )* DEDENT ;
+    /// variant = IDENTIFIER ( "(" type ( "," type )* ")" )? ;
+    /// ```
+    fn try_parse_enum_decl(&mut self) -> Result<Option<StmtKind>> {
+        let start = match self.cursor.eat(TokenKind::Enum) {
+            Ok(token) => token,
+            Err(_) => return Ok(None),
+        };
+        let docs = self.cursor.take_docs();
+
+        let id = self.cursor.eat(TokenKind::Id)?;
+
+        let opening = match self.try_eat_block_start()? {
+            Some(token) => token,
+            None => self.cursor.eat(TokenKind::Brace(true))?,
+        };
+        let closing = block_end(&opening);
+
+        let mut variants = Vec::new();
+        let end = loop {
+            if let Ok(end) = self.cursor.eat(closing) {
+                break end;
+            }
+
+            let id = self.cursor.eat(TokenKind::Id)?;
+
+            let mut types = Vec::new();
+            let mut span = id.span;
+            if self.cursor.eat(TokenKind::Parent(true)).is_ok() {
+                loop {
+                    types.push(self.parse_type()?);
+
+                    if self.cursor.eat(TokenKind::Comma).is_err() {
+                        break;
+                    }
+                }
+
+                let end = self.cursor.eat(TokenKind::Parent(false))?;
+                span = span.combine(&end.span);
+            }
+
+            variants.push(VariantDecl::new(id, types, span));
+
+            // The variants of the indentation layout are separated by newlines.
+            let comma = self.cursor.eat(TokenKind::Comma).is_ok();
+            let newline = self.cursor.eat(TokenKind::Newline).is_ok();
+            if !comma && !newline {
+                break self.cursor.eat(closing)?;
+            }
+        };
+
+        let span = start.span.combine(&end.span);
+        Ok(Some(EnumDecl::new(docs, id, variants, span).into()))
+    }
+
+    /// ```ebnf
+    /// parameters = parameter ( "," parameter )* ;
+    /// parameter = ( IDENTIFIER | "self" ) type "!"? ;
     /// ```
     fn parse_params(&mut self) -> Result<Vec<Parameter>> {
         let mut params = Vec::new();
 
         loop {
-            let id = self.cursor.eat(TokenKind::Id)?;
+            let id = self.cursor.eat_any(&[TokenKind::Id, TokenKind::Self_])?;
             let type_ = self.parse_type()?;
+            let mutable = self.cursor.eat(TokenKind::Apostrophe).ok();
 
-            let span = id.span.combine(&type_.span);
-            params.push(Parameter::new(id, type_, span));
+            let end = mutable.as_ref().map_or(type_.span, |token| token.span);
+            let span = id.span.combine(&end);
+            params.push(Parameter::new(id, type_, mutable.is_some(), span));
 
             if self.cursor.eat(TokenKind::Comma).is_err() {
                 break;
@@ -237,15 +629,70 @@ impl<'a> Parser<'a> {
     }
 
     /// ```ebnf
-    /// type = "@" ( "u8" | "i8"
-    ///      | "u16" | "i16"
-    ///      | "u32" | "i32"
-    ///      | "u64" | "i64"
-    ///      | "f32" | "f64"
-    ///      | "bool" ) ;
+    /// type = "@" type_kind ;
     /// ```
     fn parse_type(&mut self) -> Result<Type> {
         let start = self.cursor.eat(TokenKind::At)?;
+        let (kind, end) = self.parse_type_kind()?;
+
+        let span = start.span.combine(&end);
+        Ok(Type::new(kind, span))
+    }
+
+    /// ```ebnf
+    /// type_kind = "u8" | "i8"
+    ///           | "u16" | "i16"
+    ///           | "u32" | "i32"
+    ///           | "u64" | "i64"
+    ///           | "usize" | "isize"
+    ///           | "f32" | "f64"
+    ///           | "bool" | "str"
+    ///           | "void"
+    ///           | IDENTIFIER
+    ///           | "[" type_kind ( ";" NUMBER )? "]"
+    ///           | "(" type_kind ( "," type_kind )* ","? ")" ;
+    /// ```
+    fn parse_type_kind(&mut self) -> Result<(TypeKind, LabelSpan)> {
+        if let Ok(token) = self.cursor.eat(TokenKind::Id) {
+            let name = token.get_spur().expect("Identifiers always have a name.");
+            return Ok((TypeKind::Struct(name), token.span));
+        }
+
+        if self.cursor.eat(TokenKind::Bracket(true)).is_ok() {
+            let (element, _) = self.parse_type_kind()?;
+
+            // Without a length, the type is a slice of an array.
+            let kind = match self.cursor.eat(TokenKind::Semicolon) {
+                Ok(_) => {
+                    let length = self.cursor.eat(TokenKind::Int)?;
+                    let length = length.get_int().expect("Integers always have a value.");
+                    TypeKind::Array(Box::new(element), length)
+                }
+                Err(_) => TypeKind::Slice(Box::new(element)),
+            };
+
+            let end = self.cursor.eat(TokenKind::Bracket(false))?;
+            return Ok((kind, end.span));
+        }
+
+        // A single element needs a trailing comma to be a tuple, just like
+        // the tuple literal.
+        if self.cursor.eat(TokenKind::Parent(true)).is_ok() {
+            let mut elements = Vec::new();
+            let end = loop {
+                elements.push(self.parse_type_kind()?.0);
+
+                if self.cursor.eat(TokenKind::Comma).is_err() {
+                    break self.cursor.eat(TokenKind::Parent(false))?;
+                }
+
+                if let Ok(end) = self.cursor.eat(TokenKind::Parent(false)) {
+                    break end;
+                }
+            };
+
+            return Ok((TypeKind::Tuple(elements), end.span));
+        }
 
         let token = self.cursor.eat_any(&[
             TokenKind::U8,
@@ -256,51 +703,196 @@ impl<'a> Parser<'a> {
             TokenKind::I32,
             TokenKind::U64,
             TokenKind::I64,
+            TokenKind::USize,
+            TokenKind::ISize,
             TokenKind::F32,
             TokenKind::F64,
             TokenKind::Bool,
+            TokenKind::Str,
+            TokenKind::Void,
         ])?;
 
-        let span = start.span.combine(&token.span);
-        Ok(Type::new(token.kind, span))
+        Ok((token.kind.into(), token.span))
     }
 
     /// ```ebnf
-    /// let_declaration = "let" IDENTIFIER ( "=" expr )? ";" ;
+    /// let_declaration = DOC_COMMENT* "let" IDENTIFIER "!"? type? ( "=" expr )? terminator
+    ///                 | "let" tuple_pattern type? "=" expr terminator ;
     /// ```
     fn try_parse_let_decl(&mut self) -> Result<Option<StmtKind>> {
         let start = match self.cursor.eat(TokenKind::Let) {
             Ok(token) => token,
             Err(_) => return Ok(None),
         };
+        let docs = self.cursor.take_docs();
+
+        if self.cursor.is_peek(TokenKind::Parent(true)).is_some() {
+            return Ok(Some(self.finish_parse_destructure_decl(start.span)?.into()));
+        }
 
         let id = self.cursor.eat(TokenKind::Id)?;
+        Ok(Some(
+            self.finish_parse_let_decl(docs, start.span, id)?.into(),
+        ))
+    }
 
-        let type_ = self.parse_type()?;
+    /// The indentation layout also declares a variable without the `let`.
+    ///
+    /// ```ebnf
+    /// let_declaration = DOC_COMMENT* IDENTIFIER "!"? type? ( "=" expr )? terminator ;
+    /// ```
+    fn finish_parse_let_decl(
+        &mut self,
+        docs: Vec<Token>,
+        start: LabelSpan,
+        id: Token,
+    ) -> Result<LetDecl> {
+        let mutable = self.cursor.eat(TokenKind::Apostrophe).is_ok();
+
+        // Without a type, it gets inferred from the expression.
+        let type_ = match self.cursor.is_peek(TokenKind::At) {
+            Some(_) => Some(self.parse_type()?),
+            None => None,
+        };
 
         let expr = match self.cursor.eat(TokenKind::Eq) {
             Ok(_) => Some(self.parse_expr()?),
             Err(_) => None,
         };
 
-        let end = self.cursor.eat(TokenKind::Semicolon)?;
+        let end = self.eat_terminator()?;
 
-        let span = start.span.combine(&end.span);
-        Ok(Some(LetDecl::new(id, type_, expr, span).into()))
+        let span = start.combine(&end);
+        Ok(LetDecl::new(docs, id, mutable, type_, expr, span))
     }
 
     /// ```ebnf
-    /// expr = equality;
+    /// destructure_declaration = "let" tuple_pattern type? "=" expr terminator ;
+    /// ```
+    fn finish_parse_destructure_decl(&mut self, start: LabelSpan) -> Result<DestructureDecl> {
+        let pattern = self.parse_pattern()?;
+
+        let type_ = match self.cursor.is_peek(TokenKind::At) {
+            Some(_) => Some(self.parse_type()?),
+            None => None,
+        };
+
+        self.cursor.eat(TokenKind::Eq)?;
+        let expression = self.parse_expr()?;
+
+        let end = self.eat_terminator()?;
+
+        let span = start.combine(&end);
+        Ok(DestructureDecl::new(pattern, type_, expression, span))
+    }
+
+    /// ```ebnf
+    /// expr = assignment;
     /// ```
     fn try_parse_expr(&mut self) -> Result<Option<ExprKind>> {
-        self.try_parse_equality(true)
+        self.try_parse_assignment(true)
     }
 
     /// ```ebnf
-    /// expr = equality;
+    /// expr = assignment;
     /// ```
     fn parse_expr(&mut self) -> Result<ExprKind> {
-        self.parse_equality()
+        self.parse_assignment()
+    }
+
+    /// Parses an expression, while struct literals are either allowed or not.
+    fn parse_expr_with(&mut self, struct_literals: bool) -> Result<ExprKind> {
+        let previous = std::mem::replace(&mut self.struct_literals, struct_literals);
+        let result = self.parse_expr();
+        self.struct_literals = previous;
+        result
+    }
+
+    /// The condition of an `if` or `while`, which can't contain struct
+    /// literals outside of parentheses.
+    fn parse_condition(&mut self) -> Result<ExprKind> {
+        self.parse_expr_with(false)
+    }
+
+    /// ```ebnf
+    /// assignment = logical_or ( ( "=" | "+=" | "-=" | "*=" | "/=" ) assignment )? ;
+    /// ```
+    fn try_parse_assignment(&mut self, start: bool) -> Result<Option<ExprKind>> {
+        let target = match self.try_parse_logical_or(start)? {
+            Some(expr) => expr,
+            None => return Ok(None),
+        };
+
+        let operator = [
+            (TokenKind::Eq, AssignOperator::Assign),
+            (TokenKind::PlusEq, AssignOperator::Add),
+            (TokenKind::MinusEq, AssignOperator::Sub),
+            (TokenKind::AsteriskEq, AssignOperator::Mul),
+            (TokenKind::SlashEq, AssignOperator::Div),
+        ]
+        .into_iter()
+        .find_map(|(kind, operator)| self.cursor.eat(kind).ok().map(|_| operator));
+
+        let Some(operator) = operator else {
+            return Ok(Some(target));
+        };
+
+        let value = self.parse_assignment()?;
+
+        let span = target.span().combine(&value.span());
+        Ok(Some(Assign::new(target, operator, value, span).into()))
+    }
+
+    fn parse_assignment(&mut self) -> Result<ExprKind> {
+        match self.try_parse_assignment(false)? {
+            Some(expr) => Ok(expr),
+            None => Err(UnoptionalParsing.into()),
+        }
+    }
+
+    /// ```ebnf
+    /// logical_or = logical_and ( "||" logical_and )* ;
+    /// ```
+    fn try_parse_logical_or(&mut self, start: bool) -> Result<Option<ExprKind>> {
+        let mut expr = match self.try_parse_logical_and(start)? {
+            Some(expr) => expr,
+            None => return Ok(None),
+        };
+
+        while let Ok(token) = self.cursor.eat(TokenKind::PipePipe) {
+            let rhs = self.parse_logical_and()?;
+
+            let span = expr.span().combine(&rhs.span());
+            expr = Binary::new(expr, token, rhs, span).into();
+        }
+
+        Ok(Some(expr))
+    }
+
+    /// ```ebnf
+    /// logical_and = equality ( "&&" equality )* ;
+    /// ```
+    fn try_parse_logical_and(&mut self, start: bool) -> Result<Option<ExprKind>> {
+        let mut expr = match self.try_parse_equality(start)? {
+            Some(expr) => expr,
+            None => return Ok(None),
+        };
+
+        while let Ok(token) = self.cursor.eat(TokenKind::AmpersandAmpersand) {
+            let rhs = self.parse_equality()?;
+
+            let span = expr.span().combine(&rhs.span());
+            expr = Binary::new(expr, token, rhs, span).into();
+        }
+
+        Ok(Some(expr))
+    }
+
+    fn parse_logical_and(&mut self) -> Result<ExprKind> {
+        match self.try_parse_logical_and(false)? {
+            Some(expr) => Ok(expr),
+            None => Err(UnoptionalParsing.into()),
+        }
     }
 
     /// ```ebnf
@@ -330,10 +922,10 @@ impl<'a> Parser<'a> {
     }
 
     /// ```ebnf
-    /// comparison = term ( ( ">" | ">=" | "<" | "<=" ) term )* ;
+    /// comparison = bit_or ( ( ">" | ">=" | "<" | "<=" ) bit_or )* ;
     /// ```
     fn try_parse_comparison(&mut self, start: bool) -> Result<Option<ExprKind>> {
-        let mut expr = match self.try_parse_term(start)? {
+        let mut expr = match self.try_parse_bit_or(start)? {
             Some(expr) => expr,
             None => return Ok(None),
         };
@@ -344,7 +936,7 @@ impl<'a> Parser<'a> {
             TokenKind::Less,
             TokenKind::LessEq,
         ]) {
-            let rhs = self.parse_term()?;
+            let rhs = self.parse_bit_or()?;
 
             let span = expr.span().combine(&rhs.span());
             expr = Binary::new(expr, token, rhs, span).into();
@@ -360,6 +952,113 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// ```ebnf
+    /// bit_or = bit_xor ( "|" bit_xor )* ;
+    /// ```
+    fn try_parse_bit_or(&mut self, start: bool) -> Result<Option<ExprKind>> {
+        let mut expr = match self.try_parse_bit_xor(start)? {
+            Some(expr) => expr,
+            None => return Ok(None),
+        };
+
+        while let Ok(token) = self.cursor.eat(TokenKind::Pipe) {
+            let rhs = self.parse_bit_xor()?;
+
+            let span = expr.span().combine(&rhs.span());
+            expr = Binary::new(expr, token, rhs, span).into();
+        }
+
+        Ok(Some(expr))
+    }
+
+    fn parse_bit_or(&mut self) -> Result<ExprKind> {
+        match self.try_parse_bit_or(false)? {
+            Some(expr) => Ok(expr),
+            None => Err(UnoptionalParsing.into()),
+        }
+    }
+
+    /// ```ebnf
+    /// bit_xor = bit_and ( "^" bit_and )* ;
+    /// ```
+    fn try_parse_bit_xor(&mut self, start: bool) -> Result<Option<ExprKind>> {
+        let mut expr = match self.try_parse_bit_and(start)? {
+            Some(expr) => expr,
+            None => return Ok(None),
+        };
+
+        while let Ok(token) = self.cursor.eat(TokenKind::Caret) {
+            let rhs = self.parse_bit_and()?;
+
+            let span = expr.span().combine(&rhs.span());
+            expr = Binary::new(expr, token, rhs, span).into();
+        }
+
+        Ok(Some(expr))
+    }
+
+    fn parse_bit_xor(&mut self) -> Result<ExprKind> {
+        match self.try_parse_bit_xor(false)? {
+            Some(expr) => Ok(expr),
+            None => Err(UnoptionalParsing.into()),
+        }
+    }
+
+    /// ```ebnf
+    /// bit_and = shift ( "&" shift )* ;
+    /// ```
+    fn try_parse_bit_and(&mut self, start: bool) -> Result<Option<ExprKind>> {
+        let mut expr = match self.try_parse_shift(start)? {
+            Some(expr) => expr,
+            None => return Ok(None),
+        };
+
+        while let Ok(token) = self.cursor.eat(TokenKind::Ampersand) {
+            let rhs = self.parse_shift()?;
+
+            let span = expr.span().combine(&rhs.span());
+            expr = Binary::new(expr, token, rhs, span).into();
+        }
+
+        Ok(Some(expr))
+    }
+
+    fn parse_bit_and(&mut self) -> Result<ExprKind> {
+        match self.try_parse_bit_and(false)? {
+            Some(expr) => Ok(expr),
+            None => Err(UnoptionalParsing.into()),
+        }
+    }
+
+    /// ```ebnf
+    /// shift = term ( ( "<<" | ">>" ) term )* ;
+    /// ```
+    fn try_parse_shift(&mut self, start: bool) -> Result<Option<ExprKind>> {
+        let mut expr = match self.try_parse_term(start)? {
+            Some(expr) => expr,
+            None => return Ok(None),
+        };
+
+        while let Ok(token) = self
+            .cursor
+            .eat_any(&[TokenKind::LessLess, TokenKind::GreaterGreater])
+        {
+            let rhs = self.parse_term()?;
+
+            let span = expr.span().combine(&rhs.span());
+            expr = Binary::new(expr, token, rhs, span).into();
+        }
+
+        Ok(Some(expr))
+    }
+
+    fn parse_shift(&mut self) -> Result<ExprKind> {
+        match self.try_parse_shift(false)? {
+            Some(expr) => Ok(expr),
+            None => Err(UnoptionalParsing.into()),
+        }
+    }
+
     /// ```ebnf
     /// term = factor ( ( "-" | "+" ) factor )* ;
     /// ```
@@ -387,19 +1086,19 @@ impl<'a> Parser<'a> {
     }
 
     /// ```ebnf
-    /// factor = unary ( ( "/" | "*" ) unary )* ;
+    /// factor = cast ( ( "/" | "*" | "%" ) cast )* ;
     /// ```
     fn try_parse_factor(&mut self, start: bool) -> Result<Option<ExprKind>> {
-        let mut expr = match self.try_parse_unary(start)? {
+        let mut expr = match self.try_parse_cast(start)? {
             Some(expr) => expr,
             None => return Ok(None),
         };
 
-        while let Ok(token) = self
-            .cursor
-            .eat_any(&[TokenKind::Slash, TokenKind::Asterisk])
+        while let Ok(token) =
+            self.cursor
+                .eat_any(&[TokenKind::Slash, TokenKind::Asterisk, TokenKind::Percent])
         {
-            let rhs = self.parse_unary()?;
+            let rhs = self.parse_cast()?;
 
             let span = expr.span().combine(&rhs.span());
             expr = Binary::new(expr, token, rhs, span).into();
@@ -408,6 +1107,32 @@ impl<'a> Parser<'a> {
         Ok(Some(expr))
     }
 
+    /// ```ebnf
+    /// cast = unary ( "as" type )* ;
+    /// ```
+    fn try_parse_cast(&mut self, start: bool) -> Result<Option<ExprKind>> {
+        let mut expr = match self.try_parse_unary(start)? {
+            Some(expr) => expr,
+            None => return Ok(None),
+        };
+
+        while self.cursor.eat(TokenKind::As).is_ok() {
+            let type_ = self.parse_type()?;
+
+            let span = expr.span().combine(&type_.span);
+            expr = Cast::new(expr, type_, span).into();
+        }
+
+        Ok(Some(expr))
+    }
+
+    fn parse_cast(&mut self) -> Result<ExprKind> {
+        match self.try_parse_cast(false)? {
+            Some(expr) => Ok(expr),
+            None => Err(UnoptionalParsing.into()),
+        }
+    }
+
     fn parse_factor(&mut self) -> Result<ExprKind> {
         match self.try_parse_factor(false)? {
             Some(expr) => Ok(expr),
@@ -416,13 +1141,13 @@ impl<'a> Parser<'a> {
     }
 
     /// ```ebnf
-    /// unary = ( ( "!" | "-" ) unary )
+    /// unary = ( ( "!" | "-" | "~" ) unary )
     ///       | call ;
     /// ```
     fn try_parse_unary(&mut self, start: bool) -> Result<Option<ExprKind>> {
-        if let Ok(token) = self
-            .cursor
-            .eat_any(&[TokenKind::Apostrophe, TokenKind::Minus])
+        if let Ok(token) =
+            self.cursor
+                .eat_any(&[TokenKind::Apostrophe, TokenKind::Minus, TokenKind::Tilde])
         {
             let expr = self.parse_unary()?;
 
@@ -441,7 +1166,7 @@ impl<'a> Parser<'a> {
     }
 
     ///```ebnf
-    /// call = primary ( "(" args? ")" )* ;
+    /// call = primary ( "(" args? ")" | "." ( IDENTIFIER | NUMBER ) | "[" expression "]" )* ;
     ///```
     fn try_parse_call(&mut self, start: bool) -> Result<Option<ExprKind>> {
         let mut primary = match self.try_parse_primary(start)? {
@@ -449,25 +1174,45 @@ impl<'a> Parser<'a> {
             None => return Ok(None),
         };
 
-        while self.cursor.eat(TokenKind::Parent(true)).is_ok() {
-            primary = self.finish_parse_call(primary)?;
+        while let Ok(token) = self.cursor.eat_any(&[
+            TokenKind::Parent(true),
+            TokenKind::Period,
+            TokenKind::Bracket(true),
+        ]) {
+            primary = match token.kind {
+                TokenKind::Parent(true) => self.finish_parse_call(primary)?,
+                TokenKind::Bracket(true) => {
+                    let index = self.parse_expr_with(true)?;
+                    let end = self.cursor.eat(TokenKind::Bracket(false))?;
+
+                    let span = primary.span().combine(&end.span);
+                    Index::new(primary, index, span).into()
+                }
+                _ => {
+                    // A number accesses the element of a tuple.
+                    let field = self.cursor.eat_any(&[TokenKind::Id, TokenKind::Int])?;
+
+                    let span = primary.span().combine(&field.span);
+                    FieldAccess::new(primary, field, span).into()
+                }
+            };
         }
 
         Ok(Some(primary))
     }
 
     ///```ebnf
-    /// call = primary ( "(" args? ")" )* ;
+    /// call = primary ( "(" args? ")" | "." ( IDENTIFIER | NUMBER ) | "[" expression "]" )* ;
     ///```
     fn finish_parse_call(&mut self, callee: ExprKind) -> Result<ExprKind> {
-        if let Ok(end) = self.cursor.eat(TokenKind::Parent(true)) {
+        if let Ok(end) = self.cursor.eat(TokenKind::Parent(false)) {
             let span = callee.span().combine(&end.span);
             return Ok(Call::new(callee, Vec::new(), span).into());
         }
 
         let mut args = Vec::new();
         loop {
-            args.push(self.parse_expr()?);
+            args.push(self.parse_expr_with(true)?);
 
             if self.cursor.eat(TokenKind::Comma).is_err() {
                 break;
@@ -481,7 +1226,274 @@ impl<'a> Parser<'a> {
     }
 
     /// ```ebnf
-    /// primary = NUMBER | STRING | IDENTIFIER | "true" | "false" | "(" expr ")" ;
+    /// if = "if" expression block ( "else" ( if | block ) )? ;
+    /// ```
+    fn finish_parse_if(&mut self, start: Token) -> Result<If> {
+        let condition = self.parse_condition()?;
+
+        let then = match self.parse_block()? {
+            StmtKind::Block(node) => node,
+            _ => panic!("Couldn't unbox the block. This shouldn't have happened."),
+        };
+
+        let else_ = match self.cursor.eat(TokenKind::Else) {
+            Ok(_) => match self.cursor.eat(TokenKind::If) {
+                Ok(start) => Some(Else::If(Box::new(self.finish_parse_if(start)?))),
+                Err(_) => match self.parse_block()? {
+                    StmtKind::Block(node) => Some(Else::Block(node)),
+                    _ => panic!("Couldn't unbox the block. This shouldn't have happened."),
+                },
+            },
+            Err(_) => None,
+        };
+
+        let end = match else_ {
+            Some(ref else_) => else_.span(),
+            None => then.span,
+        };
+
+        let span = start.span.combine(&end);
+        Ok(If::new(condition, then, else_, span))
+    }
+
+    /// The arms are separated by commas or, with the indentation layout, by
+    /// newlines. Both can be left out after an arm that ended with a block.
+    ///
+    /// ```ebnf
+    /// match = "match" expression "{" ( match_arm ( "," match_arm )* ","? )? "}"
+    ///       | "match" expression ":" INDENT match_arm ( NEWLINE match_arm )* DEDENT ;
+    /// match_arm = pattern "=>" expression ;
+    /// ```
+    fn finish_parse_match(&mut self, start: Token) -> Result<Match> {
+        let expression = self.parse_condition()?;
+
+        let opening = match self.try_eat_block_start()? {
+            Some(token) => token,
+            None => self.cursor.eat(TokenKind::Brace(true))?,
+        };
+        let closing = block_end(&opening);
+
+        let mut arms = Vec::new();
+        let end = loop {
+            if let Ok(end) = self.cursor.eat(closing) {
+                break end;
+            }
+
+            let pattern = self.parse_pattern()?;
+            self.cursor.eat(TokenKind::FatArrow)?;
+            let expression = self.parse_expr_with(true)?;
+
+            let block = matches!(
+                expression,
+                ExprKind::Block(_) | ExprKind::If(_) | ExprKind::Match(_)
+            );
+
+            let span = pattern.span().combine(&expression.span());
+            arms.push(MatchArm::new(pattern, expression, span));
+
+            let comma = self.cursor.eat(TokenKind::Comma).is_ok();
+            let newline = self.cursor.eat(TokenKind::Newline).is_ok();
+            if !comma && !newline && !block {
+                break self.cursor.eat(closing)?;
+            }
+        };
+
+        let span = start.span.combine(&end.span);
+        Ok(Match::new(expression, arms, span))
+    }
+
+    /// ```ebnf
+    /// pattern = "_"
+    ///         | "-"? NUMBER | STRING | "true" | "false"
+    ///         | IDENTIFIER "!"?
+    ///         | IDENTIFIER "::" IDENTIFIER ( "(" ( pattern ( "," pattern )* )? ")" )?
+    ///         | tuple_pattern ;
+    /// tuple_pattern = "(" pattern ( "," pattern )* ","? ")" ;
+    /// ```
+    fn parse_pattern(&mut self) -> Result<Pattern> {
+        if let Ok(token) = self.cursor.eat(TokenKind::Underscore) {
+            return Ok(Wildcard::new(token.span).into());
+        }
+
+        if let Ok(start) = self.cursor.eat(TokenKind::Parent(true)) {
+            let mut patterns = Vec::new();
+            let end = loop {
+                patterns.push(self.parse_pattern()?);
+
+                if self.cursor.eat(TokenKind::Comma).is_err() {
+                    break self.cursor.eat(TokenKind::Parent(false))?;
+                }
+
+                if let Ok(end) = self.cursor.eat(TokenKind::Parent(false)) {
+                    break end;
+                }
+            };
+
+            let span = start.span.combine(&end.span);
+            return Ok(TuplePattern::new(patterns, span).into());
+        }
+
+        if let Ok(id) = self.cursor.eat(TokenKind::Id) {
+            if self.cursor.eat(TokenKind::ColonColon).is_err() {
+                let mutable = self.cursor.eat(TokenKind::Apostrophe).is_ok();
+                return Ok(Binding::new(id, mutable).into());
+            }
+
+            let variant = self.cursor.eat(TokenKind::Id)?;
+
+            let mut patterns = Vec::new();
+            let mut end = variant.span;
+            if self.cursor.eat(TokenKind::Parent(true)).is_ok() {
+                while self.cursor.is_peek(TokenKind::Parent(false)).is_none() {
+                    patterns.push(self.parse_pattern()?);
+
+                    if self.cursor.eat(TokenKind::Comma).is_err() {
+                        break;
+                    }
+                }
+
+                end = self.cursor.eat(TokenKind::Parent(false))?.span;
+            }
+
+            let span = id.span.combine(&end);
+            return Ok(VariantPattern::new(id, variant, patterns, span).into());
+        }
+
+        // Only numbers can be negated, which is why the minus is part of the
+        // pattern instead of being an unary expression.
+        let minus = self.cursor.eat(TokenKind::Minus).ok();
+        let token = match minus {
+            Some(_) => self.cursor.eat_any(&[TokenKind::Int, TokenKind::Decimal])?,
+            None => self.cursor.eat_any(&[
+                TokenKind::Int,
+                TokenKind::Decimal,
+                TokenKind::String,
+                TokenKind::True,
+                TokenKind::False,
+            ])?,
+        };
+
+        let kind = match token.kind {
+            TokenKind::Int => LiteralKind::Int,
+            TokenKind::Decimal => LiteralKind::Decimal,
+            TokenKind::String => LiteralKind::String,
+            _ => LiteralKind::Bool,
+        };
+
+        let span = minus
+            .as_ref()
+            .map_or(token.span, |minus| minus.span.combine(&token.span));
+        let literal = Literal::new(token, kind);
+        Ok(LiteralPattern::new(literal, minus.is_some(), span).into())
+    }
+
+    /// ```ebnf
+    /// struct_literal = IDENTIFIER "{" ( field_init ( "," field_init )* ","? )? "}" ;
+    /// field_init = IDENTIFIER ":" expression ;
+    /// ```
+    fn finish_parse_struct_literal(&mut self, id: Token) -> Result<StructLiteral> {
+        self.cursor.eat(TokenKind::Brace(true))?;
+
+        let mut fields = Vec::new();
+        let end = loop {
+            if let Ok(end) = self.cursor.eat(TokenKind::Brace(false)) {
+                break end;
+            }
+
+            let id = self.cursor.eat(TokenKind::Id)?;
+            self.cursor.eat(TokenKind::Colon)?;
+            let expression = self.parse_expr_with(true)?;
+
+            let span = id.span.combine(&expression.span());
+            fields.push(FieldInit::new(id, expression, span));
+
+            if self.cursor.eat(TokenKind::Comma).is_err() {
+                break self.cursor.eat(TokenKind::Brace(false))?;
+            }
+        };
+
+        let span = id.span.combine(&end.span);
+        Ok(StructLiteral::new(id, fields, span))
+    }
+
+    /// ```ebnf
+    /// variant_literal = IDENTIFIER "::" IDENTIFIER ( "(" args? ")" )? ;
+    /// ```
+    fn finish_parse_variant_literal(&mut self, id: Token) -> Result<VariantLiteral> {
+        self.cursor.eat(TokenKind::ColonColon)?;
+        let variant = self.cursor.eat(TokenKind::Id)?;
+
+        let mut arguments = Vec::new();
+        let mut end = variant.span;
+        if self.cursor.eat(TokenKind::Parent(true)).is_ok() {
+            while self.cursor.is_peek(TokenKind::Parent(false)).is_none() {
+                arguments.push(self.parse_expr_with(true)?);
+
+                if self.cursor.eat(TokenKind::Comma).is_err() {
+                    break;
+                }
+            }
+
+            end = self.cursor.eat(TokenKind::Parent(false))?.span;
+        }
+
+        let span = id.span.combine(&end);
+        Ok(VariantLiteral::new(id, variant, arguments, span))
+    }
+
+    /// ```ebnf
+    /// array_literal = "[" ( expression ( "," expression )* ","? )? "]" ;
+    /// ```
+    fn finish_parse_array_literal(&mut self, start: Token) -> Result<ArrayLiteral> {
+        let mut elements = Vec::new();
+        let end = loop {
+            if let Ok(end) = self.cursor.eat(TokenKind::Bracket(false)) {
+                break end;
+            }
+
+            elements.push(self.parse_expr_with(true)?);
+
+            if self.cursor.eat(TokenKind::Comma).is_err() {
+                break self.cursor.eat(TokenKind::Bracket(false))?;
+            }
+        };
+
+        let span = start.span.combine(&end.span);
+        Ok(ArrayLiteral::new(elements, span))
+    }
+
+    /// A single element needs a trailing comma, as it would be a grouping
+    /// otherwise.
+    ///
+    /// ```ebnf
+    /// tuple_literal = "(" expression ( "," expression )+ ","? ")"
+    ///               | "(" expression "," ")" ;
+    /// ```
+    fn finish_parse_tuple_literal(
+        &mut self,
+        start: Token,
+        first: ExprKind,
+    ) -> Result<TupleLiteral> {
+        let mut elements = vec![first];
+        let end = loop {
+            if self.cursor.eat(TokenKind::Comma).is_err() {
+                break self.cursor.eat(TokenKind::Parent(false))?;
+            }
+
+            if let Ok(end) = self.cursor.eat(TokenKind::Parent(false)) {
+                break end;
+            }
+
+            elements.push(self.parse_expr_with(true)?);
+        };
+
+        let span = start.span.combine(&end.span);
+        Ok(TupleLiteral::new(elements, span))
+    }
+
+    /// ```ebnf
+    /// primary = NUMBER | STRING | IDENTIFIER | "self" | "true" | "false" | "(" expr ")"
+    ///         | tuple_literal | struct_literal | variant_literal | array_literal | if | match | block ;
     /// ```
     fn try_parse_primary(&mut self, start: bool) -> Result<Option<ExprKind>> {
         if let Ok(token) = self.cursor.eat(TokenKind::Int) {
@@ -495,13 +1507,35 @@ impl<'a> Parser<'a> {
         } else if let Ok(token) = self.cursor.eat(TokenKind::False) {
             Ok(Some(Literal::new(token, LiteralKind::Bool).into()))
         } else if let Ok(token) = self.cursor.eat(TokenKind::Id) {
+            if self.cursor.is_peek(TokenKind::ColonColon).is_some() {
+                return Ok(Some(self.finish_parse_variant_literal(token)?.into()));
+            }
+
+            if self.struct_literals && self.cursor.is_peek(TokenKind::Brace(true)).is_some() {
+                return Ok(Some(self.finish_parse_struct_literal(token)?.into()));
+            }
+
+            Ok(Some(Id::new(token).into()))
+        } else if let Ok(token) = self.cursor.eat(TokenKind::Self_) {
             Ok(Some(Id::new(token).into()))
         } else if let Ok(start) = self.cursor.eat(TokenKind::Parent(true)) {
-            let expr = self.parse_expr()?;
+            let expr = self.parse_expr_with(true)?;
+            if self.cursor.is_peek(TokenKind::Comma).is_some() {
+                return Ok(Some(self.finish_parse_tuple_literal(start, expr)?.into()));
+            }
+
             let end = self.cursor.eat(TokenKind::Parent(false))?;
 
             let span = start.span.combine(&end.span);
             Ok(Some(Grouping::new(expr, span).into()))
+        } else if let Ok(start) = self.cursor.eat(TokenKind::Bracket(true)) {
+            Ok(Some(self.finish_parse_array_literal(start)?.into()))
+        } else if let Ok(start) = self.cursor.eat(TokenKind::If) {
+            Ok(Some(self.finish_parse_if(start)?.into()))
+        } else if let Ok(start) = self.cursor.eat(TokenKind::Match) {
+            Ok(Some(self.finish_parse_match(start)?.into()))
+        } else if let Ok(start) = self.cursor.eat(TokenKind::Brace(true)) {
+            Ok(Some(self.finish_parse_block(start)?.into()))
         } else if start {
             Ok(None)
         } else {
@@ -509,8 +1543,17 @@ impl<'a> Parser<'a> {
             Err(ParserError::from(Unexpected::new(
                 token.kind.to_string(),
                 token.span,
-                "int, decimal, string, true, false, identifier, oparent".to_string(),
+                "int, decimal, string, true, false, identifier, self, oparent, obracket, if, match, obrace"
+                    .to_string(),
             )))
         }
     }
 }
+
+/// The token that closes the block, which was started by the given one.
+fn block_end(start: &Token) -> TokenKind {
+    match start.kind {
+        TokenKind::Colon => TokenKind::Dedent,
+        _ => TokenKind::Brace(false),
+    }
+}
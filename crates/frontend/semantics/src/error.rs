@@ -8,7 +8,7 @@ use name_resolution::error::ResolutionError;
 use type_checker::error::TypeError;
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum SemanticError {
     NameResolution(ResolutionError),
     TypeChecker(TypeError),
@@ -1,49 +1,52 @@
 #[cfg(feature = "serialize")]
 use serde::Serialize;
 
+use std::{cell::RefCell, rc::Rc};
+
 use crate::error::SemanticError;
-use ast::{traversal::Visitable, Program};
+use ast::{traversal::Visitable, Program, Target};
+use lasso::Rodeo;
 use name_resolution::NameResolution;
 use type_checker::TypeChecker;
 
+/// Keeps the state of the passes between runs, so a program can be checked
+/// piece by piece, e.g. when using the REPL.
 #[cfg_attr(feature = "serialize", derive(Serialize))]
-#[derive(Debug)]
-pub struct Semantics<'a> {
-    program: &'a mut Program,
+#[derive(Debug, Clone)]
+pub struct Semantics {
+    name_resolution: NameResolution,
+    type_checker: TypeChecker,
     pub errors: Vec<SemanticError>,
 }
 
-impl<'a> Semantics<'a> {
-    pub fn new(program: &'a mut Program) -> Self {
+impl Semantics {
+    pub fn new(interner: Rc<RefCell<Rodeo>>, target: Target) -> Self {
         Self {
-            program,
+            name_resolution: NameResolution::new(&mut interner.borrow_mut()),
+            type_checker: TypeChecker::new(target),
             errors: Vec::new(),
         }
     }
 
-    pub fn run_all(&mut self) {
-        let mut name_resolution = NameResolution::default();
-        let _ = self.program.accept(&mut name_resolution);
+    pub fn run_all(&mut self, program: &mut Program) {
+        let _ = program.accept(&mut self.name_resolution);
 
-        if !name_resolution.errors.is_empty() {
+        if !self.name_resolution.errors.is_empty() {
             return self.errors.extend(
-                name_resolution
+                self.name_resolution
                     .errors
-                    .iter()
-                    .map(|error| SemanticError::NameResolution(error.clone())),
+                    .drain(..)
+                    .map(SemanticError::NameResolution),
             );
         }
 
-        let mut type_checker = TypeChecker::default();
-        let _ = self.program.accept(&mut type_checker);
+        let _ = program.accept(&mut self.type_checker);
 
-        if !type_checker.errors.is_empty() {
-            return self.errors.extend(
-                type_checker
-                    .errors
-                    .iter()
-                    .map(|error| SemanticError::TypeChecker(error.clone())),
-            );
-        }
+        self.errors.extend(
+            self.type_checker
+                .errors
+                .drain(..)
+                .map(SemanticError::TypeChecker),
+        );
     }
 }
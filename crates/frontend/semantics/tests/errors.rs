@@ -0,0 +1,317 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use lasso::Rodeo;
+use termcolor::NoColor;
+
+use ast::Target;
+use diagnostics::{file::Files, renderer::Renderer};
+use lexer::Lexer;
+use parser::Parser;
+use semantics::Semantics;
+
+/// Runs every semantic pass over the program and renders its errors, just
+/// like `arkoi run` does.
+fn check(source: &str) -> Vec<String> {
+    let mut files = Files::default();
+    let file_id = files.add("test.ark", source);
+
+    let interner = Rc::new(RefCell::new(Rodeo::default()));
+
+    let lexer = Lexer::new(&files, file_id, interner.clone());
+    let mut parser = Parser::new(lexer.into_iter());
+    let mut program = parser.parse_program();
+    assert!(parser.errors.is_empty(), "{:?}", parser.errors);
+
+    let mut semantics = Semantics::new(interner.clone(), Target::default());
+    semantics.run_all(&mut program);
+
+    semantics
+        .errors
+        .into_iter()
+        .map(|error| {
+            let mut output = NoColor::new(Vec::new());
+            Renderer::new(&files, interner.clone(), &mut output).render(error);
+            String::from_utf8(output.into_inner()).unwrap()
+        })
+        .collect()
+}
+
+macro_rules! test_error {
+    ($name:ident, $source:expr => $message:expr) => {
+        #[test]
+        fn $name() {
+            let errors = check($source);
+            assert!(
+                errors.iter().any(|error| error.contains($message)),
+                "Expected {:?} in {:#?}",
+                $message,
+                errors
+            );
+        }
+    };
+}
+
+test_error!(fail_break_outside_loop, "
+fun main() @i32 {
+    break;
+    return 0;
+}" => "'break' can only be used inside of a loop.");
+
+test_error!(fail_continue_after_loop, "
+fun main() @i32 {
+    while true {
+        let value @i32 = 1;
+    }
+    continue;
+    return 0;
+}" => "'continue' can only be used inside of a loop.");
+
+test_error!(fail_len_arity, "
+fun main() @i32 {
+    let values @[i32; 2] = [1, 2];
+    len(values, values) as @i32
+}" => "The builtin 'len' expected '1' arguments but instead got '2' arguments.");
+
+test_error!(fail_assign_immutable, "
+fun main() @i32 {
+    let value @i32 = 1;
+    value = 2;
+    return value;
+}" => "Can't assign to 'value', as it isn't mutable.");
+
+test_error!(fail_assign_immutable_field, "
+struct Point {
+    x @i32,
+}
+
+fun main() @i32 {
+    let point! @Point = Point { x: 1 };
+    point.x = 2;
+    return point.x;
+}" => "Can't assign to 'x', as it isn't mutable.");
+
+test_error!(fail_cyclic_initializer, "
+let first = second + 1;
+let second = first;
+
+fun main() @i32 {
+    return first;
+}" => "The initial value of 'first' depends on itself.");
+
+test_error!(fail_cyclic_initializer_through_call, "
+let value = get();
+
+fun get() @i32 {
+    value
+}
+
+fun main() @i32 {
+    return value;
+}" => "The initial value of 'value' depends on itself.");
+
+test_error!(fail_multiline_if_value, "
+fun main() @i32 {
+    let flag @bool = true;
+    let value @bool = if flag {
+        1
+    } else {
+        2
+    };
+    return 0;
+}" => "Expected to find the type 'bool' but instead got 'i32'");
+
+test_error!(fail_multiline_if_without_else, "
+fun main() @i32 {
+    let flag @bool = true;
+    let value @i32 = if flag {
+        1
+    };
+    return value;
+}" => "An if with a value also needs an else branch.");
+
+test_error!(fail_multiline_if_missing_value, "
+fun main() @i32 {
+    let flag @bool = true;
+    let value @i32 = if flag {
+        1
+    } else {
+        let other @i32 = 2;
+    };
+    return value;
+}" => "Either every branch of an if or match has a value or none of them.");
+
+test_error!(fail_multiline_block_value, "
+fun main() @i32 {
+    let value @bool = {
+        let other @i32 = 1;
+        other
+    };
+    return 0;
+}" => "Expected to find the type 'bool' but instead got 'i32'");
+
+test_error!(fail_multiline_tail_value, "
+fun main() @bool {
+    let value @i32 = 1;
+    {
+        let other @i32 = 2;
+        value + other
+    }
+}" => "Expected to find the type 'bool' but instead got 'i32'");
+
+test_error!(fail_negative_unsigned_literal, "
+fun main() @i32 {
+    let value @u8 = -1;
+    return 0;
+}" => "The literal is out of range for its type.");
+
+test_error!(fail_literal_out_of_range, "
+fun main() @i32 {
+    let value @i8 = 128;
+    return 0;
+}" => "The literal is out of range for its type.");
+
+test_error!(fail_mismatched_operands, "
+fun main() @i64 {
+    let small @u8 = 1;
+    let big @i64 = 2;
+    return small + big;
+}" => "The operands of '+' need to have the same type, but got 'u8' and 'i64'.");
+
+test_error!(fail_mismatched_comparison, "
+fun main() @bool {
+    let value @i32 = 1;
+    let other @f64 = 2.0;
+    value < other
+}" => "The operands of '<' need to have the same type, but got 'i32' and 'f64'.");
+
+test_error!(fail_non_exhaustive_match, "
+enum Shape {
+    Circle(@f64),
+    Empty,
+}
+
+fun main() @i32 {
+    let shape @Shape = Shape::Empty;
+    match shape {
+        Shape::Circle(_) => 1,
+    }
+}" => "The arms of a match need to cover every possible value.");
+
+test_error!(fail_non_exhaustive_integer_match, "
+fun main() @i32 {
+    let value @i32 = 1;
+    match value {
+        0 => 1,
+        1 => 2,
+    }
+}" => "The arms of a match need to cover every possible value.");
+
+test_error!(fail_unreachable_arm, "
+fun main() @i32 {
+    let flag @bool = true;
+    match flag {
+        true => 1,
+        _ => 2,
+        false => 3,
+    }
+}" => "This arm can never be reached.");
+
+/// A match on every value of a `u8`, followed by the given arms.
+fn u8_match(values: std::ops::Range<u16>, rest: &str) -> String {
+    let arms = values
+        .map(|value| format!("        {} => 1,\n", value))
+        .collect::<String>();
+    format!(
+        "fun main() @i32 {{\n    let value @u8 = 1;\n    match value {{\n{}{}    }}\n}}",
+        arms, rest
+    )
+}
+
+#[test]
+fn success_complete_integer_match() {
+    let errors = check(&u8_match(0..256, ""));
+    assert!(errors.is_empty(), "{:#?}", errors);
+}
+
+#[test]
+fn fail_incomplete_integer_match() {
+    let errors = check(&u8_match(0..255, ""));
+    assert!(
+        errors
+            .iter()
+            .any(|error| error.contains("The arms of a match need to cover every possible value.")),
+        "{:#?}",
+        errors
+    );
+}
+
+#[test]
+fn fail_wildcard_after_complete_integer_match() {
+    let errors = check(&u8_match(0..256, "        _ => 2,\n"));
+    assert!(
+        errors
+            .iter()
+            .any(|error| error.contains("This arm can never be reached.")),
+        "{:#?}",
+        errors
+    );
+}
+
+test_error!(fail_let_without_type_and_value, "
+fun main() @i32 {
+    let value;
+    return 0;
+}" => "The type of a variable can't be inferred without an initial value.");
+
+test_error!(fail_global_without_type_and_value, "
+let value!;
+
+fun main() @i32 {
+    return 0;
+}" => "The type of a variable can't be inferred without an initial value.");
+
+test_error!(fail_empty_array_without_type, "
+fun main() @i32 {
+    let values = [];
+    return 0;
+}" => "The type of the elements of an empty array can't be inferred.");
+
+test_error!(fail_recursive_struct, "
+struct Node {
+    value @i32,
+    next @Node,
+}
+
+fun main() @i32 {
+    return 0;
+}" => "The struct 'Node' contains itself, thus it can't have a value.");
+
+test_error!(fail_mutually_recursive_struct, "
+struct Tree {
+    children @[Branch; 2],
+}
+
+struct Branch {
+    tree @(Tree, bool),
+}
+
+fun main() @i32 {
+    return 0;
+}" => "The struct 'Tree' contains itself, thus it can't have a value.");
+
+#[test]
+fn success_struct_through_slice() {
+    let errors = check(
+        "
+struct Tree {
+    children @[Tree],
+    empty @[Tree; 0],
+}
+
+fun main() @i32 {
+    return 0;
+}",
+    );
+    assert!(errors.is_empty(), "{:#?}", errors);
+}
@@ -1,9 +1,10 @@
-use name_resolution::error::InvalidSymbolKind;
+use name_resolution::error::{ImmutableAssign, InvalidSymbolKind, UnknownField};
 #[cfg(feature = "serialize")]
 use serde::Serialize;
 
-use lasso::Rodeo;
+use lasso::{Rodeo, Spur};
 
+use crate::exhaustiveness::Witness;
 use ast::{Type, TypeKind};
 use diagnostics::{
     positional::LabelSpan,
@@ -39,10 +40,12 @@ impl From<InvalidBinaryType> for TypeError {
 }
 
 impl Reportable for InvalidBinaryType {
-    fn into_report(self, _interner: &Rodeo) -> Report {
+    fn into_report(self, interner: &Rodeo) -> Report {
         let report_message = format!(
             "There is no binary operator that supports: {} {} {}",
-            self.lhs, self.operator, self.rhs
+            self.lhs.name(interner),
+            self.operator,
+            self.rhs.name(interner)
         );
 
         ReportBuilder::default()
@@ -55,6 +58,68 @@ impl Reportable for InvalidBinaryType {
     }
 }
 
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct MismatchedOperands {
+    lhs: Type,
+    operator: String,
+    rhs: Type,
+}
+
+impl MismatchedOperands {
+    pub fn new(lhs: Type, operator: impl Into<String>, rhs: Type) -> Self {
+        Self {
+            lhs,
+            operator: operator.into(),
+            rhs,
+        }
+    }
+}
+
+impl From<MismatchedOperands> for TypeError {
+    fn from(value: MismatchedOperands) -> Self {
+        Self::MismatchedOperands(value)
+    }
+}
+
+impl Reportable for MismatchedOperands {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let lhs = self.lhs.kind.name(interner);
+        let rhs = self.rhs.kind.name(interner);
+
+        let report_message = format!(
+            "The operands of '{}' need to have the same type, but got '{}' and '{}'.",
+            self.operator, lhs, rhs
+        );
+        let note = format!(
+            "Convert one of them explicitly, e.g. with 'as @{}' on the right operand.",
+            lhs
+        );
+
+        ReportBuilder::default()
+            .message(report_message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message(format!("This is of the type '{}'", lhs))
+                    .span(self.lhs.span)
+                    .build()
+                    .unwrap(),
+            )
+            .label(
+                LabelBuilder::default()
+                    .message(format!("This is of the type '{}'", rhs))
+                    .span(self.rhs.span)
+                    .build()
+                    .unwrap(),
+            )
+            .note(note)
+            .build()
+            .unwrap()
+    }
+}
+
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug, Clone)]
 pub struct InvalidUnaryType {
@@ -80,10 +145,11 @@ impl From<InvalidUnaryType> for TypeError {
 }
 
 impl Reportable for InvalidUnaryType {
-    fn into_report(self, _interner: &Rodeo) -> Report {
+    fn into_report(self, interner: &Rodeo) -> Report {
         let report_message = format!(
             "There is no unary operator that supports: {} {}",
-            self.operator, self.expression
+            self.operator,
+            self.expression.name(interner)
         );
 
         ReportBuilder::default()
@@ -116,16 +182,16 @@ impl From<NotMatching> for TypeError {
 }
 
 impl Reportable for NotMatching {
-    fn into_report(self, _interner: &Rodeo) -> Report {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let expected = self.expected.kind.name(interner);
+        let got = self.got.kind.name(interner);
+
         let report_message = format!(
             "Expected to find the type '{}' but instead got '{}'",
-            self.expected.kind, self.got.kind
+            expected, got
         );
 
-        let instead_message = format!(
-            "Got '{}' but instead expected '{}'",
-            self.got.kind, self.expected.kind
-        );
+        let instead_message = format!("Got '{}' but instead expected '{}'", got, expected);
 
         ReportBuilder::default()
             .message(report_message)
@@ -199,13 +265,828 @@ impl Reportable for InvalidArity {
             .unwrap()
     }
 }
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct InvalidVariantArity {
+    expected: usize,
+    expected_span: LabelSpan,
+    got: usize,
+    got_span: LabelSpan,
+}
+
+impl InvalidVariantArity {
+    pub fn new(got: usize, got_span: LabelSpan, expected: usize, expected_span: LabelSpan) -> Self {
+        Self {
+            got,
+            got_span,
+            expected,
+            expected_span,
+        }
+    }
+}
+
+impl From<InvalidVariantArity> for TypeError {
+    fn from(value: InvalidVariantArity) -> Self {
+        Self::InvalidVariantArity(value)
+    }
+}
+
+impl Reportable for InvalidVariantArity {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        let report_message =
+            "The amount of values provided doesn't match with the ones of this variant.";
+
+        let expected_message = format!("This variant carries '{}' values.", self.expected);
+        let got_message = format!("But instead got '{}' values.", self.got);
+
+        ReportBuilder::default()
+            .message(report_message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message(expected_message)
+                    .span(self.expected_span)
+                    .build()
+                    .unwrap(),
+            )
+            .label(
+                LabelBuilder::default()
+                    .message(got_message)
+                    .span(self.got_span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct NonExhaustiveMatch {
+    missing: Witness,
+    span: LabelSpan,
+}
+
+impl NonExhaustiveMatch {
+    pub fn new(missing: Witness, span: LabelSpan) -> Self {
+        Self { missing, span }
+    }
+}
+
+impl From<NonExhaustiveMatch> for TypeError {
+    fn from(value: NonExhaustiveMatch) -> Self {
+        Self::NonExhaustiveMatch(value)
+    }
+}
+
+impl Reportable for NonExhaustiveMatch {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("The arms of a match need to cover every possible value.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message(format!(
+                        "The pattern '{}' isn't covered",
+                        self.missing.name(interner)
+                    ))
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct UnreachableArm {
+    span: LabelSpan,
+}
+
+impl UnreachableArm {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<UnreachableArm> for TypeError {
+    fn from(value: UnreachableArm) -> Self {
+        Self::UnreachableArm(value)
+    }
+}
+
+impl Reportable for UnreachableArm {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("This arm can never be reached.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message("The arms before already cover every value of this pattern")
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct RefutablePattern {
+    missing: Witness,
+    span: LabelSpan,
+}
+
+impl RefutablePattern {
+    pub fn new(missing: Witness, span: LabelSpan) -> Self {
+        Self { missing, span }
+    }
+}
+
+impl From<RefutablePattern> for TypeError {
+    fn from(value: RefutablePattern) -> Self {
+        Self::RefutablePattern(value)
+    }
+}
+
+impl Reportable for RefutablePattern {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("The pattern of a declaration needs to match every possible value.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message(format!(
+                        "The pattern '{}' isn't covered",
+                        self.missing.name(interner)
+                    ))
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct InvalidTuplePattern {
+    elements: usize,
+    kind: TypeKind,
+    span: LabelSpan,
+}
+
+impl InvalidTuplePattern {
+    pub fn new(elements: usize, kind: TypeKind, span: LabelSpan) -> Self {
+        Self {
+            elements,
+            kind,
+            span,
+        }
+    }
+}
+
+impl From<InvalidTuplePattern> for TypeError {
+    fn from(value: InvalidTuplePattern) -> Self {
+        Self::InvalidTuplePattern(value)
+    }
+}
+
+impl Reportable for InvalidTuplePattern {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let report_message = format!(
+            "A pattern of '{}' elements can't match a value of the type '{}'.",
+            self.elements,
+            self.kind.name(interner)
+        );
+
+        ReportBuilder::default()
+            .message(report_message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct MissingElse {
+    span: LabelSpan,
+}
+
+impl MissingElse {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<MissingElse> for TypeError {
+    fn from(value: MissingElse) -> Self {
+        Self::MissingElse(value)
+    }
+}
+
+impl Reportable for MissingElse {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("An if with a value also needs an else branch.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message("There is no value if the condition is false")
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct MissingBranchValue {
+    span: LabelSpan,
+    other: LabelSpan,
+}
+
+impl MissingBranchValue {
+    pub fn new(span: LabelSpan, other: LabelSpan) -> Self {
+        Self { span, other }
+    }
+}
+
+impl From<MissingBranchValue> for TypeError {
+    fn from(value: MissingBranchValue) -> Self {
+        Self::MissingBranchValue(value)
+    }
+}
+
+impl Reportable for MissingBranchValue {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("Either every branch of an if or match has a value or none of them.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message("This branch has no value")
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .label(
+                LabelBuilder::default()
+                    .message("But this one has")
+                    .span(self.other)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct NoFields {
+    kind: TypeKind,
+    span: LabelSpan,
+}
+
+impl NoFields {
+    pub fn new(kind: TypeKind, span: LabelSpan) -> Self {
+        Self { kind, span }
+    }
+}
+
+impl From<NoFields> for TypeError {
+    fn from(value: NoFields) -> Self {
+        Self::NoFields(value)
+    }
+}
+
+impl Reportable for NoFields {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let report_message = format!(
+            "The type '{}' doesn't have any fields.",
+            self.kind.name(interner)
+        );
+
+        ReportBuilder::default()
+            .message(report_message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct UnknownElement {
+    kind: TypeKind,
+    index: usize,
+    span: LabelSpan,
+}
+
+impl UnknownElement {
+    pub fn new(kind: TypeKind, index: usize, span: LabelSpan) -> Self {
+        Self { kind, index, span }
+    }
+}
+
+impl From<UnknownElement> for TypeError {
+    fn from(value: UnknownElement) -> Self {
+        Self::UnknownElement(value)
+    }
+}
+
+impl Reportable for UnknownElement {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let report_message = format!(
+            "The type '{}' doesn't have an element '{}'.",
+            self.kind.name(interner),
+            self.index
+        );
+
+        ReportBuilder::default()
+            .message(report_message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct MissingInitializer {
+    kind: TypeKind,
+    span: LabelSpan,
+}
+
+impl MissingInitializer {
+    pub fn new(kind: TypeKind, span: LabelSpan) -> Self {
+        Self { kind, span }
+    }
+}
+
+impl From<MissingInitializer> for TypeError {
+    fn from(value: MissingInitializer) -> Self {
+        Self::MissingInitializer(value)
+    }
+}
+
+impl Reportable for MissingInitializer {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let report_message = format!(
+            "A variable of the type '{}' needs an initial value.",
+            self.kind.name(interner)
+        );
+
+        ReportBuilder::default()
+            .message(report_message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct MissingType {
+    span: LabelSpan,
+}
+
+impl MissingType {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<MissingType> for TypeError {
+    fn from(value: MissingType) -> Self {
+        Self::MissingType(value)
+    }
+}
+
+impl Reportable for MissingType {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("The type of a variable can't be inferred without an initial value.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message("Either declare the type of this variable or give it a value")
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct VoidValue {
+    span: LabelSpan,
+}
+
+impl VoidValue {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<VoidValue> for TypeError {
+    fn from(value: VoidValue) -> Self {
+        Self::VoidValue(value)
+    }
+}
+
+impl Reportable for VoidValue {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("A call of a 'void' function can't be used as a value.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message("This call doesn't have a value")
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct VoidType {
+    span: LabelSpan,
+}
+
+impl VoidType {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<VoidType> for TypeError {
+    fn from(value: VoidType) -> Self {
+        Self::VoidType(value)
+    }
+}
+
+impl Reportable for VoidType {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("The type 'void' can only be used as the return type of a function.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct RecursiveStruct {
+    name: Spur,
+    span: LabelSpan,
+}
+
+impl RecursiveStruct {
+    pub fn new(name: Spur, span: LabelSpan) -> Self {
+        Self { name, span }
+    }
+}
+
+impl From<RecursiveStruct> for TypeError {
+    fn from(value: RecursiveStruct) -> Self {
+        Self::RecursiveStruct(value)
+    }
+}
+
+impl Reportable for RecursiveStruct {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let report_message = format!(
+            "The struct '{}' contains itself, thus it can't have a value.",
+            interner.resolve(&self.name)
+        );
+
+        ReportBuilder::default()
+            .message(report_message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message("This field leads back to the struct")
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct MissingReturnValue {
+    kind: TypeKind,
+    span: LabelSpan,
+}
+
+impl MissingReturnValue {
+    pub fn new(kind: TypeKind, span: LabelSpan) -> Self {
+        Self { kind, span }
+    }
+}
+
+impl From<MissingReturnValue> for TypeError {
+    fn from(value: MissingReturnValue) -> Self {
+        Self::MissingReturnValue(value)
+    }
+}
+
+impl Reportable for MissingReturnValue {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let report_message = format!(
+            "The function needs to return a value of the type '{}'.",
+            self.kind.name(interner)
+        );
+
+        ReportBuilder::default()
+            .message(report_message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message("This return doesn't have a value")
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct LiteralOutOfRange {
+    kind: TypeKind,
+    span: LabelSpan,
+}
+
+impl LiteralOutOfRange {
+    pub fn new(kind: TypeKind, span: LabelSpan) -> Self {
+        Self { kind, span }
+    }
+}
+
+impl From<LiteralOutOfRange> for TypeError {
+    fn from(value: LiteralOutOfRange) -> Self {
+        Self::LiteralOutOfRange(value)
+    }
+}
+
+impl Reportable for LiteralOutOfRange {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let label_message = format!(
+            "This literal doesn't fit into the type '{}'",
+            self.kind.name(interner)
+        );
+
+        ReportBuilder::default()
+            .message("The literal is out of range for its type.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message(label_message)
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct InvalidCast {
+    from: TypeKind,
+    to: TypeKind,
+    span: LabelSpan,
+}
+
+impl InvalidCast {
+    pub fn new(from: TypeKind, to: TypeKind, span: LabelSpan) -> Self {
+        Self { from, to, span }
+    }
+}
+
+impl From<InvalidCast> for TypeError {
+    fn from(value: InvalidCast) -> Self {
+        Self::InvalidCast(value)
+    }
+}
+
+impl Reportable for InvalidCast {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let report_message = format!(
+            "There is no conversion from '{}' to '{}'.",
+            self.from.name(interner),
+            self.to.name(interner)
+        );
+
+        ReportBuilder::default()
+            .message(report_message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct NotAnArray {
+    kind: TypeKind,
+    span: LabelSpan,
+}
+
+impl NotAnArray {
+    pub fn new(kind: TypeKind, span: LabelSpan) -> Self {
+        Self { kind, span }
+    }
+}
+
+impl From<NotAnArray> for TypeError {
+    fn from(value: NotAnArray) -> Self {
+        Self::NotAnArray(value)
+    }
+}
+
+impl Reportable for NotAnArray {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let report_message = format!(
+            "The type '{}' isn't an array or a slice.",
+            self.kind.name(interner)
+        );
+
+        ReportBuilder::default()
+            .message(report_message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct InvalidIndexType {
+    kind: TypeKind,
+    span: LabelSpan,
+}
+
+impl InvalidIndexType {
+    pub fn new(kind: TypeKind, span: LabelSpan) -> Self {
+        Self { kind, span }
+    }
+}
+
+impl From<InvalidIndexType> for TypeError {
+    fn from(value: InvalidIndexType) -> Self {
+        Self::InvalidIndexType(value)
+    }
+}
+
+impl Reportable for InvalidIndexType {
+    fn into_report(self, interner: &Rodeo) -> Report {
+        let report_message = format!(
+            "An index needs to be an integer, but got '{}'.",
+            self.kind.name(interner)
+        );
+
+        ReportBuilder::default()
+            .message(report_message)
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(LabelBuilder::default().span(self.span).build().unwrap())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub struct EmptyArray {
+    span: LabelSpan,
+}
+
+impl EmptyArray {
+    pub fn new(span: LabelSpan) -> Self {
+        Self { span }
+    }
+}
+
+impl From<EmptyArray> for TypeError {
+    fn from(value: EmptyArray) -> Self {
+        Self::EmptyArray(value)
+    }
+}
+
+impl Reportable for EmptyArray {
+    fn into_report(self, _interner: &Rodeo) -> Report {
+        ReportBuilder::default()
+            .message("The type of the elements of an empty array can't be inferred.")
+            .code(1)
+            .serverity(Serverity::Error)
+            .label(
+                LabelBuilder::default()
+                    .message("Declare the type of the variable this array is stored in")
+                    .span(self.span)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
+
+impl From<ImmutableAssign> for TypeError {
+    fn from(value: ImmutableAssign) -> Self {
+        Self::ImmutableAssign(value)
+    }
+}
+
+impl From<UnknownField> for TypeError {
+    fn from(value: UnknownField) -> Self {
+        Self::UnknownField(value)
+    }
+}
+
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug, Clone)]
 pub enum TypeError {
     InvalidBinaryType(InvalidBinaryType),
     InvalidUnaryType(InvalidUnaryType),
+    MismatchedOperands(MismatchedOperands),
     NotMatching(NotMatching),
     InvalidArity(InvalidArity),
+    InvalidVariantArity(InvalidVariantArity),
+    NonExhaustiveMatch(NonExhaustiveMatch),
+    UnreachableArm(UnreachableArm),
+    RefutablePattern(RefutablePattern),
+    InvalidTuplePattern(InvalidTuplePattern),
+    MissingElse(MissingElse),
+    MissingBranchValue(MissingBranchValue),
+    NoFields(NoFields),
+    UnknownField(UnknownField),
+    UnknownElement(UnknownElement),
+    MissingInitializer(MissingInitializer),
+    MissingType(MissingType),
+    VoidValue(VoidValue),
+    VoidType(VoidType),
+    RecursiveStruct(RecursiveStruct),
+    MissingReturnValue(MissingReturnValue),
+    LiteralOutOfRange(LiteralOutOfRange),
+    InvalidCast(InvalidCast),
+    NotAnArray(NotAnArray),
+    InvalidIndexType(InvalidIndexType),
+    EmptyArray(EmptyArray),
+    ImmutableAssign(ImmutableAssign),
     InternalError(InternalError),
 }
 
@@ -214,8 +1095,31 @@ impl Reportable for TypeError {
         match self {
             Self::InvalidBinaryType(error) => error.into_report(interner),
             Self::InvalidUnaryType(error) => error.into_report(interner),
+            Self::MismatchedOperands(error) => error.into_report(interner),
             Self::NotMatching(error) => error.into_report(interner),
             Self::InvalidArity(error) => error.into_report(interner),
+            Self::InvalidVariantArity(error) => error.into_report(interner),
+            Self::NonExhaustiveMatch(error) => error.into_report(interner),
+            Self::UnreachableArm(error) => error.into_report(interner),
+            Self::RefutablePattern(error) => error.into_report(interner),
+            Self::InvalidTuplePattern(error) => error.into_report(interner),
+            Self::MissingElse(error) => error.into_report(interner),
+            Self::MissingBranchValue(error) => error.into_report(interner),
+            Self::NoFields(error) => error.into_report(interner),
+            Self::UnknownField(error) => error.into_report(interner),
+            Self::UnknownElement(error) => error.into_report(interner),
+            Self::MissingInitializer(error) => error.into_report(interner),
+            Self::MissingType(error) => error.into_report(interner),
+            Self::VoidValue(error) => error.into_report(interner),
+            Self::VoidType(error) => error.into_report(interner),
+            Self::RecursiveStruct(error) => error.into_report(interner),
+            Self::MissingReturnValue(error) => error.into_report(interner),
+            Self::LiteralOutOfRange(error) => error.into_report(interner),
+            Self::InvalidCast(error) => error.into_report(interner),
+            Self::NotAnArray(error) => error.into_report(interner),
+            Self::InvalidIndexType(error) => error.into_report(interner),
+            Self::EmptyArray(error) => error.into_report(interner),
+            Self::ImmutableAssign(error) => error.into_report(interner),
             Self::InternalError(error) => error.into_report(interner),
         }
     }
@@ -0,0 +1,389 @@
+//! Checks the patterns of a `match` with the usefulness algorithm described
+//! in "Warnings for pattern matching" by Luc Maranget. A pattern is useful if
+//! it matches a value, which none of the patterns before it match. Thus, an
+//! arm is unreachable if its pattern isn't useful, while a match is
+//! exhaustive if a wildcard after the last arm wouldn't be useful.
+
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+
+use std::{cell::RefCell, collections::HashMap, rc::Rc, slice};
+
+use lasso::{Rodeo, Spur};
+
+use ast::{EnumDecl, LiteralKind, Pattern, TypeKind};
+
+/// A value that isn't matched by any arm, which is shown to the user.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone)]
+pub enum Witness {
+    Wildcard,
+    Bool(bool),
+    Int(i128),
+    /// The names of the enum and its variant together with the values.
+    Variant(Spur, Spur, Vec<Witness>),
+    Tuple(Vec<Witness>),
+}
+
+impl Witness {
+    /// Formats the witness like the pattern, which would match it.
+    pub fn name(&self, interner: &Rodeo) -> String {
+        match self {
+            Self::Wildcard => "_".to_string(),
+            Self::Bool(value) => value.to_string(),
+            Self::Int(value) => value.to_string(),
+            Self::Variant(enum_, variant, values) => {
+                let name = format!("{}::{}", interner.resolve(enum_), interner.resolve(variant));
+                if values.is_empty() {
+                    return name;
+                }
+
+                let values = values
+                    .iter()
+                    .map(|value| value.name(interner))
+                    .collect::<Vec<_>>();
+                format!("{}({})", name, values.join(", "))
+            }
+            Self::Tuple(values) => {
+                let values = values
+                    .iter()
+                    .map(|value| value.name(interner))
+                    .collect::<Vec<_>>();
+                match values.as_slice() {
+                    [value] => format!("({},)", value),
+                    _ => format!("({})", values.join(", ")),
+                }
+            }
+        }
+    }
+}
+
+/// The part of a value a pattern can match on.
+#[derive(Debug, Clone, PartialEq)]
+enum Constructor {
+    /// The variant of an enum by its position in the declaration.
+    Variant(usize),
+    /// The only constructor of a tuple, whose fields are its elements.
+    Tuple,
+    Bool(bool),
+    Int(i128),
+    /// The bits of a decimal, as it can't be compared otherwise.
+    Decimal(u64),
+    Str(Spur),
+}
+
+/// A pattern reduced to the parts, which matter for its usefulness.
+#[derive(Debug, Clone)]
+pub struct Pat {
+    /// A `None` matches any value, just like a wildcard or a binding.
+    constructor: Option<Constructor>,
+    fields: Vec<Pat>,
+}
+
+impl Pat {
+    const WILDCARD: Pat = Pat {
+        constructor: None,
+        fields: Vec::new(),
+    };
+
+    /// Reduces a pattern, which already passed the type checker.
+    pub fn new(pattern: &Pattern) -> Self {
+        let constructor = match pattern {
+            Pattern::Wildcard(_) | Pattern::Binding(_) => return Self::WILDCARD,
+            Pattern::Variant(node) => {
+                let fields = node.patterns.iter().map(Pat::new).collect();
+                let index = node.index.get().copied().unwrap_or_default();
+                return Self {
+                    constructor: Some(Constructor::Variant(index)),
+                    fields,
+                };
+            }
+            Pattern::Tuple(node) => {
+                return Self {
+                    constructor: Some(Constructor::Tuple),
+                    fields: node.patterns.iter().map(Pat::new).collect(),
+                };
+            }
+            Pattern::Literal(node) => {
+                let token = &node.literal.token;
+                match node.literal.kind {
+                    LiteralKind::Bool => Constructor::Bool(token.get_bool().unwrap_or_default()),
+                    LiteralKind::String => Constructor::Str(token.get_spur().unwrap()),
+                    LiteralKind::Int => {
+                        let value = token.get_int().unwrap_or_default() as i128;
+                        Constructor::Int(if node.negated { -value } else { value })
+                    }
+                    LiteralKind::Decimal => {
+                        let value = token.get_dec().unwrap_or_default();
+                        let value = if node.negated { -value } else { value };
+                        // Adding zero turns a negative zero into a positive one.
+                        Constructor::Decimal((value + 0.0).to_bits())
+                    }
+                }
+            }
+        };
+
+        Self {
+            constructor: Some(constructor),
+            fields: Vec::new(),
+        }
+    }
+}
+
+pub struct Exhaustiveness<'a> {
+    enums: &'a HashMap<Spur, Rc<RefCell<EnumDecl>>>,
+}
+
+impl<'a> Exhaustiveness<'a> {
+    pub fn new(enums: &'a HashMap<Spur, Rc<RefCell<EnumDecl>>>) -> Self {
+        Self { enums }
+    }
+
+    /// Whether the pattern matches a value of the type, which none of the
+    /// previous patterns match.
+    pub fn is_useful(&self, previous: &[Pat], pattern: &Pat, kind: &TypeKind) -> bool {
+        let rows = previous
+            .iter()
+            .map(|pattern| vec![pattern.clone()])
+            .collect::<Vec<_>>();
+        self.useful(&rows, slice::from_ref(pattern), slice::from_ref(kind))
+    }
+
+    /// A value of the type, which isn't matched by any of the patterns.
+    pub fn missing(&self, patterns: &[Pat], kind: &TypeKind) -> Option<Witness> {
+        let rows = patterns
+            .iter()
+            .map(|pattern| vec![pattern.clone()])
+            .collect::<Vec<_>>();
+        self.witnesses(&rows, slice::from_ref(kind))?.pop()
+    }
+
+    fn useful(&self, rows: &[Vec<Pat>], vector: &[Pat], kinds: &[TypeKind]) -> bool {
+        let Some((head, rest)) = vector.split_first() else {
+            return rows.is_empty();
+        };
+
+        if let Some(ref constructor) = head.constructor {
+            let rows = self.specialize(rows, constructor, &kinds[0]);
+            let mut vector = head.fields.clone();
+            vector.extend_from_slice(rest);
+            let kinds = self.expand(constructor, kinds);
+            return self.useful(&rows, &vector, &kinds);
+        }
+
+        match self.complete(rows, &kinds[0]) {
+            Some(constructors) => constructors.iter().any(|constructor| {
+                let rows = self.specialize(rows, constructor, &kinds[0]);
+                let mut vector = vec![Pat::WILDCARD; self.arity(constructor, &kinds[0])];
+                vector.extend_from_slice(rest);
+                let kinds = self.expand(constructor, kinds);
+                self.useful(&rows, &vector, &kinds)
+            }),
+            None => self.useful(&self.default(rows), rest, &kinds[1..]),
+        }
+    }
+
+    /// The values of a row, which isn't matched by any of the rows. The
+    /// result is reversed, thus the value of the first column is the last.
+    fn witnesses(&self, rows: &[Vec<Pat>], kinds: &[TypeKind]) -> Option<Vec<Witness>> {
+        let Some(kind) = kinds.first() else {
+            return rows.is_empty().then(Vec::new);
+        };
+
+        if let Some(constructors) = self.complete(rows, kind) {
+            return constructors.iter().find_map(|constructor| {
+                let rows = self.specialize(rows, constructor, kind);
+                let mut witnesses = self.witnesses(&rows, &self.expand(constructor, kinds))?;
+                let fields = self.arity(constructor, kind);
+                let values = (0..fields).filter_map(|_| witnesses.pop()).collect();
+                witnesses.push(self.witness(constructor, kind, values));
+                Some(witnesses)
+            });
+        }
+
+        let mut witnesses = self.witnesses(&self.default(rows), &kinds[1..])?;
+
+        // Any constructor, which isn't used by the rows, is missing. The
+        // values of an integer are tried from the smallest one on.
+        let used = self.used(rows);
+        let missing = match int_range(kind) {
+            Some((min, max)) => (min..=max)
+                .map(Constructor::Int)
+                .find(|constructor| !used.contains(constructor)),
+            None => self
+                .constructors(kind)
+                .into_iter()
+                .find(|constructor| !used.contains(constructor)),
+        };
+        let witness = match missing {
+            Some(constructor) => {
+                let values = vec![Witness::Wildcard; self.arity(&constructor, kind)];
+                self.witness(&constructor, kind, values)
+            }
+            None => Witness::Wildcard,
+        };
+
+        witnesses.push(witness);
+        Some(witnesses)
+    }
+
+    /// The constructors of the type, if the rows use all of them in their
+    /// first column. An integer is only complete if the rows list every
+    /// value of its range, while other literals than booleans never are and
+    /// tuples always are.
+    fn complete(&self, rows: &[Vec<Pat>], kind: &TypeKind) -> Option<Vec<Constructor>> {
+        if let TypeKind::Tuple(_) = kind {
+            return Some(vec![Constructor::Tuple]);
+        }
+
+        if let Some((min, max)) = int_range(kind) {
+            let mut values = self
+                .used(rows)
+                .into_iter()
+                .filter_map(|constructor| match constructor {
+                    Constructor::Int(value) => Some(value),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            values.sort_unstable();
+            values.dedup();
+
+            let count = values.len() as i128;
+            return (count == max - min + 1)
+                .then(|| values.into_iter().map(Constructor::Int).collect());
+        }
+
+        if !matches!(kind, TypeKind::Bool | TypeKind::Enum(_)) {
+            return None;
+        }
+
+        let constructors = self.constructors(kind);
+        let used = self.used(rows);
+        constructors
+            .iter()
+            .all(|constructor| used.contains(constructor))
+            .then_some(constructors)
+    }
+
+    /// Every constructor of a type, which has a finite amount of them.
+    fn constructors(&self, kind: &TypeKind) -> Vec<Constructor> {
+        match kind {
+            TypeKind::Bool => vec![Constructor::Bool(false), Constructor::Bool(true)],
+            TypeKind::Enum(name) => {
+                let variants = self.variants(name).len();
+                (0..variants).map(Constructor::Variant).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn used(&self, rows: &[Vec<Pat>]) -> Vec<Constructor> {
+        rows.iter()
+            .filter_map(|row| row.first()?.constructor.clone())
+            .collect()
+    }
+
+    /// The types of the values a variant carries.
+    fn variants(&self, name: &Spur) -> Vec<Vec<TypeKind>> {
+        let Some(enum_decl) = self.enums.get(name) else {
+            return Vec::new();
+        };
+
+        enum_decl
+            .borrow()
+            .variants
+            .iter()
+            .map(|variant| {
+                variant
+                    .types
+                    .iter()
+                    .map(|type_| type_.kind.clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn fields(&self, constructor: &Constructor, kind: &TypeKind) -> Vec<TypeKind> {
+        match (constructor, kind) {
+            (Constructor::Variant(index), TypeKind::Enum(name)) => self
+                .variants(name)
+                .into_iter()
+                .nth(*index)
+                .unwrap_or_default(),
+            (Constructor::Tuple, TypeKind::Tuple(elements)) => elements.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn arity(&self, constructor: &Constructor, kind: &TypeKind) -> usize {
+        self.fields(constructor, kind).len()
+    }
+
+    /// Replaces the type of the first column with the ones of the values
+    /// carried by the constructor.
+    fn expand(&self, constructor: &Constructor, kinds: &[TypeKind]) -> Vec<TypeKind> {
+        let mut expanded = self.fields(constructor, &kinds[0]);
+        expanded.extend_from_slice(&kinds[1..]);
+        expanded
+    }
+
+    /// Keeps the rows, which match the constructor, and replaces their first
+    /// column with the patterns of its values.
+    fn specialize(
+        &self,
+        rows: &[Vec<Pat>],
+        constructor: &Constructor,
+        kind: &TypeKind,
+    ) -> Vec<Vec<Pat>> {
+        rows.iter()
+            .filter_map(|row| {
+                let (head, rest) = row.split_first()?;
+                let mut specialized = match head.constructor {
+                    Some(ref other) if other == constructor => head.fields.clone(),
+                    Some(_) => return None,
+                    None => vec![Pat::WILDCARD; self.arity(constructor, kind)],
+                };
+
+                specialized.extend_from_slice(rest);
+                Some(specialized)
+            })
+            .collect()
+    }
+
+    /// Keeps the rows, which match any value in their first column.
+    fn default(&self, rows: &[Vec<Pat>]) -> Vec<Vec<Pat>> {
+        rows.iter()
+            .filter(|row| row.first().is_some_and(|head| head.constructor.is_none()))
+            .map(|row| row[1..].to_vec())
+            .collect()
+    }
+
+    fn witness(&self, constructor: &Constructor, kind: &TypeKind, values: Vec<Witness>) -> Witness {
+        match (constructor, kind) {
+            (Constructor::Bool(value), _) => Witness::Bool(*value),
+            (Constructor::Int(value), _) => Witness::Int(*value),
+            (Constructor::Variant(index), TypeKind::Enum(name)) => {
+                let variant = self
+                    .enums
+                    .get(name)
+                    .and_then(|enum_decl| enum_decl.borrow().variants.get(*index)?.id.get_spur());
+                match variant {
+                    Some(variant) => Witness::Variant(*name, variant, values),
+                    None => Witness::Wildcard,
+                }
+            }
+            (Constructor::Tuple, _) => Witness::Tuple(values),
+            _ => Witness::Wildcard,
+        }
+    }
+}
+
+/// The smallest and largest value of an integer type. The width of the
+/// pointer-sized ones depends on the target, thus they don't have one.
+fn int_range(kind: &TypeKind) -> Option<(i128, i128)> {
+    match *kind {
+        TypeKind::Int(true, bits) => Some((-(1 << (bits - 1)), (1 << (bits - 1)) - 1)),
+        TypeKind::Int(false, bits) => Some((0, (1 << bits) - 1)),
+        _ => None,
+    }
+}
@@ -1,4 +1,5 @@
-pub mod type_checker;
 pub mod error;
+pub mod exhaustiveness;
+pub mod type_checker;
 
 pub use crate::type_checker::*;
@@ -1,41 +1,383 @@
-use name_resolution::error::InvalidSymbolKind;
+use name_resolution::error::{ImmutableAssign, InvalidSymbolKind, UnknownField};
 #[cfg(feature = "serialize")]
 use serde::Serialize;
 
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use crate::error::{
-    InvalidArity, InvalidBinaryType, InvalidUnaryType, NoSymbolFound, NoTypeFound, NotMatching,
-    Result, TypeError,
+use lasso::Spur;
+
+use diagnostics::positional::LabelSpan;
+
+use crate::{
+    error::{
+        EmptyArray, InvalidArity, InvalidBinaryType, InvalidCast, InvalidIndexType,
+        InvalidTuplePattern, InvalidUnaryType, InvalidVariantArity, LiteralOutOfRange,
+        MismatchedOperands, MissingBranchValue, MissingElse, MissingInitializer,
+        MissingReturnValue, MissingType, NoFields, NoSymbolFound, NoTypeFound, NonExhaustiveMatch,
+        NotAnArray, NotMatching, RecursiveStruct, RefutablePattern, Result, TypeError,
+        UnknownElement, UnreachableArm, VoidType, VoidValue,
+    },
+    exhaustiveness::{Exhaustiveness, Pat},
 };
 use ast::{
     symbol::SymbolKind,
     traversal::{Visitable, Visitor},
-    Binary, BinaryOperator, Block, Call, FunDecl, Id, LetDecl, Literal, LiteralKind, Parameter,
-    Program, Return, Type, TypeKind, Unary, UnaryOperator,
+    ArrayLiteral, Assign, Binary, BinaryOperator, Block, Call, Cast, DestructureDecl, Else,
+    EnumDecl, ExprKind, ExprStmt, FieldAccess, FunDecl, Id, If, Index, Len, LetDecl, Literal,
+    LiteralKind, Match, Parameter, Pattern, Program, Return, StmtKind, StructDecl, StructLiteral,
+    Target, TupleLiteral, Type, TypeKind, Unary, UnaryOperator, VariantLiteral, While,
 };
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct TypeChecker {
+    /// Decides the width of pointer-sized integers, which limits their literals.
+    target: Target,
     current_function: Option<Type>,
+    /// The type the current expression is expected to have, which is given
+    /// to the number literals inside of it.
+    expected: Option<TypeKind>,
+    /// The declarations of the structs, which are needed to look up the
+    /// types of their fields.
+    structs: HashMap<Spur, Rc<RefCell<StructDecl>>>,
+    /// The declarations of the enums, which are needed to look up the types
+    /// of the values their variants carry.
+    enums: HashMap<Spur, Rc<RefCell<EnumDecl>>>,
     pub errors: Vec<TypeError>,
 }
 
 impl TypeChecker {
-    fn check_equality(&self, lhs: &Type, operator: BinaryOperator, rhs: &Type) -> Option<TypeKind> {
-        Some(match (lhs.kind, rhs.kind) {
-            (TypeKind::Bool, TypeKind::Bool) => match operator {
-                BinaryOperator::Eq | BinaryOperator::NotEq => TypeKind::Bool,
-                _ => return None,
+    pub fn new(target: Target) -> Self {
+        Self {
+            target,
+            ..Self::default()
+        }
+    }
+
+    /// Checks an expression, whose value is going to be used. Thus, it can't
+    /// be the call of a `void` function. The number literals inside of it
+    /// get the expected type, if they don't have a suffix.
+    fn check_value(
+        &mut self,
+        expression: &mut ExprKind,
+        expected: Option<TypeKind>,
+    ) -> std::result::Result<Type, TypeError> {
+        let span = value_span(expression);
+
+        let last = std::mem::replace(&mut self.expected, expected);
+        let result = expression.accept(self);
+        self.expected = last;
+
+        let type_ = result?.ok_or(NoTypeFound::new(span))?;
+
+        match type_.kind {
+            TypeKind::Void => Err(VoidValue::new(span).into()),
+            _ => Ok(type_),
+        }
+    }
+
+    /// Variables, parameters, fields and the elements of arrays and tuples
+    /// always hold a value.
+    fn check_not_void(&self, type_: &Type) -> std::result::Result<(), TypeError> {
+        match type_.kind.contains(&TypeKind::Void) {
+            true => Err(VoidType::new(type_.span).into()),
+            false => Ok(()),
+        }
+    }
+
+    /// A struct, which contains itself through its fields, would need an
+    /// infinitely large value.
+    fn check_not_recursive(
+        &self,
+        node: &Rc<RefCell<StructDecl>>,
+    ) -> std::result::Result<(), TypeError> {
+        let struct_decl = node.borrow();
+        let name = struct_decl.id.get_spur().unwrap();
+
+        match struct_decl
+            .fields
+            .iter()
+            .find(|field| self.contains_struct(&field.type_.kind, &name, &mut Vec::new()))
+        {
+            Some(field) => Err(RecursiveStruct::new(name, field.type_.span).into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether every value of the type contains a value of the struct. A
+    /// slice or an empty array can be empty, just like an enum can have
+    /// another variant, thus they don't.
+    fn contains_struct(&self, kind: &TypeKind, name: &Spur, visited: &mut Vec<Spur>) -> bool {
+        match kind {
+            TypeKind::Struct(other) if other == name => true,
+            TypeKind::Struct(other) if !visited.contains(other) => {
+                visited.push(*other);
+                self.structs.get(other).is_some_and(|struct_decl| {
+                    struct_decl
+                        .borrow()
+                        .fields
+                        .iter()
+                        .any(|field| self.contains_struct(&field.type_.kind, name, visited))
+                })
+            }
+            TypeKind::Array(element, length) => {
+                *length > 0 && self.contains_struct(element, name, visited)
+            }
+            TypeKind::Tuple(elements) => elements
+                .iter()
+                .any(|element| self.contains_struct(element, name, visited)),
+            _ => false,
+        }
+    }
+
+    /// Gives the parameters and the function itself their types, before
+    /// any call of the function gets checked.
+    fn check_signature(&mut self, node: &mut Rc<RefCell<FunDecl>>) -> Result {
+        node.borrow_mut()
+            .parameters
+            .iter_mut()
+            .for_each(|parameter| match parameter.accept(self) {
+                Ok(_) => {}
+                Err(error) => self.errors.push(error),
+            });
+
+        let id_span = node.borrow().id.span;
+        let type_ = node
+            .borrow_mut()
+            .type_
+            .accept(self)?
+            .ok_or(NoTypeFound::new(id_span))?;
+
+        let borrow = node.borrow();
+        let symbol = borrow.symbol.get().ok_or(NoSymbolFound::new(id_span))?;
+        symbol.borrow_mut().type_.set(type_).unwrap();
+
+        Self::default_result()
+    }
+
+    fn check_condition(&mut self, condition: &mut ExprKind) -> std::result::Result<(), TypeError> {
+        let span = value_span(condition);
+        let type_ = self.check_value(condition, Some(TypeKind::Bool))?;
+
+        if type_.kind != TypeKind::Bool {
+            let got = Type::new(type_.kind, span);
+            let expected = Type::new(TypeKind::Bool, span);
+            return Err(NotMatching::new(got, expected).into());
+        }
+
+        Ok(())
+    }
+
+    /// Makes sure that every field on the way to the assigned place is
+    /// mutable and returns the type of the place. The variable itself was
+    /// already checked by the name resolution.
+    fn check_mutable_fields(
+        &self,
+        target: &ExprKind,
+        span: LabelSpan,
+    ) -> std::result::Result<Option<TypeKind>, TypeError> {
+        let node = match target {
+            ExprKind::FieldAccess(node) => node,
+            // The elements of an array are as mutable as the array itself.
+            ExprKind::Index(node) => {
+                let target = self.check_mutable_fields(&node.target, span)?;
+                return Ok(target.as_ref().and_then(TypeKind::element).cloned());
+            }
+            ExprKind::Id(node) => {
+                let symbol = node.symbol.get().ok_or(NoSymbolFound::new(node.id.span))?;
+                return Ok(symbol.borrow().type_.get().map(|type_| type_.kind.clone()));
+            }
+            _ => return Ok(None),
+        };
+
+        let struct_decl = match self.check_mutable_fields(&node.target, span)? {
+            Some(TypeKind::Struct(name)) => self.structs.get(&name),
+            // Just like arrays, the elements of a tuple are as mutable as the
+            // tuple itself.
+            Some(TypeKind::Tuple(elements)) => {
+                let index = node.index.get().copied();
+                return Ok(index.and_then(|index| elements.into_iter().nth(index)));
+            }
+            _ => None,
+        };
+        let (Some(struct_decl), Some(index)) = (struct_decl, node.index.get()) else {
+            return Ok(None);
+        };
+
+        let struct_decl = struct_decl.borrow();
+        let field = &struct_decl.fields[*index];
+        if !field.mutable {
+            let name = field.id.get_spur().unwrap();
+            return Err(ImmutableAssign::new(name, field.span, span).into());
+        }
+
+        Ok(Some(field.type_.kind.clone()))
+    }
+
+    /// A literal gets the type of its suffix or the expected one. Without
+    /// either, an integer is an `i32` if it fits into one and a decimal is an
+    /// `f64`. A negated literal can also be the minimum of a signed integer.
+    fn check_literal(
+        &self,
+        node: &Literal,
+        negated: bool,
+    ) -> std::result::Result<TypeKind, TypeError> {
+        let kind = match (node.kind, &node.suffix, &self.expected) {
+            (LiteralKind::Bool, _, _) => return Ok(TypeKind::Bool),
+            (LiteralKind::String, _, _) => return Ok(TypeKind::Str),
+            (_, Some(suffix), _) => suffix.clone(),
+            (LiteralKind::Int, None, Some(expected)) if expected.is_integer() => expected.clone(),
+            (LiteralKind::Decimal, None, Some(expected @ TypeKind::Decimal(_))) => expected.clone(),
+            (LiteralKind::Int, None, _) => match node.token.get_int().unwrap_or_default() {
+                value if value <= i32::MAX as usize => TypeKind::Int(true, 32),
+                value if value <= i64::MAX as usize => TypeKind::Int(true, 64),
+                _ => TypeKind::Int(false, 64),
             },
+            (LiteralKind::Decimal, None, _) => TypeKind::Decimal(64),
+        };
+
+        let fits = match kind.resolve(self.target) {
+            TypeKind::Int(signed, size) => {
+                let value = node.token.get_int().unwrap_or_default() as u128;
+                let max = match (signed, negated) {
+                    (false, false) => (1 << size) - 1,
+                    (false, true) => 0,
+                    (true, false) => (1 << (size - 1)) - 1,
+                    (true, true) => 1 << (size - 1),
+                };
+
+                value <= max
+            }
+            TypeKind::Decimal(32) => node
+                .token
+                .get_dec()
+                .is_none_or(|value| value <= f32::MAX as f64),
+            _ => true,
+        };
+
+        if !fits {
+            return Err(LiteralOutOfRange::new(kind, node.token.span).into());
+        }
+
+        node.type_.set(kind.clone()).ok();
+        Ok(kind)
+    }
+
+    /// Checks a pattern against the type of the value it's matched with and
+    /// gives its bindings the type of the value they are bound to.
+    fn check_pattern(
+        &mut self,
+        pattern: &mut Pattern,
+        kind: &TypeKind,
+    ) -> std::result::Result<(), TypeError> {
+        let span = pattern.span();
+        match pattern {
+            Pattern::Wildcard(_) => Ok(()),
+            Pattern::Binding(node) => {
+                let symbol = node.symbol.get().ok_or(NoSymbolFound::new(span))?;
+                symbol
+                    .borrow_mut()
+                    .type_
+                    .set(Type::new(kind.clone(), span))
+                    .ok();
+                Ok(())
+            }
+            Pattern::Literal(node) => {
+                let last = self.expected.replace(kind.clone());
+                let result = self.check_literal(&node.literal, node.negated);
+                self.expected = last;
+                let got = result?;
+
+                let negatable = matches!(
+                    got,
+                    TypeKind::Int(true, _) | TypeKind::Size(true) | TypeKind::Decimal(_)
+                );
+                if node.negated && !negatable {
+                    return Err(InvalidUnaryType::new("-", got, span).into());
+                }
+
+                if got != *kind {
+                    let got = Type::new(got, span);
+                    let expected = Type::new(kind.clone(), span);
+                    return Err(NotMatching::new(got, expected).into());
+                }
+
+                Ok(())
+            }
+            Pattern::Variant(node) => {
+                let id = node.id.get_spur().unwrap();
+                if *kind != TypeKind::Enum(id) {
+                    let got = Type::new(TypeKind::Enum(id), span);
+                    let expected = Type::new(kind.clone(), span);
+                    return Err(NotMatching::new(got, expected).into());
+                }
+
+                let enum_decl = self
+                    .enums
+                    .get(&id)
+                    .cloned()
+                    .ok_or(NoTypeFound::new(node.id.span))?;
+                let enum_decl = enum_decl.borrow();
+
+                let index = *node
+                    .index
+                    .get()
+                    .ok_or(NoTypeFound::new(node.variant.span))?;
+                let variant = &enum_decl.variants[index];
+                if node.patterns.len() != variant.types.len() {
+                    return Err(InvalidVariantArity::new(
+                        node.patterns.len(),
+                        span,
+                        variant.types.len(),
+                        variant.span,
+                    )
+                    .into());
+                }
+
+                for (pattern, type_) in node.patterns.iter_mut().zip(variant.types.iter()) {
+                    self.check_pattern(pattern, &type_.kind)?;
+                }
+
+                Ok(())
+            }
+            Pattern::Tuple(node) => {
+                let elements = match kind {
+                    TypeKind::Tuple(elements) if elements.len() == node.patterns.len() => elements,
+                    _ => {
+                        let error =
+                            InvalidTuplePattern::new(node.patterns.len(), kind.clone(), span);
+                        return Err(error.into());
+                    }
+                };
+
+                for (pattern, kind) in node.patterns.iter_mut().zip(elements.iter()) {
+                    self.check_pattern(pattern, kind)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    fn check_logical(&self, lhs: &Type, rhs: &Type) -> Option<TypeKind> {
+        match (&lhs.kind, &rhs.kind) {
+            (TypeKind::Bool, TypeKind::Bool) => Some(TypeKind::Bool),
+            _ => None,
+        }
+    }
 
-            (TypeKind::Int(_, _), TypeKind::Int(_, _)) => match operator {
+    fn check_equality(&self, lhs: &Type, operator: BinaryOperator, rhs: &Type) -> Option<TypeKind> {
+        if lhs.kind != rhs.kind {
+            return None;
+        }
+
+        Some(match &lhs.kind {
+            TypeKind::Bool | TypeKind::Str => match operator {
                 BinaryOperator::Eq | BinaryOperator::NotEq => TypeKind::Bool,
                 _ => return None,
             },
 
-            (TypeKind::Decimal(_), TypeKind::Decimal(_)) => match operator {
+            kind if kind.is_number() => match operator {
                 BinaryOperator::Eq | BinaryOperator::NotEq => TypeKind::Bool,
                 _ => return None,
             },
@@ -50,16 +392,12 @@ impl TypeChecker {
         operator: BinaryOperator,
         rhs: &Type,
     ) -> Option<TypeKind> {
-        Some(match (lhs.kind, rhs.kind) {
-            (TypeKind::Int(_, _), TypeKind::Int(_, _)) => match operator {
-                BinaryOperator::Greater
-                | BinaryOperator::GreaterEq
-                | BinaryOperator::Less
-                | BinaryOperator::LessEq => TypeKind::Bool,
-                _ => return None,
-            },
+        if lhs.kind != rhs.kind {
+            return None;
+        }
 
-            (TypeKind::Decimal(_), TypeKind::Decimal(_)) => match operator {
+        Some(match &lhs.kind {
+            kind if kind.is_number() => match operator {
                 BinaryOperator::Greater
                 | BinaryOperator::GreaterEq
                 | BinaryOperator::Less
@@ -72,14 +410,19 @@ impl TypeChecker {
     }
 
     fn check_term(&self, lhs: &Type, operator: BinaryOperator, rhs: &Type) -> Option<TypeKind> {
-        Some(match (lhs.kind, rhs.kind) {
-            (TypeKind::Int(signed, size), TypeKind::Int(_, _)) => match operator {
-                BinaryOperator::Add | BinaryOperator::Sub => TypeKind::Int(signed, size),
+        if lhs.kind != rhs.kind {
+            return None;
+        }
+
+        Some(match &lhs.kind {
+            kind if kind.is_number() => match operator {
+                BinaryOperator::Add | BinaryOperator::Sub => kind.clone(),
                 _ => return None,
             },
 
-            (TypeKind::Decimal(size), TypeKind::Decimal(_)) => match operator {
-                BinaryOperator::Add | BinaryOperator::Sub => TypeKind::Decimal(size),
+            // Adding two strings concatenates them.
+            TypeKind::Str => match operator {
+                BinaryOperator::Add => TypeKind::Str,
                 _ => return None,
             },
 
@@ -88,20 +431,57 @@ impl TypeChecker {
     }
 
     fn check_factor(&self, lhs: &Type, operator: BinaryOperator, rhs: &Type) -> Option<TypeKind> {
-        Some(match (lhs.kind, rhs.kind) {
-            (TypeKind::Int(signed, size), TypeKind::Int(_, _)) => match operator {
-                BinaryOperator::Div | BinaryOperator::Mul => TypeKind::Int(signed, size),
+        if lhs.kind != rhs.kind {
+            return None;
+        }
+
+        Some(match &lhs.kind {
+            kind if kind.is_integer() => match operator {
+                BinaryOperator::Div | BinaryOperator::Mul | BinaryOperator::Rem => kind.clone(),
                 _ => return None,
             },
 
-            (TypeKind::Decimal(size), TypeKind::Decimal(_)) => match operator {
-                BinaryOperator::Div | BinaryOperator::Mul => TypeKind::Decimal(size),
+            kind @ TypeKind::Decimal(_) => match operator {
+                BinaryOperator::Div | BinaryOperator::Mul => kind.clone(),
                 _ => return None,
             },
 
             _ => return None,
         })
     }
+
+    fn check_bitwise(&self, lhs: &Type, rhs: &Type) -> Option<TypeKind> {
+        match &lhs.kind {
+            kind if kind.is_integer() && *kind == rhs.kind => Some(kind.clone()),
+            _ => None,
+        }
+    }
+
+    /// The shifted value keeps its type, while the amount has to be unsigned.
+    fn check_shift(&self, lhs: &Type, rhs: &Type) -> Option<TypeKind> {
+        match (&lhs.kind, &rhs.kind) {
+            (kind, TypeKind::Int(false, _) | TypeKind::Size(false)) if kind.is_integer() => {
+                Some(kind.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Numbers of different types are never converted implicitly, thus such
+    /// operands get a diagnostic suggesting an explicit cast.
+    fn invalid_binary(
+        &self,
+        lhs: Type,
+        operator: BinaryOperator,
+        rhs: Type,
+        span: LabelSpan,
+    ) -> TypeError {
+        let mismatched = lhs.kind != rhs.kind && lhs.kind.is_number() && rhs.kind.is_number();
+        match mismatched && !operator.is_shift() {
+            true => MismatchedOperands::new(lhs, operator.to_string(), rhs).into(),
+            false => InvalidBinaryType::new(lhs.kind, operator.to_string(), rhs.kind, span).into(),
+        }
+    }
 }
 
 impl Visitor for TypeChecker {
@@ -113,25 +493,196 @@ impl Visitor for TypeChecker {
     }
 
     fn visit_program(&mut self, node: &mut Program) -> Result {
-        node.statements
-            .iter_mut()
-            .for_each(|statement| match statement.accept(self) {
-                Ok(_) => {}
-                Err(error) => self.errors.push(error),
-            });
+        // The types and the signatures of the functions are known before
+        // anything uses them. The bodies come last, as they may read any
+        // global variable, whose type might be inferred from its initializer.
+        for statement in node.statements.iter_mut() {
+            let result = match statement {
+                StmtKind::FunDecl(node) => self.check_signature(node),
+                StmtKind::StructDecl(_) | StmtKind::EnumDecl(_) => statement.accept(self),
+                _ => continue,
+            };
+
+            if let Err(error) = result {
+                self.errors.push(error);
+            }
+        }
+
+        // Only now every struct is known, which a field might lead to.
+        for statement in node.statements.iter() {
+            if let StmtKind::StructDecl(node) = statement {
+                if let Err(error) = self.check_not_recursive(node) {
+                    self.errors.push(error);
+                }
+            }
+        }
+
+        for statement in node.statements.iter_mut() {
+            let result = match statement {
+                StmtKind::FunDecl(_) | StmtKind::StructDecl(_) | StmtKind::EnumDecl(_) => continue,
+                _ => statement.accept(self),
+            };
+
+            if let Err(error) = result {
+                self.errors.push(error);
+            }
+        }
+
+        for statement in node.statements.iter_mut() {
+            if let StmtKind::FunDecl(_) = statement {
+                if let Err(error) = statement.accept(self) {
+                    self.errors.push(error);
+                }
+            }
+        }
 
         Self::default_result()
     }
 
     fn visit_block(&mut self, node: &mut Block) -> Result {
+        // Only the tail is expected to have the type of the block.
+        let expected = self.expected.take();
         node.statements
             .iter_mut()
             .for_each(|statement| match statement.accept(self) {
                 Ok(_) => {}
                 Err(error) => self.errors.push(error),
             });
+        self.expected = expected;
 
-        Self::default_result()
+        // The whole block most likely spans multiple lines, thus its type
+        // keeps the span of the tail.
+        match node.tail {
+            Some(ref mut tail) => {
+                let span = value_span(tail);
+                let type_ = tail.accept(self)?;
+                Ok(type_.map(|type_| Type::new(type_.kind, span)))
+            }
+            None => Self::default_result(),
+        }
+    }
+
+    fn visit_if(&mut self, node: &mut If) -> Result {
+        self.check_condition(&mut node.condition)?;
+
+        // A branch ending with the call of a `void` function has no value.
+        let has_value = |type_: &Type| type_.kind != TypeKind::Void;
+
+        // The whole if most likely spans multiple lines, thus the branches
+        // are represented by their values.
+        let then_span = branch_span(&node.then);
+        let then = node.then.accept(self)?.filter(has_value);
+
+        // Without an expected type, the else branch needs to match the then branch.
+        let expected = self
+            .expected
+            .clone()
+            .or(then.as_ref().map(|then| then.kind.clone()));
+        let (else_span, else_) = match node.else_ {
+            Some(ref mut else_) => {
+                let last = std::mem::replace(&mut self.expected, expected);
+                let else_type = else_.accept(self);
+                self.expected = last;
+
+                (else_span(else_), else_type?.filter(has_value))
+            }
+            None if then.is_some() => {
+                let start = node.span.span.start();
+                let keyword = LabelSpan::new(start..start + "if".len(), node.span.file_id);
+                return Err(MissingElse::new(keyword).into());
+            }
+            None => return Self::default_result(),
+        };
+
+        match (then, else_) {
+            (Some(then), Some(else_)) if then == else_ => Ok(Some(Type::new(then.kind, then_span))),
+            (Some(then), Some(else_)) => Err(NotMatching::new(else_, then).into()),
+            (Some(_), None) => Err(MissingBranchValue::new(else_span, then_span).into()),
+            (None, Some(_)) => Err(MissingBranchValue::new(then_span, else_span).into()),
+            (None, None) => Self::default_result(),
+        }
+    }
+
+    fn visit_match(&mut self, node: &mut Match) -> Result {
+        let scrutinee_span = value_span(&node.expression);
+        let scrutinee = self.check_value(&mut node.expression, None)?;
+
+        // Just like the branches of an if, either every arm has a value of
+        // the same type or none of them.
+        let has_value = |type_: &Type| type_.kind != TypeKind::Void;
+
+        let mut patterns = Vec::new();
+        let mut first: Option<(Option<Type>, LabelSpan)> = None;
+        for arm in node.arms.iter_mut() {
+            match self.check_pattern(&mut arm.pattern, &scrutinee.kind) {
+                Ok(()) => patterns.push(Pat::new(&arm.pattern)),
+                Err(error) => {
+                    self.errors.push(error);
+                    continue;
+                }
+            }
+
+            // Without an expected type, the arms need to match the first one.
+            let expected = self.expected.clone().or(first
+                .as_ref()
+                .and_then(|(type_, _)| type_.as_ref().map(|type_| type_.kind.clone())));
+
+            let span = value_span(&arm.expression);
+            let last = std::mem::replace(&mut self.expected, expected);
+            let result = arm.expression.accept(self);
+            self.expected = last;
+
+            let type_ = match result {
+                Ok(type_) => type_.filter(has_value),
+                Err(error) => {
+                    self.errors.push(error);
+                    continue;
+                }
+            };
+
+            let error: TypeError = match (&first, type_) {
+                (None, type_) => {
+                    first = Some((type_, span));
+                    continue;
+                }
+                (Some((Some(first), _)), Some(type_)) if *first == type_ => continue,
+                (Some((Some(first), _)), Some(type_)) => {
+                    NotMatching::new(type_, first.clone()).into()
+                }
+                (Some((Some(_), first_span)), None) => {
+                    MissingBranchValue::new(span, *first_span).into()
+                }
+                (Some((None, first_span)), Some(_)) => {
+                    MissingBranchValue::new(*first_span, span).into()
+                }
+                (Some((None, _)), None) => continue,
+            };
+            self.errors.push(error);
+        }
+
+        // An invalid pattern would only lead to more errors.
+        if patterns.len() == node.arms.len() {
+            let exhaustiveness = Exhaustiveness::new(&self.enums);
+            for (index, arm) in node.arms.iter().enumerate() {
+                if !exhaustiveness.is_useful(&patterns[..index], &patterns[index], &scrutinee.kind)
+                {
+                    self.errors
+                        .push(UnreachableArm::new(arm.pattern.span()).into());
+                }
+            }
+
+            if let Some(missing) = exhaustiveness.missing(&patterns, &scrutinee.kind) {
+                self.errors
+                    .push(NonExhaustiveMatch::new(missing, scrutinee_span).into());
+            }
+        }
+
+        // The type keeps the span of the first arm, as the whole match most
+        // likely spans multiple lines.
+        match first {
+            Some((type_, _)) => Ok(type_),
+            None => Self::default_result(),
+        }
     }
 
     fn visit_call(&mut self, node: &mut Call) -> Result {
@@ -146,7 +697,7 @@ impl Visitor for TypeChecker {
             SymbolKind::Function(fun_decl) => fun_decl,
             kind => return Err(InvalidSymbolKind::new(kind, "function", call_span).into()),
         };
-        let mut fun_decl = fun_decl.borrow_mut();
+        let fun_decl = fun_decl.borrow();
 
         if node.arguments.len() != fun_decl.parameters.len() {
             return Err(InvalidArity::new(
@@ -159,50 +710,97 @@ impl Visitor for TypeChecker {
         }
 
         for (index, argument) in node.arguments.iter_mut().enumerate() {
-            let argument_type = match argument.accept(self) {
-                Ok(Some(type_)) => type_,
-                Ok(None) => {
-                    self.errors.push(NoTypeFound::new(argument.span()).into());
-                    continue;
-                }
+            let parameter_type = &fun_decl.parameters.get(index).unwrap().type_;
+            let expected = Some(parameter_type.kind.clone());
+            let argument_type = match self.check_value(argument, expected) {
+                Ok(type_) => type_,
                 Err(error) => {
                     self.errors.push(error);
                     continue;
                 }
             };
 
-            let parameter = fun_decl.parameters.get_mut(index).unwrap();
-            let parameter_type = match parameter.accept(self) {
-                Ok(Some(type_)) => type_,
-                Ok(None) => {
-                    self.errors.push(NoTypeFound::new(argument.span()).into());
-                    continue;
-                }
-                Err(error) => {
-                    self.errors.push(error);
-                    continue;
-                }
+            if !coerces(&argument_type.kind, &parameter_type.kind) {
+                return Err(NotMatching::new(argument_type, parameter_type.clone()).into());
+            }
+        }
+
+        let type_ = symbol.type_.get().ok_or(NoTypeFound::new(call_span))?;
+        Ok(Some(Type::new(type_.kind.clone(), call_span)))
+    }
+
+    fn visit_while(&mut self, node: &mut While) -> Result {
+        self.check_condition(&mut node.condition)?;
+        node.block.accept(self)?;
+
+        Self::default_result()
+    }
+
+    fn visit_assign(&mut self, node: &mut Assign) -> Result {
+        let target_span = node.target.span();
+        let target = self.check_value(&mut node.target, None)?;
+        self.check_mutable_fields(&node.target, target_span)?;
+
+        let value_span = value_span(&node.value);
+        let value = self.check_value(&mut node.value, Some(target.kind.clone()))?;
+
+        if let Some(operator) = node.operator.binary() {
+            let result = match operator {
+                operator if operator.is_term() => self.check_term(&target, operator, &value),
+                _ => self.check_factor(&target, operator, &value),
             };
 
-            if parameter_type != argument_type {
-                return Err(NotMatching::new(argument_type, parameter_type).into());
+            if result.is_none() {
+                let target = Type::new(target.kind, target_span);
+                let value = Type::new(value.kind, value_span);
+                return Err(self.invalid_binary(target, operator, value, node.span));
             }
         }
 
+        if !coerces(&value.kind, &target.kind) {
+            let got = Type::new(value.kind, value_span);
+            let expected = Type::new(target.kind, target_span);
+            return Err(NotMatching::new(got, expected).into());
+        }
+
         Self::default_result()
     }
 
     fn visit_binary(&mut self, node: &mut Binary) -> Result {
-        let lhs_span = node.lhs.span();
-        let lhs = node.lhs.accept(self)?.ok_or(NoTypeFound::new(lhs_span))?;
-        let rhs_span = node.lhs.span();
-        let rhs = node.rhs.accept(self)?.ok_or(NoTypeFound::new(rhs_span))?;
+        // The operands of an arithmetic operation are expected to have the
+        // type of its result, while the ones of a comparison only need to
+        // match each other.
+        let expected = match node.operator {
+            operator if operator.is_logical() => Some(TypeKind::Bool),
+            operator if operator.is_arithmetic() => self.expected.clone(),
+            _ => None,
+        };
+
+        // An operand without a type of its own is checked last, as it gets
+        // the type of the other one. The amount of a shift is independent of
+        // the shifted value, thus an untyped one is a `u32`.
+        let (lhs, rhs) = if node.operator.is_shift() {
+            let lhs = self.check_value(&mut node.lhs, self.expected.clone())?;
+            let rhs = self.check_value(&mut node.rhs, Some(TypeKind::Int(false, 32)))?;
+            (lhs, rhs)
+        } else if is_untyped(&node.lhs) && !is_untyped(&node.rhs) {
+            let rhs = self.check_value(&mut node.rhs, expected)?;
+            let lhs = self.check_value(&mut node.lhs, Some(rhs.kind.clone()))?;
+            (lhs, rhs)
+        } else {
+            let lhs = self.check_value(&mut node.lhs, expected)?;
+            let rhs = self.check_value(&mut node.rhs, Some(lhs.kind.clone()))?;
+            (lhs, rhs)
+        };
 
         let result = match node.operator {
+            operator if operator.is_logical() => self.check_logical(&lhs, &rhs),
             operator if operator.is_equality() => self.check_equality(&lhs, operator, &rhs),
             operator if operator.is_comparison() => self.check_comparison(&lhs, operator, &rhs),
             operator if operator.is_term() => self.check_term(&lhs, operator, &rhs),
             operator if operator.is_factor() => self.check_factor(&lhs, operator, &rhs),
+            operator if operator.is_bitwise() => self.check_bitwise(&lhs, &rhs),
+            operator if operator.is_shift() => self.check_shift(&lhs, &rhs),
             _ => todo!(),
         };
 
@@ -210,19 +808,25 @@ impl Visitor for TypeChecker {
             return Ok(Some(Type::new(kind, node.span)));
         }
 
-        Err(InvalidBinaryType::new(lhs.kind, node.operator.to_string(), rhs.kind, node.span).into())
+        let lhs = Type::new(lhs.kind, node.lhs.span());
+        let rhs = Type::new(rhs.kind, node.rhs.span());
+        Err(self.invalid_binary(lhs, node.operator, rhs, node.span))
     }
 
     fn visit_unary(&mut self, node: &mut Unary) -> Result {
-        let expression_span = node.expression.span();
-        let expression = node
-            .expression
-            .accept(self)?
-            .ok_or(NoTypeFound::new(expression_span))?;
+        let expression = match (node.operator, &mut node.expression) {
+            (UnaryOperator::Neg, ExprKind::Literal(literal)) => {
+                let kind = self.check_literal(literal, true)?;
+                Type::new(kind, literal.token.span)
+            }
+            (_, expression) => self.check_value(expression, self.expected.clone())?,
+        };
 
         let type_kind = match (node.operator, expression.kind) {
             (UnaryOperator::Neg, TypeKind::Int(true, size)) => TypeKind::Int(true, size),
+            (UnaryOperator::Neg, TypeKind::Size(true)) => TypeKind::Size(true),
             (UnaryOperator::Neg, TypeKind::Decimal(size)) => TypeKind::Decimal(size),
+            (UnaryOperator::BitNot, kind @ (TypeKind::Int(_, _) | TypeKind::Size(_))) => kind,
             (UnaryOperator::LogNeg, TypeKind::Bool) => TypeKind::Bool,
             (operator, expression) => {
                 return Err(
@@ -234,101 +838,397 @@ impl Visitor for TypeChecker {
         Ok(Some(Type::new(type_kind, node.span)))
     }
 
+    fn visit_cast(&mut self, node: &mut Cast) -> Result {
+        // The value gets converted, thus its literals keep their own type.
+        let expression = self.check_value(&mut node.expression, None)?;
+
+        let kind = node.type_.kind.clone();
+        if !can_cast(&expression.kind, &kind) {
+            return Err(InvalidCast::new(expression.kind, kind, node.span).into());
+        }
+
+        Ok(Some(Type::new(kind, node.span)))
+    }
+
     fn visit_return(&mut self, node: &mut Return) -> Result {
-        if let Some(ref mut expression) = node.expression {
-            let function_type = self
-                .current_function
-                .clone()
-                .ok_or(NoTypeFound::new(node.span))?;
+        let function_type = self
+            .current_function
+            .clone()
+            .ok_or(NoTypeFound::new(node.span))?;
 
-            let type_ = expression
-                .accept(self)?
-                .ok_or(NoTypeFound::new(node.span))?;
-            if function_type != type_ {
-                return Err(NotMatching::new(type_, function_type).into());
+        match node.expression {
+            Some(ref mut expression) => {
+                let type_ = self.check_value(expression, Some(function_type.kind.clone()))?;
+                if !coerces(&type_.kind, &function_type.kind) {
+                    return Err(NotMatching::new(type_, function_type).into());
+                }
+            }
+            None if function_type.kind != TypeKind::Void => {
+                return Err(MissingReturnValue::new(function_type.kind, node.span).into());
             }
+            None => {}
         }
 
         Self::default_result()
     }
 
     fn visit_literal(&mut self, node: &mut Literal) -> Result {
-        let type_kind = match node.kind {
-            LiteralKind::Int => TypeKind::Int(
-                false,
-                match node.token.get_int().unwrap() {
-                    value if value <= u8::MAX as usize => 8,
-                    value if value <= u16::MAX as usize => 16,
-                    value if value <= u32::MAX as usize => 32,
-                    value if value <= u64::MAX as usize => 64,
-                    _ => panic!("Invalid int size"),
-                },
-            ),
-            LiteralKind::Decimal => {
-                let value = node.token.get_dec().unwrap();
-                // TODO: Maybe error prone.
-                if value >= f32::MIN as f64 && value <= f32::MAX as f64 {
-                    TypeKind::Decimal(32)
-                } else {
-                    TypeKind::Decimal(64)
-                }
-            }
-            LiteralKind::Bool => TypeKind::Bool,
-            _ => todo!(),
-        };
-
-        Ok(Some(Type::new(type_kind, node.token.span)))
+        let kind = self.check_literal(node, false)?;
+        Ok(Some(Type::new(kind, node.token.span)))
     }
 
     fn visit_type(&mut self, node: &mut Type) -> Result {
         Ok(Some(node.clone()))
     }
 
+    fn visit_expr_stmt(&mut self, node: &mut ExprStmt) -> Result {
+        let type_ = node.expression.accept(self)?;
+        if let Some(ref type_) = type_ {
+            node.type_.set(type_.kind.clone()).ok();
+        }
+
+        Ok(type_)
+    }
+
     fn visit_let_decl(&mut self, node: &mut LetDecl) -> Result {
         let id_span = node.id.span;
-        let type_ = node.type_.accept(self)?.ok_or(NoTypeFound::new(id_span))?;
+        let symbol = node.symbol.get().ok_or(NoSymbolFound::new(id_span))?;
+
+        let Some(ref mut type_) = node.type_ else {
+            // Without a declared type, the variable gets the type of its
+            // initializer.
+            let expression = node.expression.as_mut().ok_or(MissingType::new(id_span))?;
+            let type_ = self.check_value(expression, None)?;
+            symbol.borrow_mut().type_.set(type_).unwrap();
 
-        if let Some(ref mut expression) = node.expression {
-            expression.accept(self)?;
+            return Self::default_result();
+        };
+
+        let type_ = type_.accept(self)?.ok_or(NoTypeFound::new(id_span))?;
+        if let Err(error) = self.check_not_void(&type_) {
+            self.errors.push(error);
         }
 
-        let symbol = node.symbol.get().ok_or(NoSymbolFound::new(id_span))?;
-        symbol.borrow_mut().type_.set(type_).unwrap();
+        // The type is known from the declaration, thus the variable can be
+        // used later on, even if its initializer is invalid.
+        symbol.borrow_mut().type_.set(type_.clone()).unwrap();
+
+        match node.expression {
+            Some(ref mut expression) => {
+                let expression_span = value_span(expression);
+                let expression = self.check_value(expression, Some(type_.kind.clone()))?;
+                if !coerces(&expression.kind, &type_.kind) {
+                    let got = Type::new(expression.kind, expression_span);
+                    return Err(NotMatching::new(got, type_).into());
+                }
+            }
+            None if !has_zero(&type_.kind) => {
+                return Err(MissingInitializer::new(type_.kind, node.span).into());
+            }
+            None => {}
+        }
 
         Self::default_result()
     }
 
-    fn visit_fun_decl(&mut self, node: &mut Rc<RefCell<FunDecl>>) -> Result {
-        node.borrow_mut()
-            .parameters
-            .iter_mut()
-            .for_each(|parameter| match parameter.accept(self) {
-                Ok(_) => {}
-                Err(error) => self.errors.push(error),
-            });
+    fn visit_destructure_decl(&mut self, node: &mut DestructureDecl) -> Result {
+        let type_ = match node.type_ {
+            Some(ref mut type_) => {
+                let type_span = type_.span;
+                let type_ = type_.accept(self)?.ok_or(NoTypeFound::new(type_span))?;
+                self.check_not_void(&type_)?;
+                Some(type_)
+            }
+            None => None,
+        };
+
+        let expression_span = value_span(&node.expression);
+        let expected = type_.as_ref().map(|type_| type_.kind.clone());
+        let expression = self.check_value(&mut node.expression, expected)?;
+
+        // Without a type, the bindings get their types from the expression.
+        let type_ = match type_ {
+            Some(type_) if !coerces(&expression.kind, &type_.kind) => {
+                let got = Type::new(expression.kind, expression_span);
+                return Err(NotMatching::new(got, type_).into());
+            }
+            Some(type_) => type_,
+            None => expression,
+        };
+
+        self.check_pattern(&mut node.pattern, &type_.kind)?;
 
+        let exhaustiveness = Exhaustiveness::new(&self.enums);
+        let pattern = Pat::new(&node.pattern);
+        if let Some(missing) = exhaustiveness.missing(&[pattern], &type_.kind) {
+            return Err(RefutablePattern::new(missing, node.pattern.span()).into());
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_fun_decl(&mut self, node: &mut Rc<RefCell<FunDecl>>) -> Result {
         let id_span = node.borrow().id.span;
         let type_ = node
-            .borrow_mut()
-            .type_
-            .accept(self)?
+            .borrow()
+            .symbol
+            .get()
+            .and_then(|symbol| symbol.borrow().type_.get().cloned())
             .ok_or(NoTypeFound::new(id_span))?;
 
-        let borrow = node.borrow();
-        let symbol = borrow.symbol.get().ok_or(NoSymbolFound::new(id_span))?;
-        symbol.borrow_mut().type_.set(type_.clone()).unwrap();
+        // The block is checked on its own, as recursive calls inside of it
+        // need to borrow the function declaration again.
+        let mut block = node.borrow().block.clone();
 
-        let last = self.current_function.clone();
-        self.current_function = Some(type_);
-        node.borrow_mut().block.accept(self)?;
+        let last = self.current_function.replace(type_.clone());
+        let expected = self.expected.replace(type_.kind.clone());
+        let result = block.accept(self);
         self.current_function = last;
+        self.expected = expected;
+
+        let tail_span = block.tail.as_ref().map(value_span);
+        node.borrow_mut().block = block;
+
+        // The value of the block is the value the function returns.
+        if let (Some(tail), Some(tail_span)) = (result?, tail_span) {
+            if !coerces(&tail.kind, &type_.kind) {
+                let got = Type::new(tail.kind, tail_span);
+                return Err(NotMatching::new(got, type_).into());
+            }
+        }
 
         Self::default_result()
     }
 
+    fn visit_struct_decl(&mut self, node: &mut Rc<RefCell<StructDecl>>) -> Result {
+        let id = node.borrow().id.get_spur().unwrap();
+        self.structs.insert(id, node.clone());
+
+        for field in node.borrow().fields.iter() {
+            if let Err(error) = self.check_not_void(&field.type_) {
+                self.errors.push(error);
+            }
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_enum_decl(&mut self, node: &mut Rc<RefCell<EnumDecl>>) -> Result {
+        let id = node.borrow().id.get_spur().unwrap();
+        self.enums.insert(id, node.clone());
+
+        for variant in node.borrow().variants.iter() {
+            for type_ in variant.types.iter() {
+                if let Err(error) = self.check_not_void(type_) {
+                    self.errors.push(error);
+                }
+            }
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_variant_literal(&mut self, node: &mut VariantLiteral) -> Result {
+        let id = node.id.get_spur().unwrap();
+        let enum_decl = self
+            .enums
+            .get(&id)
+            .cloned()
+            .ok_or(NoTypeFound::new(node.id.span))?;
+        let enum_decl = enum_decl.borrow();
+
+        let index = *node
+            .index
+            .get()
+            .ok_or(NoTypeFound::new(node.variant.span))?;
+        let variant = &enum_decl.variants[index];
+        if node.arguments.len() != variant.types.len() {
+            return Err(InvalidVariantArity::new(
+                node.arguments.len(),
+                node.span,
+                variant.types.len(),
+                variant.span,
+            )
+            .into());
+        }
+
+        for (argument, expected) in node.arguments.iter_mut().zip(variant.types.iter()) {
+            let argument_span = value_span(argument);
+            let type_ = match self.check_value(argument, Some(expected.kind.clone())) {
+                Ok(type_) => type_,
+                Err(error) => {
+                    self.errors.push(error);
+                    continue;
+                }
+            };
+
+            if !coerces(&type_.kind, &expected.kind) {
+                let got = Type::new(type_.kind, argument_span);
+                self.errors
+                    .push(NotMatching::new(got, expected.clone()).into());
+            }
+        }
+
+        Ok(Some(Type::new(TypeKind::Enum(id), node.span)))
+    }
+
+    fn visit_struct_literal(&mut self, node: &mut StructLiteral) -> Result {
+        let id = node.id.get_spur().unwrap();
+        let struct_decl = self
+            .structs
+            .get(&id)
+            .cloned()
+            .ok_or(NoTypeFound::new(node.id.span))?;
+        let struct_decl = struct_decl.borrow();
+
+        for field in node.fields.iter_mut() {
+            let field_span = value_span(&field.expression);
+            let index = *field.index.get().ok_or(NoTypeFound::new(field.span))?;
+            let expected = &struct_decl.fields[index].type_;
+
+            let type_ = match self.check_value(&mut field.expression, Some(expected.kind.clone())) {
+                Ok(type_) => type_,
+                Err(error) => {
+                    self.errors.push(error);
+                    continue;
+                }
+            };
+
+            if !coerces(&type_.kind, &expected.kind) {
+                let got = Type::new(type_.kind, field_span);
+                self.errors
+                    .push(NotMatching::new(got, expected.clone()).into());
+            }
+        }
+
+        Ok(Some(Type::new(TypeKind::Struct(id), node.span)))
+    }
+
+    fn visit_field_access(&mut self, node: &mut FieldAccess) -> Result {
+        let target_span = node.target.span();
+        let target = self.check_value(&mut node.target, None)?;
+
+        // Only the elements of a tuple are accessed by their position.
+        if let Some(index) = node.field.get_int() {
+            let element = match target.kind {
+                TypeKind::Tuple(ref elements) => elements.get(index).cloned(),
+                _ => None,
+            }
+            .ok_or(UnknownElement::new(target.kind, index, node.field.span))?;
+            node.index.set(index).ok();
+
+            return Ok(Some(Type::new(element, node.span)));
+        }
+
+        let struct_decl = match target.kind {
+            TypeKind::Struct(name) => self.structs.get(&name).cloned(),
+            _ => None,
+        }
+        .ok_or(NoFields::new(target.kind, target_span))?;
+        let struct_decl = struct_decl.borrow();
+
+        let name = node.field.get_spur().unwrap();
+        let Some(index) = struct_decl.field_index(name) else {
+            let struct_name = struct_decl.id.get_spur().unwrap();
+            return Err(UnknownField::new(struct_name, name, node.field.span).into());
+        };
+        node.index.set(index).ok();
+
+        let kind = struct_decl.fields[index].type_.kind.clone();
+        Ok(Some(Type::new(kind, node.span)))
+    }
+
+    fn visit_index(&mut self, node: &mut Index) -> Result {
+        let target_span = node.target.span();
+        let target = self.check_value(&mut node.target, None)?;
+        let Some(element) = target.kind.element().cloned() else {
+            return Err(NotAnArray::new(target.kind, target_span).into());
+        };
+
+        // Any integer can be used as an index, while untyped ones are a `usize`.
+        let index_span = value_span(&node.index);
+        let index = self.check_value(&mut node.index, Some(TypeKind::INDEX))?;
+        if !index.kind.is_integer() {
+            return Err(InvalidIndexType::new(index.kind, index_span).into());
+        }
+
+        Ok(Some(Type::new(element, node.span)))
+    }
+
+    fn visit_array_literal(&mut self, node: &mut ArrayLiteral) -> Result {
+        // Without the type of an expected array, the elements need to match
+        // the first one. Thus, an empty array needs to be expected.
+        let mut element = self
+            .expected
+            .as_ref()
+            .and_then(TypeKind::element)
+            .map(|kind| Type::new(kind.clone(), node.span));
+
+        for expression in node.elements.iter_mut() {
+            let expression_span = value_span(expression);
+            let expected = element.as_ref().map(|element| element.kind.clone());
+
+            let type_ = match self.check_value(expression, expected) {
+                Ok(type_) => type_,
+                Err(error) if element.is_none() => return Err(error),
+                Err(error) => {
+                    self.errors.push(error);
+                    continue;
+                }
+            };
+
+            match element {
+                Some(ref element) if *element != type_ => {
+                    let got = Type::new(type_.kind, expression_span);
+                    self.errors
+                        .push(NotMatching::new(got, element.clone()).into());
+                }
+                Some(_) => {}
+                None => element = Some(type_),
+            }
+        }
+
+        let element = element.ok_or(EmptyArray::new(node.span))?;
+        let kind = TypeKind::Array(Box::new(element.kind), node.elements.len());
+        Ok(Some(Type::new(kind, node.span)))
+    }
+
+    fn visit_tuple_literal(&mut self, node: &mut TupleLiteral) -> Result {
+        // The elements only get the expected types of a tuple with the same
+        // amount of elements.
+        let mut expected = match self.expected {
+            Some(TypeKind::Tuple(ref elements)) if elements.len() == node.elements.len() => {
+                elements.clone()
+            }
+            _ => Vec::new(),
+        }
+        .into_iter();
+
+        let mut elements = Vec::new();
+        for expression in node.elements.iter_mut() {
+            let type_ = self.check_value(expression, expected.next())?;
+            elements.push(type_.kind);
+        }
+
+        Ok(Some(Type::new(TypeKind::Tuple(elements), node.span)))
+    }
+
+    fn visit_len(&mut self, node: &mut Len) -> Result {
+        let span = value_span(&node.expression);
+        let type_ = self.check_value(&mut node.expression, None)?;
+        if type_.kind.element().is_none() {
+            return Err(NotAnArray::new(type_.kind, span).into());
+        }
+
+        Ok(Some(Type::new(TypeKind::INDEX, node.span)))
+    }
+
     fn visit_parameter(&mut self, node: &mut Parameter) -> Result {
         let id_span = node.id.span;
         let type_ = node.type_.accept(self)?.ok_or(NoTypeFound::new(id_span))?;
+        if let Err(error) = self.check_not_void(&type_) {
+            self.errors.push(error);
+        }
 
         let symbol = node.symbol.get().ok_or(NoSymbolFound::new(id_span))?;
         symbol.borrow_mut().type_.set(type_).unwrap();
@@ -345,3 +1245,89 @@ impl Visitor for TypeChecker {
         Ok(Some(type_.clone()))
     }
 }
+
+/// The span errors about the value of the expression point at. A block, an
+/// if or a match most likely spans multiple lines, thus the value of its
+/// first branch is used instead.
+fn value_span(expression: &ExprKind) -> LabelSpan {
+    match expression {
+        ExprKind::Block(node) => branch_span(node),
+        ExprKind::If(node) => branch_span(&node.then),
+        ExprKind::Match(node) => match node.arms.first() {
+            Some(arm) => value_span(&arm.expression),
+            None => expression.span(),
+        },
+        _ => expression.span(),
+    }
+}
+
+/// The value at the end of the branch, or its closing brace without one.
+fn branch_span(block: &Block) -> LabelSpan {
+    match block.tail {
+        Some(ref tail) => value_span(tail),
+        None => {
+            let end = block.span.span.end();
+            LabelSpan::new(end.saturating_sub(1)..end, block.span.file_id)
+        }
+    }
+}
+
+fn else_span(else_: &Else) -> LabelSpan {
+    match else_ {
+        Else::Block(node) => branch_span(node),
+        Else::If(node) => branch_span(&node.then),
+    }
+}
+
+/// Whether the expression is made up of number literals without a suffix,
+/// which get their type from the context.
+fn is_untyped(expression: &ExprKind) -> bool {
+    match expression {
+        ExprKind::Literal(node) => {
+            node.suffix.is_none() && matches!(node.kind, LiteralKind::Int | LiteralKind::Decimal)
+        }
+        ExprKind::Unary(node) => is_untyped(&node.expression),
+        ExprKind::Grouping(node) => is_untyped(&node.expression),
+        ExprKind::Binary(node) if node.operator.is_arithmetic() => {
+            is_untyped(&node.lhs) && is_untyped(&node.rhs)
+        }
+        ExprKind::Binary(node) if node.operator.is_shift() => is_untyped(&node.lhs),
+        _ => false,
+    }
+}
+
+/// Whether a value of the one type can be used where the other one is
+/// expected. Besides the exact same type, an array can be used as a slice of
+/// its elements, which also applies to the elements of a tuple.
+fn coerces(from: &TypeKind, to: &TypeKind) -> bool {
+    match (from, to) {
+        (TypeKind::Array(from, _), TypeKind::Slice(to)) => from == to,
+        (TypeKind::Tuple(from), TypeKind::Tuple(to)) => {
+            from.len() == to.len() && from.iter().zip(to).all(|(from, to)| coerces(from, to))
+        }
+        (from, to) => from == to,
+    }
+}
+
+/// Whether a declaration of the type can start with a zero value, which
+/// isn't the case for structs, enums and slices, as their content is unknown.
+fn has_zero(kind: &TypeKind) -> bool {
+    match kind {
+        TypeKind::Struct(_) | TypeKind::Enum(_) | TypeKind::Slice(_) => false,
+        TypeKind::Array(element, _) => has_zero(element),
+        TypeKind::Tuple(elements) => elements.iter().all(has_zero),
+        _ => true,
+    }
+}
+
+/// The conversions allowed by `as`. Besides the implicit conversions of
+/// [`coerces`], numbers can be converted to each other and a `bool` to an
+/// integer.
+fn can_cast(from: &TypeKind, to: &TypeKind) -> bool {
+    match (from, to) {
+        (from, to) if coerces(from, to) => true,
+        (from, to) if from.is_number() && to.is_number() => true,
+        (TypeKind::Bool, to) => to.is_integer(),
+        _ => false,
+    }
+}
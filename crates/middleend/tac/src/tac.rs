@@ -1,11 +1,12 @@
 #[cfg(feature = "serialize")]
 use serde::Serialize;
 
-use std::rc::Rc;
+use std::{cell::RefCell, rc::Rc};
 
 use ast::{
+    symbol::Symbol,
     traversal::{Visitable, Visitor},
-    BinaryOperator, UnaryOperator,
+    BinaryOperator, Literal, Pattern, TypeKind, UnaryOperator,
 };
 
 type Result = std::result::Result<Option<Operand>, TACError>;
@@ -60,6 +61,51 @@ impl From<Binary> for Quadruple {
     }
 }
 
+/// Converts the value to another type, like an explicit `as` does.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct Cast {
+    value: Operand,
+    result: Operand,
+    kind: TypeKind,
+}
+
+impl Cast {
+    pub fn new(value: Operand, result: Operand, kind: TypeKind) -> Self {
+        Self {
+            value,
+            result,
+            kind,
+        }
+    }
+}
+
+impl From<Cast> for Quadruple {
+    fn from(value: Cast) -> Self {
+        Self::Cast(Box::new(value))
+    }
+}
+
+/// Copies the value into the result, e.g. to merge the values of branches.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct Assign {
+    value: Operand,
+    result: Operand,
+}
+
+impl Assign {
+    pub fn new(value: Operand, result: Operand) -> Self {
+        Self { value, result }
+    }
+}
+
+impl From<Assign> for Quadruple {
+    fn from(value: Assign) -> Self {
+        Self::Assign(Box::new(value))
+    }
+}
+
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug)]
 pub struct Return {
@@ -78,14 +124,162 @@ impl From<Return> for Quadruple {
     }
 }
 
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct IfFalse {
+    condition: Operand,
+    label: Rc<Label>,
+}
+
+impl IfFalse {
+    pub fn new(condition: Operand, label: Rc<Label>) -> Self {
+        Self { condition, label }
+    }
+}
+
+impl From<IfFalse> for Quadruple {
+    fn from(value: IfFalse) -> Self {
+        Self::IfFalse(Box::new(value))
+    }
+}
+
+/// Builds the variant with the given position in its enum out of the values.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct Variant {
+    index: usize,
+    values: Vec<Operand>,
+    result: Operand,
+}
+
+impl Variant {
+    pub fn new(index: usize, values: Vec<Operand>, result: Operand) -> Self {
+        Self {
+            index,
+            values,
+            result,
+        }
+    }
+}
+
+impl From<Variant> for Quadruple {
+    fn from(value: Variant) -> Self {
+        Self::Variant(Box::new(value))
+    }
+}
+
+/// Whether the value is the variant with the given position in its enum.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct IsVariant {
+    value: Operand,
+    index: usize,
+    result: Operand,
+}
+
+impl IsVariant {
+    pub fn new(value: Operand, index: usize, result: Operand) -> Self {
+        Self {
+            value,
+            index,
+            result,
+        }
+    }
+}
+
+impl From<IsVariant> for Quadruple {
+    fn from(value: IsVariant) -> Self {
+        Self::IsVariant(Box::new(value))
+    }
+}
+
+/// Copies the value a variant carries at the given index into the result.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct Payload {
+    value: Operand,
+    index: usize,
+    result: Operand,
+}
+
+impl Payload {
+    pub fn new(value: Operand, index: usize, result: Operand) -> Self {
+        Self {
+            value,
+            index,
+            result,
+        }
+    }
+}
+
+impl From<Payload> for Quadruple {
+    fn from(value: Payload) -> Self {
+        Self::Payload(Box::new(value))
+    }
+}
+
+/// Builds a tuple out of the values.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct Tuple {
+    values: Vec<Operand>,
+    result: Operand,
+}
+
+impl Tuple {
+    pub fn new(values: Vec<Operand>, result: Operand) -> Self {
+        Self { values, result }
+    }
+}
+
+impl From<Tuple> for Quadruple {
+    fn from(value: Tuple) -> Self {
+        Self::Tuple(Box::new(value))
+    }
+}
+
+/// Copies the element of a tuple at the given index into the result.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug)]
+pub struct Element {
+    value: Operand,
+    index: usize,
+    result: Operand,
+}
+
+impl Element {
+    pub fn new(value: Operand, index: usize, result: Operand) -> Self {
+        Self {
+            value,
+            index,
+            result,
+        }
+    }
+}
+
+impl From<Element> for Quadruple {
+    fn from(value: Element) -> Self {
+        Self::Element(Box::new(value))
+    }
+}
+
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug)]
 pub enum Quadruple {
     Binary(Box<Binary>),
     Unary(Box<Unary>),
     LogNeg(Box<Unary>),
+    Assign(Box<Assign>),
+    Cast(Box<Cast>),
     Label(Rc<Label>),
+    Goto(Rc<Label>),
+    IfFalse(Box<IfFalse>),
     Return(Box<Return>),
+    Variant(Box<Variant>),
+    IsVariant(Box<IsVariant>),
+    Payload(Box<Payload>),
+    Tuple(Box<Tuple>),
+    Element(Box<Element>),
 }
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
@@ -116,40 +310,120 @@ impl From<Rc<Label>> for Quadruple {
 #[derive(Debug, Clone)]
 pub enum Operand {
     Label(Rc<Label>),
-    Immediate,
-    Temp,
+    Immediate(Literal),
+    Variable(Rc<RefCell<Symbol>>),
+    Temp(usize),
 }
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug)]
 pub enum TACError {
     NoOperand,
+    NoSymbol,
 }
 
 #[derive(Debug, Default)]
 pub struct TACTransformer {
-    instructions: Vec<Quadruple>,
+    pub instructions: Vec<Quadruple>,
     label_index: usize,
+    temp_index: usize,
+    /// The start and end labels of the enclosing loops, which are the
+    /// targets of `continue` and `break`.
+    loops: Vec<(Rc<Label>, Rc<Label>)>,
 }
 
 impl TACTransformer {
+    /// Creates a new label, which still needs to be inserted at the place
+    /// it should point to.
     pub fn label(&mut self) -> Rc<Label> {
         let label = Rc::new(Label::new(self.label_index));
-
         self.label_index += 1;
-        self.instructions.push(label.clone().into());
-
         label
     }
 
     pub fn temp(&mut self) -> Operand {
-        todo!()
+        let temp = Operand::Temp(self.temp_index);
+        self.temp_index += 1;
+        temp
     }
 
     pub fn insert(&mut self, instruction: impl Into<Quadruple>) {
         let instruction = instruction.into();
         self.instructions.push(instruction);
     }
+
+    /// The right operand of `&&` and `||` is jumped over, if the left one
+    /// already decides the result.
+    fn logical(&mut self, node: &mut ast::Binary) -> Result {
+        let result = self.temp();
+        let end = self.label();
+
+        let lhs = node.lhs.accept(self)?.ok_or(TACError::NoOperand)?;
+        self.insert(Assign::new(lhs, result.clone()));
+
+        match node.operator {
+            BinaryOperator::LogAnd => self.insert(IfFalse::new(result.clone(), end.clone())),
+            _ => {
+                let rhs = self.label();
+                self.insert(IfFalse::new(result.clone(), rhs.clone()));
+                self.insert(Quadruple::Goto(end.clone()));
+                self.insert(rhs);
+            }
+        }
+
+        let rhs = node.rhs.accept(self)?.ok_or(TACError::NoOperand)?;
+        self.insert(Assign::new(rhs, result.clone()));
+        self.insert(end);
+
+        Ok(Some(result))
+    }
+
+    /// Tests the value against the pattern and jumps to the label as soon
+    /// as a test fails. The bindings are assigned along the way.
+    fn pattern(&mut self, pattern: &Pattern, value: Operand, next: &Rc<Label>) -> Result {
+        match pattern {
+            Pattern::Wildcard(_) => {}
+            Pattern::Binding(node) => {
+                let symbol = node.symbol.get().ok_or(TACError::NoSymbol)?;
+                self.insert(Assign::new(value, Operand::Variable(symbol.clone())));
+            }
+            Pattern::Literal(node) => {
+                let mut literal = Operand::Immediate(node.literal.clone());
+                if node.negated {
+                    let temp = self.temp();
+                    self.insert(Unary::new(literal, temp.clone(), UnaryOperator::Neg));
+                    literal = temp;
+                }
+
+                let condition = self.temp();
+                let binary = Binary::new(value, literal, condition.clone(), BinaryOperator::Eq);
+                self.insert(binary);
+                self.insert(IfFalse::new(condition, next.clone()));
+            }
+            Pattern::Variant(node) => {
+                let index = *node.index.get().ok_or(TACError::NoSymbol)?;
+
+                let condition = self.temp();
+                self.insert(IsVariant::new(value.clone(), index, condition.clone()));
+                self.insert(IfFalse::new(condition, next.clone()));
+
+                for (index, pattern) in node.patterns.iter().enumerate() {
+                    let payload = self.temp();
+                    self.insert(Payload::new(value.clone(), index, payload.clone()));
+                    self.pattern(pattern, payload, next)?;
+                }
+            }
+            Pattern::Tuple(node) => {
+                for (index, pattern) in node.patterns.iter().enumerate() {
+                    let element = self.temp();
+                    self.insert(Element::new(value.clone(), index, element.clone()));
+                    self.pattern(pattern, element, next)?;
+                }
+            }
+        }
+
+        Self::default_result()
+    }
 }
 
 impl Visitor for TACTransformer {
@@ -160,7 +434,125 @@ impl Visitor for TACTransformer {
         Ok(None)
     }
 
+    fn visit_if(&mut self, node: &mut ast::If) -> Result {
+        let condition = node.condition.accept(self)?.ok_or(TACError::NoOperand)?;
+        let result = self.temp();
+        let otherwise = self.label();
+        self.insert(IfFalse::new(condition, otherwise.clone()));
+
+        let then = node.then.accept(self)?;
+        let Some(ref mut else_) = node.else_ else {
+            self.insert(otherwise);
+            return Self::default_result();
+        };
+
+        // The type checker made sure that either both branches have a value
+        // or none of them.
+        let end = self.label();
+        let has_value = then.is_some();
+        if let Some(value) = then {
+            self.insert(Assign::new(value, result.clone()));
+        }
+        self.insert(Quadruple::Goto(end.clone()));
+
+        self.insert(otherwise);
+        if let Some(value) = else_.accept(self)? {
+            self.insert(Assign::new(value, result.clone()));
+        }
+        self.insert(end);
+
+        Ok(has_value.then_some(result))
+    }
+
+    fn visit_while(&mut self, node: &mut ast::While) -> Result {
+        let start = self.label();
+        let end = self.label();
+
+        self.insert(start.clone());
+        let condition = node.condition.accept(self)?.ok_or(TACError::NoOperand)?;
+        self.insert(IfFalse::new(condition, end.clone()));
+
+        self.loops.push((start.clone(), end.clone()));
+        let result = node.block.accept(self);
+        self.loops.pop();
+        result?;
+
+        self.insert(Quadruple::Goto(start));
+        self.insert(end);
+
+        Self::default_result()
+    }
+
+    fn visit_break(&mut self, _node: &mut ast::Break) -> Result {
+        if let Some((_, end)) = self.loops.last() {
+            self.insert(Quadruple::Goto(end.clone()));
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_continue(&mut self, _node: &mut ast::Continue) -> Result {
+        if let Some((start, _)) = self.loops.last() {
+            self.insert(Quadruple::Goto(start.clone()));
+        }
+
+        Self::default_result()
+    }
+
+    fn visit_match(&mut self, node: &mut ast::Match) -> Result {
+        let scrutinee = node.expression.accept(self)?.ok_or(TACError::NoOperand)?;
+        let result = self.temp();
+        let end = self.label();
+
+        let mut has_value = false;
+        for arm in node.arms.iter_mut() {
+            let next = self.label();
+            self.pattern(&arm.pattern, scrutinee.clone(), &next)?;
+
+            if let Some(value) = arm.expression.accept(self)? {
+                self.insert(Assign::new(value, result.clone()));
+                has_value = true;
+            }
+
+            self.insert(Quadruple::Goto(end.clone()));
+            self.insert(next);
+        }
+        self.insert(end);
+
+        Ok(has_value.then_some(result))
+    }
+
+    fn visit_variant_literal(&mut self, node: &mut ast::VariantLiteral) -> Result {
+        let index = *node.index.get().ok_or(TACError::NoSymbol)?;
+
+        let mut values = Vec::new();
+        for argument in node.arguments.iter_mut() {
+            values.push(argument.accept(self)?.ok_or(TACError::NoOperand)?);
+        }
+
+        let temp = self.temp();
+        self.insert(Variant::new(index, values, temp.clone()));
+
+        Ok(Some(temp))
+    }
+
+    fn visit_tuple_literal(&mut self, node: &mut ast::TupleLiteral) -> Result {
+        let mut values = Vec::new();
+        for element in node.elements.iter_mut() {
+            values.push(element.accept(self)?.ok_or(TACError::NoOperand)?);
+        }
+
+        let temp = self.temp();
+        self.insert(Tuple::new(values, temp.clone()));
+
+        Ok(Some(temp))
+    }
+
     fn visit_binary(&mut self, node: &mut ast::Binary) -> Result {
+        if node.operator.is_logical() {
+            return self.logical(node);
+        }
+
         let lhs = node.lhs.accept(self)?.ok_or(TACError::NoOperand)?;
         let rhs = node.rhs.accept(self)?.ok_or(TACError::NoOperand)?;
 
@@ -182,4 +574,237 @@ impl Visitor for TACTransformer {
 
         Ok(Some(temp))
     }
+
+    fn visit_cast(&mut self, node: &mut ast::Cast) -> Result {
+        let operand = node.expression.accept(self)?.ok_or(TACError::NoOperand)?;
+
+        let temp = self.temp();
+        let cast = Cast::new(operand, temp.clone(), node.type_.kind.clone());
+
+        self.instructions.push(cast.into());
+
+        Ok(Some(temp))
+    }
+
+    fn visit_literal(&mut self, node: &mut Literal) -> Result {
+        Ok(Some(Operand::Immediate(node.clone())))
+    }
+
+    fn visit_id(&mut self, node: &mut ast::Id) -> Result {
+        let symbol = node.symbol.get().ok_or(TACError::NoSymbol)?;
+        Ok(Some(Operand::Variable(symbol.clone())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, rc::Rc};
+
+    use lasso::Rodeo;
+
+    use ast::{traversal::Visitable, Target};
+    use diagnostics::file::Files;
+    use lexer::Lexer;
+    use parser::Parser;
+    use semantics::Semantics;
+
+    use crate::{Quadruple, TACTransformer};
+
+    fn lower(source: &str) -> TACTransformer {
+        let mut files = Files::default();
+        let file_id = files.add("test.ark", source);
+
+        let interner = Rc::new(RefCell::new(Rodeo::default()));
+
+        let lexer = Lexer::new(&files, file_id, interner.clone());
+        let mut parser = Parser::new(lexer.into_iter());
+        let mut program = parser.parse_program();
+        assert!(parser.errors.is_empty(), "{:?}", parser.errors);
+
+        let mut semantics = Semantics::new(interner, Target::default());
+        semantics.run_all(&mut program);
+        assert!(semantics.errors.is_empty(), "{:?}", semantics.errors);
+
+        let mut transformer = TACTransformer::default();
+        program.accept(&mut transformer).unwrap();
+        transformer
+    }
+
+    #[test]
+    fn while_loop() {
+        let transformer = lower(
+            "
+fun main(flag @bool) @bool {
+    while flag {
+        if flag {
+            break;
+        }
+        continue;
+    }
+    return flag;
+}",
+        );
+
+        let jumps = transformer
+            .instructions
+            .iter()
+            .filter_map(|instruction| match instruction {
+                Quadruple::Label(label) => Some(("label", label.index)),
+                Quadruple::Goto(label) => Some(("goto", label.index)),
+                Quadruple::IfFalse(if_false) => Some(("if_false", if_false.label.index)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            jumps,
+            vec![
+                ("label", 0),
+                ("if_false", 1),
+                ("if_false", 2),
+                ("goto", 1),
+                ("label", 2),
+                ("goto", 0),
+                ("goto", 0),
+                ("label", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn if_else() {
+        let transformer = lower(
+            "
+fun main(flag @bool) @i32 {
+    if flag { 1 } else if !flag { 2 } else { 3 }
+}",
+        );
+
+        let jumps = transformer
+            .instructions
+            .iter()
+            .filter_map(|instruction| match instruction {
+                Quadruple::Label(label) => Some(("label", label.index)),
+                Quadruple::Goto(label) => Some(("goto", label.index)),
+                Quadruple::IfFalse(if_false) => Some(("if_false", if_false.label.index)),
+                Quadruple::Assign(_) => Some(("assign", 0)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            jumps,
+            vec![
+                ("if_false", 0),
+                ("assign", 0),
+                ("goto", 1),
+                ("label", 0),
+                ("if_false", 2),
+                ("assign", 0),
+                ("goto", 3),
+                ("label", 2),
+                ("assign", 0),
+                ("label", 3),
+                ("assign", 0),
+                ("label", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn logical() {
+        let transformer = lower(
+            "
+fun main(lhs @bool, rhs @bool) @bool {
+    lhs && rhs || lhs
+}",
+        );
+
+        let jumps = transformer
+            .instructions
+            .iter()
+            .filter_map(|instruction| match instruction {
+                Quadruple::Label(label) => Some(("label", label.index)),
+                Quadruple::Goto(label) => Some(("goto", label.index)),
+                Quadruple::IfFalse(if_false) => Some(("if_false", if_false.label.index)),
+                Quadruple::Assign(_) => Some(("assign", 0)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            jumps,
+            vec![
+                ("assign", 0),
+                ("if_false", 1),
+                ("assign", 0),
+                ("label", 1),
+                ("assign", 0),
+                ("if_false", 2),
+                ("goto", 0),
+                ("label", 2),
+                ("assign", 0),
+                ("label", 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn match_expression() {
+        let transformer = lower(
+            "
+enum Option {
+    Some(@i32),
+    None,
+}
+
+fun main(option @Option) @i32 {
+    match option {
+        Option::Some(0) => 1,
+        Option::Some(value) => value,
+        Option::None => 0,
+    }
+}",
+        );
+
+        let jumps = transformer
+            .instructions
+            .iter()
+            .filter_map(|instruction| match instruction {
+                Quadruple::Label(label) => Some(("label", label.index)),
+                Quadruple::Goto(label) => Some(("goto", label.index)),
+                Quadruple::IfFalse(if_false) => Some(("if_false", if_false.label.index)),
+                Quadruple::Assign(_) => Some(("assign", 0)),
+                Quadruple::IsVariant(is_variant) => Some(("is_variant", is_variant.index)),
+                Quadruple::Payload(payload) => Some(("payload", payload.index)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            jumps,
+            vec![
+                ("is_variant", 0),
+                ("if_false", 1),
+                ("payload", 0),
+                ("if_false", 1),
+                ("assign", 0),
+                ("goto", 0),
+                ("label", 1),
+                ("is_variant", 0),
+                ("if_false", 2),
+                ("payload", 0),
+                ("assign", 0),
+                ("assign", 0),
+                ("goto", 0),
+                ("label", 2),
+                ("is_variant", 1),
+                ("if_false", 3),
+                ("assign", 0),
+                ("goto", 0),
+                ("label", 3),
+                ("label", 0),
+            ]
+        );
+    }
 }
@@ -0,0 +1,88 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use lasso::Rodeo;
+use termcolor::NoColor;
+
+use ast::Target;
+use bytecode::{Compiler, Module};
+use diagnostics::{file::Files, renderer::Renderer, report::Reportable};
+use interpreter::Interpreter;
+use lexer::Lexer;
+use parser::Parser;
+use semantics::Semantics;
+use value::Value;
+use vm::VM;
+
+pub fn run(source: &str) -> Result<Option<Value>, String> {
+    run_file("test.ark", source)
+}
+
+/// Runs the program with the interpreter as well as compiled to bytecode
+/// with the VM, just like `arkoi run` does for a source or a compiled file.
+/// Both need to agree on the value returned by `main` or on the error, which
+/// is rendered.
+pub fn run_file(path: &str, source: &str) -> Result<Option<Value>, String> {
+    let mut files = Files::default();
+    let file_id = files.add(path, source);
+
+    let interner = Rc::new(RefCell::new(Rodeo::default()));
+
+    let lexer = Lexer::new(&files, file_id, interner.clone());
+    let mut parser = Parser::new(lexer.into_iter());
+    let mut program = parser.parse_program();
+    assert!(parser.errors.is_empty(), "{:?}", parser.errors);
+
+    let mut semantics = Semantics::new(interner.clone(), Target::default());
+    semantics.run_all(&mut program);
+    assert!(semantics.errors.is_empty(), "{:?}", semantics.errors);
+
+    let mut interpreter = Interpreter::new(interner.clone(), Target::default());
+    let interpreted = interpreter
+        .run(&mut program)
+        .map_err(|error| render(&files, &interner, error));
+
+    let compiled = Compiler::new(interner.clone(), Target::default())
+        .compile(&mut program)
+        .map_err(|error| render(&files, &interner, error))
+        .and_then(|module| {
+            // Every program goes through the file format, just like a
+            // compiled file would.
+            let module = Module::decode(&module.encode()).unwrap();
+            VM::new(&module)
+                .run()
+                .map_err(|error| render(&files, &interner, error))
+        });
+
+    match (&interpreted, &compiled) {
+        (Ok(interpreted), Ok(compiled)) => assert_eq!(
+            interpreted, compiled,
+            "The interpreter and the VM disagree on {:?}",
+            source
+        ),
+        // The VM doesn't know the source, thus it names the function the
+        // error happened in after the message of the interpreter.
+        (Err(interpreted), Err(compiled)) => {
+            let header = interpreted.lines().next().unwrap_or_default();
+            assert!(
+                compiled.starts_with(header.trim_end_matches('.')),
+                "The interpreter and the VM disagree on {:?}: {:?} and {:?}",
+                source,
+                interpreted,
+                compiled
+            );
+        }
+        _ => panic!(
+            "The interpreter and the VM disagree on {:?}: {:?} and {:?}",
+            source, interpreted, compiled
+        ),
+    }
+
+    interpreted
+}
+
+fn render<E: Reportable>(files: &Files, interner: &Rc<RefCell<Rodeo>>, error: E) -> String {
+    let mut output = NoColor::new(Vec::new());
+    Renderer::new(files, interner.clone(), &mut output).render(error);
+    String::from_utf8(output.into_inner()).unwrap()
+}
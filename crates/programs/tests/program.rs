@@ -0,0 +1,845 @@
+use programs::{run, run_file};
+use value::Value;
+
+macro_rules! test_program {
+    (FAIL: $name:ident, $source:expr) => {
+        #[test]
+        fn $name() {
+            let result = run($source);
+            assert!(result.is_err(), "{:?} should be an error", result);
+        }
+    };
+    ($name:ident, $source:expr => $expected:expr) => {
+        #[test]
+        fn $name() {
+            let result = run($source).unwrap();
+            assert_eq!(result, Some($expected), "Input was {:?}", $source);
+        }
+    };
+    (INDENTATION: $name:ident, $source:expr => $expected:expr) => {
+        #[test]
+        fn $name() {
+            let result = run_file("test.arki", $source).unwrap();
+            assert_eq!(result, Some($expected), "Input was {:?}", $source);
+        }
+    };
+}
+
+test_program!(success_return, "
+fun main() @i32 {
+    let value @i32 = 42;
+    return value;
+}" => Value::Int(42, true, 32));
+
+test_program!(success_arithmetic, "
+fun main() @i32 {
+    let value @i32 = 2 * 20 + 4 / 2;
+    return value;
+}" => Value::Int(42, true, 32));
+
+test_program!(success_global, "
+let global @i32 = 40;
+
+fun main() @i32 {
+    let local @i32 = 2;
+    return global + local;
+}" => Value::Int(42, true, 32));
+
+test_program!(success_call, "
+fun add(lhs @i32, rhs @i32) @i32 {
+    return lhs + rhs;
+}
+
+fun main() @i32 {
+    let value @i32 = 20;
+    return add(add(value, value), add(value, value) / value);
+}" => Value::Int(42, true, 32));
+
+test_program!(success_scopes, "
+fun main() @i32 {
+    let value @i32 = 2;
+    {
+        let value @i32 = 40;
+        let other @i32 = value;
+    }
+    return value;
+}" => Value::Int(2, true, 32));
+
+test_program!(success_wrapping, "
+fun main() @u8 {
+    let value @u8 = 255;
+    return value + 2;
+}" => Value::Int(1, false, 8));
+
+test_program!(success_comparison, "
+fun main() @bool {
+    let value @f64 = 4.25;
+    return value * 4.0 >= 17.0;
+}" => Value::Bool(true));
+
+test_program!(success_if, "
+fun main() @i32 {
+    let value @i32 = 42;
+    let zero @i32 = 0;
+    if value > zero {
+        return value;
+    }
+    return zero;
+}" => Value::Int(42, true, 32));
+
+test_program!(success_else_if, "
+fun sign(value @i32) @i32 {
+    let zero @i32 = 0;
+    let one @i32 = 1;
+    if value > zero {
+        return one;
+    } else if value < zero {
+        return zero - one;
+    } else {
+        return zero;
+    }
+}
+
+fun main() @i32 {
+    let value @i32 = 2;
+    let other @i32 = 5;
+    return sign(value - other);
+}" => Value::Int(-1, true, 32));
+
+test_program!(success_if_expression, "
+fun main() @i32 {
+    let flag @bool = false;
+    let value @i32 = if flag { 1 } else if !flag { 42 } else { 2 };
+    return value;
+}" => Value::Int(42, true, 32));
+
+test_program!(success_while, "
+fun count(value @i32) @i32 {
+    let zero @i32 = 0;
+    let one @i32 = 1;
+    while value > zero {
+        return count(value - one) + one;
+    }
+    return zero;
+}
+
+fun main() @i32 {
+    let value @i32 = 42;
+    return count(value);
+}" => Value::Int(42, true, 32));
+
+test_program!(success_break_continue, "
+fun main() @i32 {
+    let sum! @i32 = 0;
+    let index! @i32 = 0;
+    let one @i32 = 1;
+    let five @i32 = 5;
+    let ten @i32 = 10;
+    while true {
+        index += one;
+        if index == five {
+            continue;
+        }
+        if index > ten {
+            break;
+        }
+        sum += index;
+    }
+    return sum;
+}" => Value::Int(50, true, 32));
+
+test_program!(success_assign, "
+let global! @i32 = 0;
+
+fun set(value @i32) @bool {
+    global = value;
+    return true;
+}
+
+fun main() @i32 {
+    let value! @i32 = 21;
+    let done @bool = set(value);
+    value = global;
+    {
+        value = value + global;
+    }
+    return value;
+}" => Value::Int(42, true, 32));
+
+test_program!(success_compound_assign, "
+fun main() @i32 {
+    let value! @i32 = 10;
+    let two @i32 = 2;
+    let three @i32 = 3;
+    value += two;
+    value -= three;
+    value *= two;
+    value /= three;
+    return value;
+}" => Value::Int(6, true, 32));
+
+test_program!(success_compound_assign_order, "
+let global! @i32 = 1;
+
+fun set() @i32 {
+    global = 40;
+    2
+}
+
+fun main() @i32 {
+    global += set();
+    global
+}" => Value::Int(42, true, 32));
+
+test_program!(success_assign_parameter, "
+fun double(value @i32!) @i32 {
+    value *= value / value + value / value;
+    return value;
+}
+
+fun main() @i32 {
+    let value @i32 = 21;
+    let result @i32 = double(value);
+    return result + value - value;
+}" => Value::Int(42, true, 32));
+
+test_program!(success_struct, "
+struct Point {
+    x @i32,
+    y @i32,
+}
+
+fun main() @i32 {
+    let value @i32 = 40;
+    let other @i32 = 2;
+    let point @Point = Point { y: other, x: value };
+    return point.x + point.y;
+}" => Value::Int(42, true, 32));
+
+test_program!(success_struct_value, "
+struct Pair {
+    first @bool,
+    second @f64,
+}
+
+fun main() @Pair {
+    let value @f64 = 4.25;
+    return Pair { first: true, second: value };
+}" => Value::Struct(vec![Value::Bool(true), Value::Decimal(4.25, 64)]));
+
+test_program!(success_nested_field_assign, "
+struct Inner {
+    value @i32!,
+}
+
+struct Outer {
+    flag @bool!,
+    inner @Inner!,
+}
+
+fun main() @i32 {
+    let value @i32 = 2;
+    let outer! @Outer = Outer { flag: false, inner: Inner { value: value } };
+    let forty @i32 = 40;
+    outer.inner.value += forty;
+    outer.flag = true;
+    if outer.flag {
+        return outer.inner.value;
+    }
+    return value;
+}" => Value::Int(42, true, 32));
+
+test_program!(success_assign_field_order, "
+struct Pair {
+    a @i32!,
+    b @i32!,
+}
+
+let pair! @Pair = Pair { a: 0, b: 0 };
+
+fun set() @i32 {
+    pair.b = 40;
+    2
+}
+
+fun main() @i32 {
+    pair.a = set();
+    pair.a + pair.b
+}" => Value::Int(42, true, 32));
+
+test_program!(success_struct_copy, "
+struct Counter {
+    count @i32!,
+}
+
+fun increment(self @Counter!) @Counter {
+    let one @i32 = 1;
+    self.count += one;
+    return self;
+}
+
+fun main() @i32 {
+    let value @i32 = 41;
+    let counter @Counter = Counter { count: value };
+    let other @Counter = increment(counter);
+    return other.count + counter.count - value;
+}" => Value::Int(42, true, 32));
+
+test_program!(success_tail_value, "
+fun add(lhs @i32, rhs @i32) @i32 {
+    lhs + rhs
+}
+
+fun main() @i32 {
+    let value @i32 = 21;
+    add(value, value)
+}" => Value::Int(42, true, 32));
+
+test_program!(success_block_expression, "
+fun main() @i32 {
+    let value @i32 = {
+        let first @i32 = 40;
+        let second @i32 = 2;
+        first + second
+    };
+    {
+        let value @i32 = 1;
+        value
+    }
+    { value }
+}" => Value::Int(42, true, 32));
+
+test_program!(success_tail_if, "
+fun max(lhs @i32, rhs @i32) @i32 {
+    if lhs > rhs { lhs } else { rhs }
+}
+
+fun main() @i32 {
+    let value @i32 = 42;
+    let other @i32 = 2;
+    max(other, value)
+}" => Value::Int(42, true, 32));
+
+test_program!(success_void, "
+let counter! @i32 = 0;
+
+fun add(value @i32) @void {
+    let zero @i32 = 0;
+    if value == zero {
+        return;
+    }
+    counter += value;
+}
+
+fun twice(value @i32) @void {
+    add(value);
+    add(value)
+}
+
+fun main() @i32 {
+    let value @i32 = 21;
+    let zero @i32 = 0;
+    twice(value);
+    add(zero);
+    counter
+}" => Value::Int(42, true, 32));
+
+test_program!(success_logical, "
+let calls! @i32 = 0;
+
+fun check(value @bool) @bool {
+    calls += 1;
+    value
+}
+
+fun main() @i32 {
+    let yes @bool = true;
+    let no @bool = false;
+    if check(no) && check(yes) || check(yes) && !check(no) {
+        if no || yes && !no == true {
+            return calls;
+        }
+    }
+    0
+}" => Value::Int(3, true, 32));
+
+test_program!(success_bitwise, "
+fun main() @u32 {
+    let flags! @u32 = 0b1100;
+    let mask @u8 = 0b1010;
+    flags = flags & 0b1000 | 1 << 4 ^ 0b11;
+    if ~0u8 >> mask != 0b11_1111 {
+        return 1;
+    }
+    if -7 % 3 != -1 {
+        return 2;
+    }
+    flags % 17 + 1 << 33
+}" => Value::Int(22, false, 32));
+
+test_program!(success_cast, "
+fun main() @i64 {
+    let small @i8 = -1;
+    let wide @u16 = small as @u16;
+    let ratio @f64 = 2.85;
+    let flag @bool = true;
+    if (1e10 as @u8) != 255 {
+        return 1;
+    }
+    if (-2.5 as @u32) != 0 {
+        return 2;
+    }
+    if (300 as @u8 as @i8) != 44 {
+        return 3;
+    }
+    wide as @i64 - 65535 + (ratio * 10.0) as @i64 + flag as @i64 * 14
+}" => Value::Int(42, true, 64));
+
+test_program!(success_string, "
+fun greet(name @str) @str {
+    \"Hello, \" + name + \"!\\n\"
+}
+
+fun main() @str {
+    let name! @str = \"\\u{57}orld\";
+    if name == \"World\" {
+        name = \"\\\"\" + name + \"\\\"\";
+    }
+    greet(name)
+}" => Value::Str("Hello, \"World\"!\n".into()));
+
+test_program!(success_literal_types, "
+struct Range {
+    min @i8,
+    max @u64,
+}
+
+fun half(value @f32) @f32 {
+    value / 2.0
+}
+
+fun main() @i64 {
+    let range @Range = Range { min: -128, max: 18446744073709551615 };
+    let small! @u8 = 250;
+    small += 10;
+    if half(3.0) != 1.5 {
+        return 1;
+    }
+    if range.min != -128 {
+        return 2;
+    }
+    if range.max != 18446744073709551615 {
+        return 3;
+    }
+    if small != 4 {
+        return 4;
+    }
+    5000000000 - 4999999958
+}" => Value::Int(42, true, 64));
+
+test_program!(success_size, "
+fun sum(length @usize) @usize {
+    let total! @usize = 0;
+    let index! @usize = 0;
+    while index < length {
+        index += 1;
+        total += index;
+    }
+    total
+}
+
+fun main() @isize {
+    let offset @isize = -3isize;
+    let wrapped @usize = 0usize - 1;
+    if wrapped != 18446744073709551615 {
+        return 1;
+    }
+    if sum(8) + 9 != 45 {
+        return 2;
+    }
+    offset * -14
+}" => Value::Int(42, true, 64));
+
+test_program!(success_number_literals, "
+fun main() @i64 {
+    let small @i8 = -128i8;
+    let number @i64 = 0xFF_i64 + 0o17i64 + 0b1010i64 + 1_000i64;
+    let scale @f64 = 2.5e1f64 * 4e0f64;
+    if scale == 1E+2f64 {
+        if small == -128i8 {
+            return number;
+        }
+    }
+    0i64
+}" => Value::Int(1280, true, 64));
+
+test_program!(success_comments, "
+/// The answer to everything.
+let answer @i32 = 42; // The only global.
+
+/* A block comment /* with a nested one */ */
+/// Divides the answer by one.
+fun main() @i32 {
+    /// The divisor.
+    let one @i32 = 1;
+    answer / /* one */ one // The tail.
+}" => Value::Int(42, true, 32));
+
+test_program!(INDENTATION: success_indentation, "
+struct Point:
+    x @i32!
+    y @i32
+
+fun max(lhs @i32, rhs @i32) @i32:
+    if lhs > rhs:
+        lhs
+    else:
+        rhs
+
+fun main() @i32:
+    sum! @i32 = 0
+    let index! @i32 = 0
+    let one @i32 = 1; let ten @i32 = 10
+    while index < ten:
+        index += one
+        if index > ten:
+            break
+        sum += index
+    let point! @Point = Point { x: sum, y: one }
+    point.x -= max(one, ten)
+    point.x - point.y - one
+" => Value::Int(43, true, 32));
+
+test_program!(INDENTATION: success_indentation_tabs, "
+fun test(b @i32) @i32:
+\ta! @i32 = b
+\tc! @i32 = b
+\td! @i32 = a + b
+
+\treturn a + c + d
+
+fun main() @i32:
+\tvalue @i32 = 16
+\treturn test(value)
+" => Value::Int(64, true, 32));
+
+test_program!(success_array, "
+struct Grid {
+    cells @[[u8; 2]; 2]!,
+}
+
+fun sum(values @[i32]) @i32 {
+    let total! @i32 = 0;
+    let index! @usize = 0;
+    while index < len(values) {
+        total += values[index];
+        index += 1;
+    }
+    total
+}
+
+fun main() @i32 {
+    let values! @[i32; 3] = [1, 2, 3];
+    values[1] = 10;
+    values[2] += 5;
+
+    let grid! @Grid = Grid { cells: [[0, 0], [0, 0]] };
+    grid.cells[1][0] = 7;
+    let zeroes @[i64; 4];
+
+    sum(values) + grid.cells[1][0] as @i32 + len(zeroes) as @i32
+}" => Value::Int(30, true, 32));
+
+test_program!(success_empty_array, "
+fun count(values @[i32]) @usize {
+    len(values)
+}
+
+fun main() @i32 {
+    let empty @[i32; 0] = [];
+    let values @[i32; 2] = [40, 2];
+    (count(empty) + count([]) + len(values)) as @i32 + values[0]
+}" => Value::Int(42, true, 32));
+
+test_program!(success_len_shadowed, "
+fun len(values @[i32]) @i32 {
+    values[0]
+}
+
+fun count(values @[i32]) @usize {
+    let len @usize = 2;
+    len
+}
+
+fun main() @i32 {
+    let values @[i32; 3] = [40, 1, 1];
+    len(values) + count(values) as @i32
+}" => Value::Int(42, true, 32));
+
+test_program!(success_assign_index_order, "
+let values! @[i32; 2] = [0, 0];
+
+fun set() @i32 {
+    values[1] = 40;
+    2
+}
+
+fun main() @i32 {
+    values[0] = set();
+    values[0] + values[1]
+}" => Value::Int(42, true, 32));
+
+test_program!(success_match, "
+enum Shape {
+    Circle(@f64),
+    Rect(@i32, @i32),
+    Empty,
+}
+
+enum Wrapper {
+    Some(@Shape),
+    None,
+}
+
+let global @Wrapper = Wrapper::Some(Shape::Rect(4, 5));
+
+fun area(shape @Shape) @i32 {
+    match shape {
+        Shape::Rect(0, _) => 0,
+        Shape::Rect(width, height) => width * height,
+        Shape::Circle(_) => 3,
+        Shape::Empty => -1,
+    }
+}
+
+fun sign(value @i32) @i32 {
+    match value {
+        -1 => 100,
+        0 => 200,
+        _ => 300,
+    }
+}
+
+fun main() @i32 {
+    let width @i32 = match global {
+        Wrapper::Some(Shape::Rect(width, _)) => width,
+        Wrapper::Some(other) => area(other),
+        Wrapper::None => 0,
+    };
+    let flag @bool = true;
+    let bit @i32 = match flag { true => 1, false => 2 };
+    width + area(Shape::Rect(2, 3)) + sign(-1) + sign(0) + bit + area(Shape::Empty)
+}" => Value::Int(310, true, 32));
+
+test_program!(INDENTATION: success_match_indentation, "
+enum Option:
+    Some(@i32)
+    None
+
+fun get(option @Option) @i32:
+    match option:
+        Option::Some(value) => value
+        Option::None => 0
+
+fun main() @i32:
+    let value @Option = Option::Some(40)
+    let none @Option = Option::None
+    get(value) + get(none) + 2
+" => Value::Int(42, true, 32));
+
+test_program!(success_tuple, "
+enum Shape {
+    Circle(@i32),
+    Rect(@i32, @i32),
+}
+
+let (offset, scale!) @(i32, i32) = (1, 2);
+
+fun divide(lhs @i32, rhs @i32) @(i32, i32) {
+    (lhs / rhs, lhs % rhs)
+}
+
+fun area(pair @(Shape, i32)) @i32 {
+    match pair {
+        (Shape::Rect(0, _), _) => 0,
+        (Shape::Rect(width, height), count) => width * height * count,
+        (Shape::Circle(radius), count) => radius * count,
+    }
+}
+
+fun main() @i32 {
+    let (quotient, remainder!) = divide(17, 5);
+    remainder += 1;
+    let pair! @((i32, bool), [i32; 2]) = ((7, true), [1, 2]);
+    pair.0.0 = 8;
+    let single @(i32,) = (4,);
+    let (a, (b, _)) = (1, (2, 3));
+    let grouped @i32 = (quotient + remainder) * 2;
+    grouped + pair.0.0 + pair.1[1] + single.0 + a + b + area((Shape::Rect(2, 3), 2)) + offset
+}" => Value::Int(42, true, 32));
+
+test_program!(INDENTATION: success_tuple_indentation, "
+fun swap(pair @(i32, i32)) @(i32, i32):
+    (pair.1, pair.0)
+
+fun main() @i32:
+    let (first, second) = swap((2, 40))
+    first + second
+" => Value::Int(42, true, 32));
+
+test_program!(success_let_inference, "
+struct Point {
+    x @i64,
+    y @i64,
+}
+
+let origin = Point { x: 1, y: 2 };
+
+fun make() @(i64, bool) {
+    (5, true)
+}
+
+fun main() @i32 {
+    let count! = 0;
+    let ratio = 0.5f32;
+    let values = [1, 2, 3];
+    let total! @u64 = 0;
+    while count < 3 {
+        total += values[count] as @u64;
+        count += 1;
+    }
+    let sum = origin.x + origin.y + make().0 + total as @i64;
+    sum as @i32 + (ratio * 2.0) as @i32 + count
+}" => Value::Int(18, true, 32));
+
+test_program!(INDENTATION: success_let_inference_indentation, "
+offset = 2u8
+
+fun main() @u8:
+    value! = 40u8
+    value += offset
+    value
+" => Value::Int(42, false, 8));
+
+test_program!(success_forward_declarations, "
+let total = base * 2 + bonus();
+let base = offset + 3;
+let (offset, flag) = (1, true);
+
+fun main() @i32 {
+    let value @i32 = if is_even(10) && !is_odd(4) && flag { total } else { 0 };
+    value + make().x + make().y.z
+}
+
+fun bonus() @i32 {
+    base + 2
+}
+
+fun is_even(n @i32) @bool {
+    if n == 0 { true } else { is_odd(n - 1) }
+}
+
+fun is_odd(n @i32) @bool {
+    if n == 0 { false } else { is_even(n - 1) }
+}
+
+fun make() @Point {
+    Point { x: 28, y: origin }
+}
+
+struct Point {
+    x @i32,
+    y @Inner,
+}
+
+struct Inner {
+    z @i32,
+}
+
+let origin = Inner { z: 0 };
+" => Value::Int(42, true, 32));
+
+test_program!(INDENTATION: success_forward_declarations_indentation, "
+fun main() @u32:
+    count(answer)
+
+fun count(n @u32) @u32:
+    if n == 0:
+        0
+    else:
+        count(n - 1) + 1
+
+answer = half * 2
+half = 21u32
+" => Value::Int(42, false, 32));
+
+test_program!(FAIL: fail_division_by_zero, "
+fun main() @i32 {
+    let value @i32 = 42;
+    let zero @i32 = 0;
+    return value / zero;
+}");
+
+test_program!(FAIL: fail_compound_division_by_zero, "
+fun main() @i32 {
+    let value! @i32 = 42;
+    let zero @i32 = 0;
+    value /= zero;
+    return value;
+}");
+
+test_program!(FAIL: fail_remainder_by_zero, "
+fun main() @u8 {
+    let value @u8 = 42;
+    let zero @u8 = 0;
+    value % zero
+}");
+
+test_program!(FAIL: fail_index_out_of_bounds, "
+fun main() @i32 {
+    let values @[i32; 3] = [1, 2, 3];
+    let index @i64 = -1;
+    values[index]
+}");
+
+test_program!(FAIL: fail_assign_index_out_of_bounds, "
+fun main() @i32 {
+    let values! @[i32; 3] = [1, 2, 3];
+    values[len(values)] = 4;
+    0
+}");
+
+test_program!(FAIL: fail_missing_return, "
+fun main() @i32 {
+    let value @i32 = 42;
+}");
+
+#[test]
+fn render_missing_return() {
+    let error = run("
+fun main() @i32 {
+    let value @i32 = 42;
+}")
+    .unwrap_err();
+
+    let message = "The function 'main' reached its end without returning a value.";
+    assert!(error.contains(message), "{}", error);
+}
+
+test_program!(success_deep_recursion, "
+fun depth(value @i32) @i32 {
+    if value == 0 {
+        return 0;
+    }
+    return depth(value - 1) + 1;
+}
+
+fun main() @i32 {
+    return depth(20) + depth(22);
+}" => Value::Int(42, true, 32));
+
+test_program!(FAIL: fail_missing_main, "
+fun test() @i32 {
+    let value @i32 = 42;
+    return value;
+}");